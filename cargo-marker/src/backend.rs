@@ -5,19 +5,33 @@
 //! tests later down the line.
 
 use self::{lints::LintCrate, toolchain::Toolchain};
-use crate::config::LintDependencyEntry;
+use crate::config::{LintDependencyEntry, LintLevel};
 use crate::error::prelude::*;
 use crate::observability::display::{self, print_stage};
 use crate::observability::prelude::*;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
+use cargo_metadata::Message;
 use itertools::Itertools;
-use std::collections::BTreeMap;
+use marker_adapter::{LintCrateInfo, LintCrateRegistry, CAPPED_LINT_NOTE};
+use marker_api::{Lint, LintPassInfo};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{BufReader, IsTerminal};
+use std::process::{Command, ExitStatus, Stdio};
+use yansi::Paint;
 
+pub mod baseline;
 pub mod cargo;
 pub mod driver;
+pub mod fix;
 pub mod lints;
+pub mod sarif;
 pub mod toolchain;
 
+#[cfg(test)]
+mod test_utils;
+
 /// Markers configuration for any action that requires lint crates to be available.
 ///
 /// It's assumed that all paths in this struct are absolute paths.
@@ -32,11 +46,28 @@ pub struct Config {
     pub marker_dir: Utf8PathBuf,
     /// The list of lints.
     pub lints: BTreeMap<String, LintDependencyEntry>,
+    /// The configuration of the lint crates, if specified.
+    pub lint_crate_configs: Option<LintCrateConfigs>,
+    /// The levels of lints and lint groups from the manifest. Levels of groups
+    /// are applied first, to allow overriding them for individual lints.
+    pub lint_levels: Vec<(String, LintLevel)>,
+    /// The levels of lints and lint groups from the command line, in the order
+    /// they should be applied. These override the levels from the manifest.
+    pub cli_lint_levels: Vec<(String, LintLevel)>,
     /// Additional flags, which should be passed to rustc during the compilation
     /// of crates.
     pub build_rustc_flags: String,
     /// Indicates if this is a release or debug build.
     pub debug_build: bool,
+    /// Run Cargo without accessing the network, when lint crates are fetched
+    /// and built.
+    pub offline: bool,
+    /// Like [`Config::offline`], but also requires the lockfile of the lint
+    /// crates to be up to date.
+    pub frozen: bool,
+    /// A directory created by `cargo marker vendor`. If specified, lint crates
+    /// and their dependencies are taken from this directory.
+    pub vendor_dir: Option<Utf8PathBuf>,
     pub toolchain: Toolchain,
 }
 
@@ -45,8 +76,14 @@ impl Config {
         Ok(Self {
             marker_dir: toolchain.find_target_dir()?.join("marker"),
             lints: BTreeMap::default(),
+            lint_crate_configs: None,
+            lint_levels: vec![],
+            cli_lint_levels: vec![],
             build_rustc_flags: String::new(),
             debug_build: false,
+            offline: false,
+            frozen: false,
+            vendor_dir: None,
             toolchain,
         })
     }
@@ -58,17 +95,71 @@ impl Config {
     fn lint_crate_dir(&self) -> Utf8PathBuf {
         self.marker_dir.join("lints")
     }
+
+    /// The manifest of the dummy crate, which is used to fetch and build the
+    /// lint crates. See [`lints::fetch`] for more information.
+    fn dummy_manifest(&self) -> Utf8PathBuf {
+        self.marker_dir.join("Cargo.toml")
+    }
+
+    /// The Cargo config file with the source replacements for vendored lint
+    /// crates. See [`lints::vendor`] for more information.
+    fn vendor_config(&self) -> Utf8PathBuf {
+        self.marker_dir.join("vendor-config.toml")
+    }
+
+    /// Additional arguments for all Cargo commands, which fetch or build lint
+    /// crates via the dummy crate.
+    fn lint_crate_cargo_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if self.frozen {
+            args.push("--frozen".to_string());
+        } else if self.offline {
+            args.push("--offline".to_string());
+        }
+        if self.vendor_dir.is_some() {
+            args.push("--config".to_string());
+            args.push(self.vendor_config().into_string());
+        }
+        args
+    }
+}
+
+/// The configuration of lint crates, taken from the
+/// `[workspace.metadata.marker.config]` table. It's passed to the driver as
+/// JSON, via the `MARKER_LINT_CRATES_CONFIG` environment value.
+#[derive(Debug, Serialize)]
+pub struct LintCrateConfigs {
+    /// The path of the `Cargo.toml` file, which contains the configuration.
+    pub manifest_path: Utf8PathBuf,
+    /// The configuration values, keyed by the name of the lint crate.
+    pub config: BTreeMap<String, toml::Value>,
 }
 
 /// This struct contains all information to use rustc as a driver.
 #[derive(Debug)]
 pub struct CheckInfo {
     pub env: Vec<(&'static str, String)>,
+    /// The compiled lint crates, which are passed to the driver.
+    pub lint_crates: Vec<LintCrate>,
+}
+
+/// The format, in which diagnostics should be emitted by [`run_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// The normal human readable output of Cargo and rustc.
+    Human,
+    /// The JSON messages emitted by Cargo with `--message-format=json`.
+    Json,
+    /// A [SARIF](https://sarifweb.azurewebsites.net/) log, containing the
+    /// diagnostics of Marker lints.
+    Sarif,
 }
 
 pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
     print_stage("compiling lints");
-    let lints = lints::build_lints(config)?
+    let lint_crates = lints::build_lints(config)?;
+    let lints = lint_crates
         .iter()
         .map(|LintCrate { name, file }| format!("{name}:{file}"))
         .join(";");
@@ -78,33 +169,345 @@ pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
         ("RUSTC_WORKSPACE_WRAPPER", config.toolchain.driver_path.clone().into_string()),
         ("MARKER_LINT_CRATES", lints),
     ];
+    if let Some(lint_crate_configs) = &config.lint_crate_configs {
+        let lint_crate_configs =
+            serde_json::to_string(lint_crate_configs).expect("the lint crate config is valid JSON");
+        env.push(("MARKER_LINT_CRATES_CONFIG", lint_crate_configs));
+    }
+    let lint_levels = ordered_lint_levels(config, &lint_crates)?;
+    if !lint_levels.is_empty() {
+        let lint_levels = lint_levels
+            .iter()
+            .map(|(lint, level)| level.to_rustc_arg(lint))
+            .join(" ");
+        env.push(("MARKER_LINT_LEVELS", lint_levels));
+    }
     if let Some(toolchain) = &config.toolchain.cargo.toolchain {
         env.push(("RUSTUP_TOOLCHAIN", toolchain.into()));
     }
 
-    Ok(CheckInfo { env })
+    Ok(CheckInfo { env, lint_crates })
+}
+
+/// Returns all lint levels in the order they should be applied. Later entries
+/// override earlier ones.
+///
+/// The lint crates and the groups declared by them are only known after loading
+/// the lint crates. This is required to apply the levels of groups from the
+/// manifest first.
+fn ordered_lint_levels<'a>(config: &'a Config, lint_crates: &[LintCrate]) -> Result<Vec<&'a (String, LintLevel)>> {
+    let mut levels: Vec<_> = config.lint_levels.iter().collect();
+    if !levels.is_empty() {
+        let registry = load_registry(lint_crates)?;
+        let groups: HashSet<String> = registry
+            .lint_pass_infos()
+            .flat_map(LintPassInfo::lints)
+            .filter_map(|lint| Some(lint.name.rsplit_once("::")?.0.to_ascii_lowercase()))
+            .chain(
+                registry
+                    .lint_groups()
+                    .into_iter()
+                    .map(|(name, _)| name.to_ascii_lowercase()),
+            )
+            .collect();
+        levels.sort_by_key(|(name, _)| !groups.contains(&name.to_ascii_lowercase()));
+    }
+    levels.extend(&config.cli_lint_levels);
+    Ok(levels)
+}
+
+/// Copies the lint crates and their dependencies into the given directory, to
+/// allow using them without network access.
+pub fn vendor_lints(config: &Config, dir: &Utf8Path) -> Result {
+    print_stage("vendoring lints");
+    lints::vendor_lints(config, dir)
+}
+
+/// Builds and loads the lint crates, to collect the lints they provide.
+pub fn collect_lints(config: &Config) -> Result<Vec<&'static Lint>> {
+    print_stage("compiling lints");
+    load_lints(&lints::build_lints(config)?)
+}
+
+/// Loads the given compiled lint crates, to collect the lints they provide.
+pub fn load_lints(lint_crates: &[LintCrate]) -> Result<Vec<&'static Lint>> {
+    let lints = load_registry(lint_crates)?
+        .lint_pass_infos()
+        .flat_map(LintPassInfo::lints)
+        .copied()
+        .collect();
+
+    Ok(lints)
+}
+
+fn load_registry(lint_crates: &[LintCrate]) -> Result<LintCrateRegistry> {
+    let lint_crates: Vec<_> = lint_crates
+        .iter()
+        .map(|LintCrate { name, file }| LintCrateInfo {
+            name: name.clone(),
+            path: file.clone(),
+            config: None,
+        })
+        .collect();
+
+    LintCrateRegistry::new(&lint_crates).context(|| "Failed to load the lint crates")
+}
+
+/// The explanation of the given lint, with the leading space of every doc
+/// comment line removed. The result is Markdown.
+pub fn lint_explanation(lint: &Lint) -> String {
+    let explanation = lint
+        .explanation
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    explanation.trim().to_string()
 }
 
-pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result {
+/// Options for [`run_check`], which control how diagnostics are reported.
+#[derive(Debug)]
+pub struct CheckOptions {
+    pub message_format: MessageFormat,
+    /// Diagnostics recorded in this baseline file are suppressed.
+    pub baseline: Option<Utf8PathBuf>,
+    /// All diagnostics of Marker lints are recorded in this baseline file.
+    pub write_baseline: Option<Utf8PathBuf>,
+}
+
+pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String], options: &CheckOptions) -> Result {
     let stage = "linting";
     print_stage(stage);
 
+    // The diagnostics have to be collected, if they're processed by Marker.
+    let collect_diags = options.message_format == MessageFormat::Sarif
+        || options.baseline.is_some()
+        || options.write_baseline.is_some();
+
     let mut cmd = config.toolchain.cargo_with_driver();
     cmd.arg("check");
+    if options.message_format == MessageFormat::Json {
+        cmd.arg("--message-format=json");
+    } else if collect_diags {
+        cmd.arg(diagnostics_message_format(additional_cargo_args)?);
+    }
     cmd.args(additional_cargo_args);
+    if options.baseline.is_some() || options.write_baseline.is_some() {
+        // Lints with the error level would stop the compilation of the crate
+        // and its dependents. All diagnostics are collected, if they're capped.
+        // The success of the check is then determined by Marker.
+        cmd.env("MARKER_CAP_LINTS", "warn");
+    }
 
     cmd.envs(info.env);
 
-    let exit_status = cmd
-        .log()
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let success = if collect_diags {
+        check_with_diagnostics(cmd, &info.lint_crates, options)?
+    } else {
+        cmd.log()
+            .spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?")
+            .success()
+    };
 
-    if exit_status.success() {
+    if success {
         return Ok(());
     }
 
     Err(Error::root(format!("{} finished with an error", display::stage(stage))))
 }
+
+/// Runs the given check command and processes the collected diagnostics, as
+/// requested by the options. Returns `true`, if the check was successful.
+fn check_with_diagnostics(cmd: Command, lint_crates: &[LintCrate], options: &CheckOptions) -> Result<bool> {
+    let mut matcher = options
+        .baseline
+        .as_deref()
+        .map(baseline::Baseline::load)
+        .transpose()?
+        .map(baseline::BaselineMatcher::new);
+
+    let (exit_status, diags) = run_with_diagnostics(cmd, |diag| {
+        matcher.as_mut().map_or(true, |matcher| !matcher.suppress(diag))
+    })?;
+    let mut success = exit_status.success();
+
+    if let Some(path) = &options.write_baseline {
+        // The Marker lints are capped while the baseline is written. Errors are
+        // therefore emitted by the compiler and would result in an incomplete
+        // baseline.
+        if !success {
+            return Err(Error::root("The check failed, the baseline was not written"));
+        }
+
+        let baseline = baseline::Baseline::from_diagnostics(&diags);
+        baseline.write(path)?;
+        let count = baseline.entries().len();
+        let entries = if count == 1 { "entry" } else { "entries" };
+        eprintln!("       {} {path} ({count} {entries})", "Wrote".bold().green());
+    }
+
+    if let Some(matcher) = &matcher {
+        if matcher.suppressed > 0 {
+            eprintln!("{} diagnostic(s) were suppressed by the baseline", matcher.suppressed);
+        }
+        let stale: Vec<_> = matcher.stale_entries().collect();
+        if !success {
+            // Crates depending on a failed crate are not checked. Their entries
+            // would incorrectly be reported as stale.
+            if !stale.is_empty() {
+                eprintln!("Stale baseline entries are only listed, if the check succeeds");
+            }
+        } else if !stale.is_empty() {
+            eprintln!(
+                "The baseline contains {} stale {}, which can be removed with {}:",
+                stale.len(),
+                if stale.len() == 1 { "entry" } else { "entries" },
+                display::cli("--write-baseline")
+            );
+            for entry in stale {
+                eprintln!("    {} in {}", entry.lint, entry.file);
+            }
+        }
+
+        // The Marker lints are capped, while a baseline is used. Lints with the
+        // error level are identified by the note added by the driver. Only the
+        // diagnostics, which weren't suppressed, fail the check.
+        success &= !diags
+            .iter()
+            .any(|diag| diag.children.iter().any(|child| child.message == CAPPED_LINT_NOTE));
+    }
+
+    if options.message_format == MessageFormat::Sarif {
+        let lints = load_lints(lint_crates)?;
+        let log = sarif::SarifLog::new(&lints, &diags);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&log).expect("the SARIF log is valid JSON")
+        );
+    }
+
+    Ok(success)
+}
+
+/// Returns the `--message-format` argument, which is used to collect the
+/// diagnostics. The rendered diagnostics only contain colors, if they're printed
+/// to a terminal. A message format passed to Cargo by the user is rejected, as
+/// it would change the messages, which are processed by Marker.
+fn diagnostics_message_format(additional_cargo_args: &[String]) -> Result<&'static str> {
+    let has_message_format = additional_cargo_args
+        .iter()
+        .take_while(|arg| *arg != "--")
+        .any(|arg| arg == "--message-format" || arg.starts_with("--message-format="));
+    if has_message_format {
+        return Err(Error::root(format!(
+            "{} can't be passed to Cargo, since Marker processes the diagnostics",
+            "--message-format".red()
+        )));
+    }
+
+    if std::io::stderr().is_terminal() {
+        Ok("--message-format=json-diagnostic-rendered-ansi")
+    } else {
+        Ok("--message-format=json")
+    }
+}
+
+/// Runs Marker like [`run_check`] and applies all machine-applicable suggestions
+/// emitted by lint crates to the source files inside `workspace_root`.
+pub fn run_fix(
+    config: &Config,
+    info: CheckInfo,
+    additional_cargo_args: &[String],
+    workspace_root: &Utf8Path,
+) -> Result {
+    let stage = "linting";
+    print_stage(stage);
+
+    let mut cmd = config.toolchain.cargo_with_driver();
+    cmd.arg("check");
+    cmd.arg(diagnostics_message_format(additional_cargo_args)?);
+    cmd.args(additional_cargo_args);
+
+    cmd.envs(info.env);
+
+    let (exit_status, diags) = run_with_diagnostics(cmd, |_| true)?;
+
+    // Suggestions are only applied, if the crate could be checked. Errors
+    // emitted by lint crates shouldn't prevent their own fixes.
+    let has_compiler_errors = diags.iter().any(|diag| {
+        matches!(diag.level, DiagnosticLevel::Error | DiagnosticLevel::Ice)
+            && !fix::is_marker_lint(diag)
+            // The summary, like "aborting due to 2 previous errors", is also an error
+            && !diag.message.starts_with("aborting due to")
+    });
+    if !exit_status.success() && has_compiler_errors {
+        return Err(Error::root(format!(
+            "{} finished with an error, no fixes were applied",
+            display::stage(stage)
+        )));
+    }
+
+    print_stage("applying fixes");
+    let suggestions = fix::collect_suggestions(&diags, workspace_root);
+    let plan = fix::plan_fixes(suggestions, workspace_root);
+    for (file, edits) in fix::write_fixes(&plan)? {
+        let file = file.strip_prefix(workspace_root).unwrap_or(file);
+        let fixes = if edits == 1 { "fix" } else { "fixes" };
+        println!("       {} {file} ({edits} {fixes})", "Fixed".bold().green());
+    }
+    if plan.applied == 0 {
+        println!("No machine-applicable suggestions were found");
+    }
+    if !plan.skipped.is_empty() {
+        println!(
+            "{} suggestion(s) overlapped with other fixes and were skipped. \
+            Running the command again might apply them.",
+            plan.skipped.len()
+        );
+    }
+
+    // Like `cargo marker check`, errors emitted by lint crates fail the command,
+    // even if some of them were fixed.
+    if !exit_status.success() {
+        return Err(Error::root(format!("{} finished with an error", display::stage(stage))));
+    }
+
+    Ok(())
+}
+
+/// Runs the given Cargo command, which has to emit JSON messages, and collects
+/// the diagnostics from its output. Diagnostics rejected by `filter` are
+/// neither collected nor displayed.
+fn run_with_diagnostics(
+    mut cmd: Command,
+    mut filter: impl FnMut(&Diagnostic) -> bool,
+) -> Result<(ExitStatus, Vec<Diagnostic>)> {
+    cmd.stdout(Stdio::piped());
+
+    let mut child = cmd.log().spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("stdout was configured to be piped");
+
+    let mut diags = vec![];
+    for message in Message::parse_stream(BufReader::new(stdout)) {
+        let message = message.context(|| "Failed to read the output of cargo")?;
+        let Message::CompilerMessage(msg) = message else {
+            continue;
+        };
+        if !filter(&msg.message) {
+            continue;
+        }
+
+        // The JSON output replaces the normal output of Cargo. The rendered
+        // diagnostics are therefore forwarded, to keep the user informed.
+        if let Some(rendered) = &msg.message.rendered {
+            eprint!("{rendered}");
+        }
+        diags.push(msg.message);
+    }
+
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+    Ok((exit_status, diags))
+}
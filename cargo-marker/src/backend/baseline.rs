@@ -0,0 +1,219 @@
+//! A baseline records the diagnostics of Marker lints, which have been emitted
+//! for the existing code. Diagnostics matching an entry of the baseline are
+//! suppressed in later runs, allowing new lints to be adopted gradually on large
+//! code bases.
+
+use super::fix;
+use crate::error::prelude::*;
+use crate::utils;
+use camino::Utf8Path;
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticSpan};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The version of the baseline file format. It should be incremented, if the
+/// format or the computation of fingerprints changes.
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Baseline {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+/// A single diagnostic recorded in the baseline. Line numbers are deliberately
+/// not part of the entry, to keep it valid when unrelated code is added or
+/// removed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BaselineEntry {
+    /// The full name of the lint, like `marker::lint_crate::lint_name`
+    pub lint: String,
+    /// The file of the primary span, as emitted by rustc
+    pub file: String,
+    /// A hash of the code snippet of the primary span
+    pub fingerprint: String,
+}
+
+impl BaselineEntry {
+    fn from_diagnostic(diag: &Diagnostic) -> Option<Self> {
+        if !fix::is_marker_lint(diag) {
+            return None;
+        }
+
+        let lint = diag.code.as_ref()?.code.clone();
+        let span = diag.spans.iter().find(|span| span.is_primary)?;
+        Some(Self {
+            lint,
+            file: span.file_name.clone(),
+            fingerprint: fingerprint(span),
+        })
+    }
+}
+
+impl Baseline {
+    /// Creates a baseline from all diagnostics of Marker lints.
+    pub fn from_diagnostics(diags: &[Diagnostic]) -> Self {
+        let mut entries: Vec<_> = diags.iter().filter_map(BaselineEntry::from_diagnostic).collect();
+        // Sorting the entries keeps the diff small, when the file is updated
+        entries.sort();
+
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    pub fn entries(&self) -> &[BaselineEntry] {
+        &self.entries
+    }
+
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).context(|| format!("Failed to read the baseline file `{path}`"))?;
+        let baseline: Self =
+            serde_json::from_str(&content).context(|| format!("Failed to parse the baseline file `{path}`"))?;
+
+        if baseline.version != BASELINE_VERSION {
+            return Err(Error::root(format!(
+                "The baseline file `{path}` has the unsupported version {}, expected version {BASELINE_VERSION}. \
+                Use `--write-baseline` to recreate it",
+                baseline.version
+            )));
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn write(&self, path: &Utf8Path) -> Result {
+        let mut content = serde_json::to_string_pretty(self).expect("the baseline is valid JSON");
+        content.push('\n');
+        std::fs::write(path, content).context(|| format!("Failed to write the baseline file `{path}`"))
+    }
+}
+
+/// Matches diagnostics against the entries of a [`Baseline`]. Every entry can
+/// only suppress a single diagnostic. This way, new diagnostics are reported,
+/// even if they're identical to a recorded one.
+#[derive(Debug)]
+pub struct BaselineMatcher {
+    remaining: BTreeMap<BaselineEntry, usize>,
+    /// The number of diagnostics, which were suppressed.
+    pub suppressed: usize,
+}
+
+impl BaselineMatcher {
+    pub fn new(baseline: Baseline) -> Self {
+        let mut remaining = BTreeMap::new();
+        for entry in baseline.entries {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        Self {
+            remaining,
+            suppressed: 0,
+        }
+    }
+
+    /// Returns `true`, if the diagnostic is recorded in the baseline and should
+    /// therefore be suppressed.
+    pub fn suppress(&mut self, diag: &Diagnostic) -> bool {
+        let Some(entry) = BaselineEntry::from_diagnostic(diag) else {
+            return false;
+        };
+        let Some(count) = self.remaining.get_mut(&entry).filter(|count| **count > 0) else {
+            return false;
+        };
+
+        *count -= 1;
+        self.suppressed += 1;
+        true
+    }
+
+    /// The entries of the baseline, which didn't match any diagnostic. The code
+    /// of these entries has most likely been fixed or removed.
+    pub fn stale_entries(&self) -> impl Iterator<Item = &BaselineEntry> {
+        self.remaining
+            .iter()
+            .flat_map(|(entry, count)| std::iter::repeat(entry).take(*count))
+    }
+}
+
+/// Computes the fingerprint of the code highlighted by the span. Each line is
+/// trimmed, to make the fingerprint independent of indentation changes.
+fn fingerprint(span: &DiagnosticSpan) -> String {
+    let snippet = span
+        .text
+        .iter()
+        .map(|line| {
+            let start = line.highlight_start.saturating_sub(1);
+            let len = line.highlight_end.saturating_sub(line.highlight_start);
+            line.text.chars().skip(start).take(len).collect::<String>()
+        })
+        .map(|line| line.trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    utils::stable_hash(snippet.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::test_utils;
+
+    fn diag(code: &str, file: &str, line: &str) -> Diagnostic {
+        test_utils::diagnostic(code, "message")
+            .span(test_utils::span(file).text(line))
+            .build()
+    }
+
+    #[test]
+    fn test_write_baseline() {
+        let diags = [
+            diag("marker::lints::b", "src/lib.rs", "    duck();"),
+            diag("marker::lints::a", "src/main.rs", "    duck();"),
+            diag("unused_variables", "src/lib.rs", "    duck();"),
+        ];
+        let baseline = Baseline::from_diagnostics(&diags);
+
+        expect_test::expect![[r#"
+            {
+              "version": 1,
+              "entries": [
+                {
+                  "lint": "marker::lints::a",
+                  "file": "src/main.rs",
+                  "fingerprint": "995f35e072598160"
+                },
+                {
+                  "lint": "marker::lints::b",
+                  "file": "src/lib.rs",
+                  "fingerprint": "995f35e072598160"
+                }
+              ]
+            }"#]]
+        .assert_eq(&serde_json::to_string_pretty(&baseline).unwrap());
+    }
+
+    #[test]
+    fn test_suppress_recorded_diagnostics() {
+        let recorded = diag("marker::lints::a", "src/lib.rs", "    duck();");
+        let baseline =
+            Baseline::from_diagnostics(&[recorded.clone(), diag("marker::lints::a", "src/lib.rs", "    goose();")]);
+        let mut matcher = BaselineMatcher::new(baseline);
+
+        // Indentation changes don't affect the fingerprint
+        assert!(matcher.suppress(&diag("marker::lints::a", "src/lib.rs", "        duck();")));
+        // Every entry only suppresses a single diagnostic
+        assert!(!matcher.suppress(&recorded));
+        assert!(!matcher.suppress(&diag("marker::lints::b", "src/lib.rs", "    duck();")));
+        assert!(!matcher.suppress(&diag("marker::lints::a", "src/main.rs", "    duck();")));
+        assert_eq!(matcher.suppressed, 1);
+
+        let stale: Vec<_> = matcher
+            .stale_entries()
+            .map(|entry| entry.fingerprint.as_str())
+            .collect();
+        assert_eq!(stale.len(), 1);
+        assert_ne!(stale[0], BaselineEntry::from_diagnostic(&recorded).unwrap().fingerprint);
+    }
+}
@@ -0,0 +1,321 @@
+//! This module collects machine-applicable suggestions from the diagnostics
+//! emitted by Marker lints and applies them to the source files.
+
+use crate::error::prelude::*;
+use crate::observability::prelude::*;
+use camino::{Utf8Path, Utf8PathBuf};
+use cargo_metadata::diagnostic::{Applicability, Diagnostic};
+use std::collections::BTreeMap;
+use std::fs;
+use std::ops::Range;
+use yansi::Paint;
+
+/// All lints registered by Marker are prefixed with this tool name.
+const MARKER_LINT_PREFIX: &str = "marker::";
+
+/// A single replacement of a byte range in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub file: Utf8PathBuf,
+    pub range: Range<usize>,
+    pub replacement: String,
+}
+
+/// A suggestion, which consists of one or more edits. The edits of a
+/// suggestion are only applied together, or not at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The name of the lint, which emitted this suggestion.
+    pub lint: String,
+    pub edits: Vec<Edit>,
+}
+
+/// The edits, which can be applied without conflicts.
+#[derive(Debug, Default)]
+pub struct FixPlan {
+    /// The accepted edits grouped by file. The edits of each file are sorted
+    /// by their start and don't overlap.
+    pub edits: BTreeMap<Utf8PathBuf, Vec<Edit>>,
+    /// The number of suggestions, which were accepted.
+    pub applied: usize,
+    /// Suggestions, which were skipped, since they overlap with a previously
+    /// accepted suggestion. They'll most likely be emitted again in the next run.
+    pub skipped: Vec<Suggestion>,
+}
+
+/// Returns `true`, if the diagnostic was emitted by a lint from a lint crate.
+pub fn is_marker_lint(diag: &Diagnostic) -> bool {
+    diag.code
+        .as_ref()
+        .map_or(false, |code| code.code.starts_with(MARKER_LINT_PREFIX))
+}
+
+/// Collects all machine-applicable suggestions of Marker lints from the given
+/// diagnostics. Relative file paths are resolved relative to `workspace_root`.
+pub fn collect_suggestions(diags: &[Diagnostic], workspace_root: &Utf8Path) -> Vec<Suggestion> {
+    let mut suggestions = vec![];
+    for diag in diags.iter().filter(|diag| is_marker_lint(diag)) {
+        let lint = diag.code.as_ref().map(|code| code.code.clone()).unwrap_or_default();
+
+        // Rustc attaches suggestions to the child diagnostics. Each child with
+        // replacements represents one suggestion.
+        for child in &diag.children {
+            let replacements: Vec<_> = child
+                .spans
+                .iter()
+                .filter(|span| span.suggested_replacement.is_some())
+                .collect();
+            if replacements.is_empty() {
+                continue;
+            }
+
+            // A suggestion is only machine-applicable, if all parts of it are.
+            let machine_applicable = replacements
+                .iter()
+                .all(|span| matches!(span.suggestion_applicability, Some(Applicability::MachineApplicable)));
+            if !machine_applicable {
+                continue;
+            }
+
+            let edits = replacements
+                .into_iter()
+                .map(|span| Edit {
+                    file: workspace_root.join(&span.file_name),
+                    range: span.byte_start as usize..span.byte_end as usize,
+                    replacement: span.suggested_replacement.clone().unwrap_or_default(),
+                })
+                .collect();
+            suggestions.push(Suggestion {
+                lint: lint.clone(),
+                edits,
+            });
+        }
+    }
+
+    suggestions
+}
+
+/// Selects the suggestions, which can be applied together. Suggestions are
+/// accepted in the order they were emitted. A suggestion is skipped, if any of
+/// its edits overlaps with an edit of a previously accepted suggestion.
+///
+/// Cargo can emit the same diagnostic multiple times, for example, when a file
+/// is part of the library and test target. Suggestions with edits identical
+/// to accepted ones are therefore ignored.
+pub fn plan_fixes(suggestions: Vec<Suggestion>, workspace_root: &Utf8Path) -> FixPlan {
+    let mut plan = FixPlan::default();
+
+    for suggestion in suggestions {
+        // Marker should never modify files outside of the current workspace,
+        // like the sources of registry dependencies.
+        if suggestion
+            .edits
+            .iter()
+            .any(|edit| !edit.file.starts_with(workspace_root))
+        {
+            debug!(lint = %suggestion.lint, "Ignoring suggestion for a file outside the workspace");
+            continue;
+        }
+
+        let is_duplicate = suggestion.edits.iter().all(|edit| {
+            plan.edits
+                .get(&edit.file)
+                .map_or(false, |accepted| accepted.contains(edit))
+        });
+        if is_duplicate {
+            continue;
+        }
+
+        let overlaps = suggestion.edits.iter().enumerate().any(|(index, edit)| {
+            let overlaps_accepted = plan
+                .edits
+                .get(&edit.file)
+                .map_or(false, |accepted| accepted.iter().any(|other| overlap(edit, other)));
+            let overlaps_sibling = suggestion.edits[..index]
+                .iter()
+                .any(|other| other.file == edit.file && overlap(edit, other));
+            overlaps_accepted || overlaps_sibling
+        });
+        if overlaps {
+            plan.skipped.push(suggestion);
+            continue;
+        }
+
+        for edit in suggestion.edits {
+            let edits = plan.edits.entry(edit.file.clone()).or_default();
+            let index = edits.partition_point(|other| other.range.start <= edit.range.start);
+            edits.insert(index, edit);
+        }
+        plan.applied += 1;
+    }
+
+    plan
+}
+
+/// Checks if two edits conflict with each other. Two insertions at the same
+/// position are considered to overlap, as the order of the inserted text
+/// would be ambiguous.
+fn overlap(a: &Edit, b: &Edit) -> bool {
+    if a.range == b.range {
+        return true;
+    }
+    a.range.start < b.range.end && b.range.start < a.range.end
+}
+
+/// Applies the given edits to the source. The edits have to be sorted and
+/// must not overlap.
+pub fn apply_edits(source: &str, edits: &[Edit]) -> Result<String> {
+    let mut result = String::with_capacity(source.len());
+    let mut last_end = 0;
+    for edit in edits {
+        let Range { start, end } = edit.range.clone();
+        if start < last_end || end > source.len() || !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+            return Err(Error::root(format!(
+                "The suggested edit for {}..{} in {} doesn't match the file content",
+                start,
+                end,
+                edit.file.red()
+            )));
+        }
+
+        result.push_str(&source[last_end..start]);
+        result.push_str(&edit.replacement);
+        last_end = end;
+    }
+    result.push_str(&source[last_end..]);
+
+    Ok(result)
+}
+
+/// Writes the planned edits to the files and returns the number of edits per
+/// modified file.
+pub fn write_fixes(plan: &FixPlan) -> Result<Vec<(&Utf8Path, usize)>> {
+    let mut modified = vec![];
+    for (file, edits) in &plan.edits {
+        let source = fs::read_to_string(file).context(|| format!("Failed to read {}", file.red()))?;
+        let fixed = apply_edits(&source, edits)?;
+        fs::write(file, fixed).context(|| format!("Failed to write {}", file.red()))?;
+
+        info!(%file, edits = edits.len(), "Applied fixes");
+        modified.push((file.as_path(), edits.len()));
+    }
+
+    Ok(modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::test_utils;
+    use expect_test::{expect, Expect};
+
+    const ROOT: &str = "/workspace";
+
+    fn edit(file: &str, range: Range<usize>, replacement: &str) -> Edit {
+        Edit {
+            file: Utf8Path::new(ROOT).join(file),
+            range,
+            replacement: replacement.to_string(),
+        }
+    }
+
+    fn suggestion(edits: Vec<Edit>) -> Suggestion {
+        Suggestion {
+            lint: "marker::test::lint".to_string(),
+            edits,
+        }
+    }
+
+    fn assert_fixed(source: &str, suggestions: Vec<Suggestion>, expect: &Expect) {
+        let plan = plan_fixes(suggestions, Utf8Path::new(ROOT));
+        let edits = plan.edits.values().flatten().cloned().collect::<Vec<_>>();
+        let actual = format!(
+            "{}\napplied: {}, skipped: {}",
+            apply_edits(source, &edits).unwrap(),
+            plan.applied,
+            plan.skipped.len()
+        );
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn test_apply_sorted_edits() {
+        assert_fixed(
+            "let x = 1 + 2;",
+            vec![
+                suggestion(vec![edit("lib.rs", 12..13, "3")]),
+                suggestion(vec![edit("lib.rs", 4..5, "y")]),
+            ],
+            &expect![[r"
+                let y = 1 + 3;
+                applied: 2, skipped: 0"]],
+        );
+    }
+
+    #[test]
+    fn test_skip_overlapping_suggestions() {
+        assert_fixed(
+            "let x = 1 + 2;",
+            vec![
+                suggestion(vec![edit("lib.rs", 8..13, "3")]),
+                suggestion(vec![edit("lib.rs", 12..13, "4")]),
+                // Multipart suggestions are skipped completely
+                suggestion(vec![edit("lib.rs", 4..5, "y"), edit("lib.rs", 10..11, "-")]),
+            ],
+            &expect![[r"
+                let x = 3;
+                applied: 1, skipped: 2"]],
+        );
+    }
+
+    #[test]
+    fn test_ignore_duplicate_suggestions() {
+        assert_fixed(
+            "let x = 1 + 2;",
+            vec![
+                suggestion(vec![edit("lib.rs", 4..5, "y")]),
+                suggestion(vec![edit("lib.rs", 4..5, "y")]),
+            ],
+            &expect![[r"
+                let y = 1 + 2;
+                applied: 1, skipped: 0"]],
+        );
+    }
+
+    #[test]
+    fn test_ignore_files_outside_workspace() {
+        let plan = plan_fixes(
+            vec![suggestion(vec![Edit {
+                file: Utf8PathBuf::from("/registry/dep/src/lib.rs"),
+                range: 0..1,
+                replacement: String::new(),
+            }])],
+            Utf8Path::new(ROOT),
+        );
+        assert!(plan.edits.is_empty());
+        assert_eq!(plan.applied, 0);
+    }
+
+    #[test]
+    fn test_collect_machine_applicable_suggestions() {
+        let diag = |code: &str, applicability: &str| {
+            test_utils::diagnostic(code, "message")
+                .help("try", [test_utils::span("src/lib.rs").suggestion("y", applicability)])
+                .build()
+        };
+
+        let diags = [
+            diag("marker::marker_lints::lint", "MachineApplicable"),
+            diag("marker::marker_lints::lint", "MaybeIncorrect"),
+            diag("unused_variables", "MachineApplicable"),
+        ];
+        let suggestions = collect_suggestions(&diags, Utf8Path::new(ROOT));
+        assert_eq!(
+            suggestions,
+            vec![Suggestion {
+                lint: "marker::marker_lints::lint".to_string(),
+                edits: vec![edit("src/lib.rs", 4..5, "y")],
+            }]
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use super::Config;
 use crate::error::prelude::*;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
 
 mod build;
 mod fetch;
+mod fingerprint;
+mod vendor;
 
 /// This struct contains all information of a lint crate required to compile
 /// the crate. See the [fetch] module for how external crates are fetched and
@@ -13,12 +16,15 @@ pub struct LintCrateSource {
     /// The name of the package, for now we can assume that this is the name
     /// that will be used to construct the dynamic library.
     name: String,
+    /// The version of the package. Together with the name, it identifies the
+    /// package in the dependency graph of the dummy crate.
+    version: String,
     /// The absolute path to the manifest of this lint crate
     manifest: Utf8PathBuf,
 }
 
 /// The information of a compiled lint crate.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LintCrate {
     /// The name of the crate
     pub name: String,
@@ -35,3 +41,9 @@ pub fn build_lints(config: &Config) -> Result<Vec<LintCrate>> {
     let sources = fetch::fetch_crates(config)?;
     build::build_lints(&sources, config)
 }
+
+/// This function copies all lints specified in the given [`Config`] and their
+/// dependencies into the given directory.
+pub fn vendor_lints(config: &Config, dir: &Utf8Path) -> Result {
+    vendor::vendor_lints(config, dir)
+}
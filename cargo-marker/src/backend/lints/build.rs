@@ -1,11 +1,14 @@
+use super::fingerprint::{self, BuildInputs};
 use super::{LintCrate, LintCrateSource};
 use crate::backend::Config;
 use crate::error::prelude::*;
 use crate::observability::prelude::*;
-use crate::utils::utf8::IntoUtf8;
 use camino::Utf8Path;
+use cargo_metadata::{Artifact, Message};
 use itertools::Itertools;
-use std::{collections::HashSet, ffi::OsStr};
+use std::ffi::OsStr;
+use std::io::BufReader;
+use std::process::Stdio;
 use yansi::Paint;
 
 #[cfg(target_os = "linux")]
@@ -28,53 +31,98 @@ const ARTIFACT_ENDINGS: &[&str] = &[
 ];
 
 pub fn build_lints(sources: &[LintCrateSource], config: &Config) -> Result<Vec<LintCrate>> {
-    // By default Cargo doesn't provide the path of the compiled lint crate.
-    // As a work around, we use the `--out-dir` option to make cargo copy all
-    // created binaries into one folder. We then scan that folder as we build our crates
-    // and collect all dynamic libraries that show up, we link the name of the crate we just built
-    // with the file(s) we found after we built it, assuming that they are related.
-    //
-    // TODO Look into how to optimize this a bit better:
-    // Another option that would be potentially more performant is if we built each
-    // crate in it's own target dir. this would eliminate the need for HashSet<_> below, without
-    // changing too much else about the implementation.
-    //
-    // This would be so much simpler if we could get an output name from Cargo
+    let inputs = BuildInputs::new(sources, config);
+    if let Some(lints) = fingerprint::fresh_lint_crates(&inputs, config) {
+        return Ok(lints);
+    }
 
     // Clear previously build lints
     let lints_dir = config.lint_crate_dir();
     clear_lints_dir(&lints_dir)?;
 
-    // Build lint crates and find the output of those builds
-    let mut found_paths = HashSet::new();
-    let ending = DYNAMIC_LIB_FILE_ENDING;
-    let mut lints = Vec::with_capacity(sources.len());
+    // All lint crates are dependencies of the dummy crate. This allows Cargo to
+    // build them in a single invocation, in parallel and with shared dependencies.
+    // The compiled libraries are then copied into the lints directory.
+    let artifacts = build_dummy_crate(sources, config)?;
 
+    std::fs::create_dir_all(&lints_dir).context(|| format!("Failed to create the lints directory {lints_dir}"))?;
+    let mut lints = Vec::with_capacity(sources.len());
+    let mut dep_info_files = Vec::with_capacity(sources.len());
     for lint_src in sources {
-        build_lint(lint_src, config)?;
-        match std::fs::read_dir(&lints_dir) {
-            Ok(dir) => {
-                for file in dir {
-                    let file = file.unwrap().path().into_utf8()?;
-                    if file.extension() == Some(ending) && !found_paths.contains(&file) {
-                        found_paths.insert(file.clone());
-                        lints.push(LintCrate {
-                            file,
-                            name: lint_src.name.clone(),
-                        });
-                    }
-                }
-            },
-            Err(err) => {
-                // This shouldn't really be a point of failure. In this case, I'm
-                // more interested in the HOW?
-                panic!("unable to read lints dir after lint compilation: {lints_dir} ({err:#?})");
-            },
+        let Some(artifact) = artifacts
+            .iter()
+            .find(|artifact| artifact.manifest_path == lint_src.manifest)
+        else {
+            return Err(Error::root(format!(
+                "Cargo didn't build the lint crate {}. Make sure that it's a `cdylib`",
+                lint_src.name.red().bold()
+            )));
+        };
+
+        for file in &artifact.filenames {
+            if file.extension() != Some(DYNAMIC_LIB_FILE_ENDING) {
+                continue;
+            }
+
+            let target = lints_dir.join(file.file_name().expect("the artifact is a file"));
+            std::fs::copy(file, &target).context(|| format!("Failed to copy the lint crate {file} to {target}"))?;
+            dep_info_files.push(file.with_extension("d"));
+            lints.push(LintCrate {
+                file: target,
+                name: lint_src.name.clone(),
+            });
         }
     }
+
+    // The fingerprint only allows later runs to skip the build. Failing to
+    // save it, shouldn't fail the check.
+    if let Err(err) = fingerprint::save(inputs, &lints, &dep_info_files, config) {
+        warn!(%err, "Failed to save the fingerprint of the lint crates, they'll be built again");
+    }
+
     Ok(lints)
 }
 
+/// Builds the given lint crates, via the dummy crate. This returns the
+/// `cdylib` artifacts reported by Cargo.
+fn build_dummy_crate(sources: &[LintCrateSource], config: &Config) -> Result<Vec<Artifact>> {
+    let mut cmd = config.toolchain.cargo_build_command(config, &config.dummy_manifest());
+    for lint_src in sources {
+        // Several packages with the same name can be part of the dependency
+        // graph. The version makes the package ID specification unambiguous.
+        cmd.arg("--package");
+        cmd.arg(format!("{}@{}", lint_src.name, lint_src.version));
+    }
+
+    // The JSON messages are used to find the compiled libraries. Diagnostics
+    // are still rendered for the user, by Cargo.
+    cmd.arg("--message-format=json-render-diagnostics");
+    cmd.stdout(Stdio::piped());
+
+    let mut child = cmd.log().spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("stdout was configured to be piped");
+
+    let mut artifacts = vec![];
+    for message in Message::parse_stream(BufReader::new(stdout)) {
+        let message = message.context(|| "Failed to read the output of cargo")?;
+        if let Message::CompilerArtifact(artifact) = message {
+            if artifact.target.kind.iter().any(|kind| kind == "cdylib") {
+                artifacts.push(artifact);
+            }
+        }
+    }
+
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+    if exit_status.success() {
+        return Ok(artifacts);
+    }
+
+    Err(Error::root(format!(
+        "Failed to compile the lint crates {}",
+        sources.iter().map(|lint_src| lint_src.name.red().bold()).join(", ")
+    )))
+}
+
 /// This function clears the `marker/lints` directory holding all compiled lints. This
 /// is required, as Marker uses the content of that directory to determine which lints
 /// should be run.
@@ -116,29 +164,3 @@ fn clear_lints_dir(lints_dir: &Utf8Path) -> Result {
     // The dir should now be empty
     std::fs::remove_dir(lints_dir).context(|| format!("Failed to remove lints directory {lints_dir}"))
 }
-
-fn build_lint(lint_src: &LintCrateSource, config: &Config) -> Result {
-    let mut cmd = config.toolchain.cargo_build_command(config, &lint_src.manifest);
-
-    // Set output dir. This currently requires unstable options
-    cmd.arg("-Z");
-    cmd.arg("unstable-options");
-    cmd.arg("--out-dir");
-    cmd.arg(config.lint_crate_dir().as_os_str());
-
-    let exit_status = cmd
-        .log()
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
-
-    if exit_status.success() {
-        return Ok(());
-    }
-
-    Err(Error::root(format!(
-        "Failed to compile the lint crate {}",
-        lint_src.name.red().bold()
-    )))
-}
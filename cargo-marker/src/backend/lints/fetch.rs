@@ -8,15 +8,18 @@
 //! This module uses a small hack. Marker creates a new Cargo project, with the
 //! specified lint crates as dependencies. Then `cargo fetch` is called, which
 //! will download the crates into Cargo's cache. The absolute path to the lints
-//! can then be retrieved from `cargo metadata`.
+//! can then be retrieved from `cargo metadata`. The same dummy crate is later
+//! used to build all lint crates in a single Cargo invocation.
 
-use super::LintCrateSource;
+use super::{vendor, LintCrateSource};
 use crate::error::prelude::*;
 use crate::observability::prelude::*;
 use crate::{backend::Config, config::LintDependencyEntry};
 use camino::{Utf8Path, Utf8PathBuf};
-use cargo_metadata::Metadata;
+use cargo_metadata::{Metadata, Package};
+use itertools::Itertools;
 use std::collections::BTreeMap;
+use yansi::Paint;
 
 /// This function fetches and locates all lint crates specified in the given
 /// configuration.
@@ -24,32 +27,55 @@ pub fn fetch_crates(config: &Config) -> Result<Vec<LintCrateSource>> {
     // FIXME(xFrednet): Only create the dummy crate, if there is a non
     // local dependency.
 
-    let manifest = setup_dummy_crate(config)?;
+    if config.vendor_dir.is_some() {
+        vendor::setup_vendored_sources(config)?;
+    }
+
+    // Features are omitted at first. Otherwise, Cargo would fail with a
+    // resolver error for unknown features, instead of Marker's validation.
+    let manifest = setup_dummy_crate(config, false)?;
 
     call_cargo_fetch(&manifest, config)?;
 
     let metadata = call_cargo_metadata(&manifest, config)?;
 
-    Ok(extract_lint_crate_sources(&metadata, config))
+    let sources = extract_lint_crate_sources(&metadata, config)?;
+
+    // The validated features are added, as the dummy crate is also used to
+    // build the lint crates.
+    setup_dummy_crate(config, true)?;
+
+    Ok(sources)
 }
 
 /// This function sets up the dummy crate with all the lints listed as dependencies.
 /// It returns the path of the manifest, if everything was successful.
-fn setup_dummy_crate(config: &Config) -> Result<Utf8PathBuf> {
+pub(super) fn setup_dummy_crate(config: &Config, with_features: bool) -> Result<Utf8PathBuf> {
     /// A small hack, to have the lints namespaced under the `[dependencies]` section
     #[derive(serde::Serialize)]
     struct DepNamespace<'a> {
-        dependencies: &'a BTreeMap<String, LintDependencyEntry>,
+        dependencies: BTreeMap<&'a String, LintDependencyEntry>,
     }
 
+    let dependencies = config
+        .lints
+        .iter()
+        .map(|(name, entry)| {
+            let mut entry = entry.clone();
+            if !with_features {
+                entry.default_features = None;
+                entry.features = None;
+            }
+            (name, entry)
+        })
+        .collect();
+
     // Manifest
-    let lints_as_deps = toml::to_string(&DepNamespace {
-        dependencies: &config.lints,
-    })
-    .expect("DepNamespace can be represented as TOML");
+    let lints_as_deps =
+        toml::to_string(&DepNamespace { dependencies }).expect("DepNamespace can be represented as TOML");
 
     let manifest_content = format!("{DUMMY_MANIFEST_TEMPLATE}{lints_as_deps}");
-    let manifest_path = config.marker_dir.join("Cargo.toml");
+    let manifest_path = config.dummy_manifest();
     write_to_file(&manifest_path, &manifest_content)?;
 
     // `./src/main.rs` file
@@ -71,7 +97,7 @@ fn write_to_file(path: &Utf8Path, content: &str) -> Result {
 const DUMMY_MANIFEST_TEMPLATE: &str = r#"
 # This is a dummy crate used by Marker, to get Cargo to fetch the lint crates
 # as normal dependencies. The location of the fetched crates is then read using
-# `cargo metadata`.
+# `cargo metadata`. The lint crates are also built as dependencies of this crate.
 
 [package]
 name = "markers-dummy-crate-for-fetching"
@@ -98,6 +124,7 @@ fn call_cargo_fetch(manifest: &Utf8Path, config: &Config) -> Result {
     cmd.arg("fetch");
     cmd.arg("--manifest-path");
     cmd.arg(manifest.as_os_str());
+    cmd.args(config.lint_crate_cargo_args());
 
     // Only fetch for the specified target. Cargo will just fetch everything,
     // if the `--target` flag is not specified.
@@ -126,18 +153,53 @@ fn call_cargo_metadata(manifest: &Utf8Path, config: &Config) -> Result<Metadata>
         .cargo
         .metadata()
         .manifest_path(manifest)
+        .other_options(config.lint_crate_cargo_args())
         .exec()
         .context(|| format!("Failed to get cargo metadata for the lint crates at {manifest}"))
 }
 
-fn extract_lint_crate_sources(metadata: &Metadata, marker_config: &Config) -> Vec<LintCrateSource> {
-    metadata
+fn extract_lint_crate_sources(metadata: &Metadata, marker_config: &Config) -> Result<Vec<LintCrateSource>> {
+    let (sources, errors): (Vec<_>, Vec<_>) = metadata
         .packages
         .iter()
-        .filter(|pkg| marker_config.lints.contains_key(&pkg.name))
-        .map(|pkg| LintCrateSource {
-            name: pkg.name.clone(),
-            manifest: pkg.manifest_path.clone(),
+        .filter_map(|pkg| Some((pkg, marker_config.lints.get(&pkg.name)?)))
+        .map(|(pkg, entry)| {
+            validate_features(pkg, entry.features.as_deref().unwrap_or_default())?;
+            Ok(LintCrateSource {
+                name: pkg.name.clone(),
+                version: pkg.version.to_string(),
+                manifest: pkg.manifest_path.clone(),
+            })
         })
-        .collect()
+        .partition_result();
+
+    Error::try_many(errors, "Invalid features were specified for lint crates")?;
+
+    Ok(sources)
+}
+
+/// Checks that all requested features are declared by the lint crate. Features
+/// of dependencies, like `dep/feature`, are left to Cargo.
+fn validate_features(pkg: &Package, features: &[String]) -> Result {
+    let unknown: Vec<_> = features
+        .iter()
+        .filter(|feature| !feature.contains('/') && !pkg.features.contains_key(*feature))
+        .collect();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let available = if pkg.features.is_empty() {
+        "The lint crate doesn't declare any features".to_string()
+    } else {
+        format!(
+            "Available features: {}",
+            pkg.features.keys().map(|feature| feature.green()).join(", ")
+        )
+    };
+    Err(Error::root(format!(
+        "The lint crate {} doesn't have the feature(s) {}. {available}",
+        pkg.name.red().bold(),
+        unknown.iter().map(|feature| feature.red()).join(", "),
+    )))
 }
@@ -0,0 +1,181 @@
+//! Building lint crates takes a while, even if Cargo has nothing to do. This
+//! module stores a fingerprint of the last successful build in Marker's target
+//! directory. The build is skipped, if the fingerprint still matches.
+//!
+//! The fingerprint consists of the [`BuildInputs`] and the source files of the
+//! lint crates. The source files are taken from the dep-info files emitted by
+//! Cargo. These list all local source files, including the ones of path
+//! dependencies. Files of registry and git dependencies are immutable and
+//! therefore covered by the lockfile.
+
+use super::{LintCrate, LintCrateSource};
+use crate::backend::Config;
+use crate::error::prelude::*;
+use crate::utils;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use std::time::UNIX_EPOCH;
+
+/// Everything that affects the build of the lint crates, apart from their
+/// source files.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct BuildInputs {
+    marker_api_version: String,
+    toolchain: Option<String>,
+    driver: Option<FileStamp>,
+    debug_build: bool,
+    rustc_flags: String,
+    /// The hash of the dummy manifest, which contains the lint crates with
+    /// their version, source and features.
+    manifest_hash: String,
+    /// The hash of the lockfile of the dummy crate.
+    lockfile_hash: String,
+    /// The manifests of the lint crates. These aren't part of dep-info files.
+    lint_manifests: Vec<Option<FileStamp>>,
+}
+
+impl BuildInputs {
+    pub fn new(sources: &[LintCrateSource], config: &Config) -> Self {
+        let manifest = config.dummy_manifest();
+        let hash_file = |path: &Utf8Path| {
+            let content = std::fs::read(path).unwrap_or_default();
+            utils::stable_hash(&content)
+        };
+
+        Self {
+            marker_api_version: marker_api::MARKER_API_VERSION.to_string(),
+            toolchain: config.toolchain.cargo.toolchain.clone(),
+            driver: FileStamp::new(&config.toolchain.driver_path),
+            debug_build: config.debug_build,
+            rustc_flags: config.build_rustc_flags.clone(),
+            manifest_hash: hash_file(&manifest),
+            lockfile_hash: hash_file(&manifest.with_file_name("Cargo.lock")),
+            lint_manifests: sources.iter().map(|src| FileStamp::new(&src.manifest)).collect(),
+        }
+    }
+}
+
+/// The size and modification time of a file, used to detect changes.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct FileStamp {
+    path: Utf8PathBuf,
+    len: u64,
+    /// The modification time in nanoseconds since the Unix epoch
+    modified: u64,
+}
+
+impl FileStamp {
+    fn new(path: &Utf8Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+        Some(Self {
+            path: path.to_path_buf(),
+            len: metadata.len(),
+            modified: u64::try_from(modified.as_nanos()).ok()?,
+        })
+    }
+
+    fn is_unchanged(&self) -> bool {
+        FileStamp::new(&self.path).as_ref() == Some(self)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Fingerprint {
+    inputs: BuildInputs,
+    sources: Vec<FileStamp>,
+    lint_crates: Vec<LintCrate>,
+}
+
+fn fingerprint_path(config: &Config) -> Utf8PathBuf {
+    config.marker_dir.join("lints-fingerprint.json")
+}
+
+/// Returns the lint crates of the last build, if they're still up to date.
+pub fn fresh_lint_crates(inputs: &BuildInputs, config: &Config) -> Option<Vec<LintCrate>> {
+    let content = std::fs::read_to_string(fingerprint_path(config)).ok()?;
+    let fingerprint: Fingerprint = serde_json::from_str(&content).ok()?;
+
+    let is_fresh = fingerprint.inputs == *inputs
+        && fingerprint.sources.iter().all(FileStamp::is_unchanged)
+        && fingerprint.lint_crates.iter().all(|krate| krate.file.exists());
+    is_fresh.then_some(fingerprint.lint_crates)
+}
+
+/// Stores the fingerprint of a successful build. The source files are read
+/// from the given dep-info files. No fingerprint is stored, if one of them
+/// is missing.
+pub fn save(inputs: BuildInputs, lint_crates: &[LintCrate], dep_info_files: &[Utf8PathBuf], config: &Config) -> Result {
+    let mut sources = vec![];
+    for dep_info in dep_info_files {
+        let content =
+            std::fs::read_to_string(dep_info).context(|| format!("Failed to read the dep-info file {dep_info}"))?;
+        for file in parse_dep_info(&content) {
+            // A missing file can't be tracked. Without a fingerprint, the lint
+            // crates will simply be built again, during the next run.
+            let Some(stamp) = FileStamp::new(Utf8Path::new(&file)) else {
+                let _ = std::fs::remove_file(fingerprint_path(config));
+                return Ok(());
+            };
+            sources.push(stamp);
+        }
+    }
+    sources.sort_by(|a, b| a.path.cmp(&b.path));
+    sources.dedup_by(|a, b| a.path == b.path);
+
+    let fingerprint = Fingerprint {
+        inputs,
+        sources,
+        lint_crates: lint_crates.to_vec(),
+    };
+    let content = serde_json::to_string_pretty(&fingerprint).expect("the fingerprint is valid JSON");
+    let path = fingerprint_path(config);
+    std::fs::write(&path, content).context(|| format!("Failed to write the lint crate fingerprint to {path}"))
+}
+
+/// Parses the dependencies listed in a Makefile-style dep-info file, like:
+///
+/// ```text
+/// /target/release/liblints.so: /lints/src/lib.rs /lints/src/with\ space.rs
+/// ```
+fn parse_dep_info(content: &str) -> Vec<String> {
+    let mut files = vec![];
+    for line in content.lines() {
+        let Some((_target, deps)) = line.split_once(": ") else {
+            continue;
+        };
+
+        let mut file = String::new();
+        let mut chars = deps.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                // Only spaces are escaped, Windows paths contain backslashes
+                '\\' if chars.peek() == Some(&' ') => file.extend(chars.next()),
+                ' ' if !file.is_empty() => files.push(std::mem::take(&mut file)),
+                ' ' => {},
+                _ => file.push(c),
+            }
+        }
+        if !file.is_empty() {
+            files.push(file);
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dep_info() {
+        let content = "/target/release/liblints.so: /lints/src/lib.rs /lints/src/with\\ space.rs\n\
+            \n\
+            /lints/src/lib.rs:\n";
+        assert_eq!(
+            parse_dep_info(content),
+            vec!["/lints/src/lib.rs", "/lints/src/with space.rs"]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Air-gapped environments can't fetch lint crates from registries or git. This
+//! module uses `cargo vendor` to copy the lint crates and their dependencies
+//! into a local directory. The directory can then be used, to fetch and build
+//! the lint crates without network access.
+//!
+//! Besides the vendored crates, the directory contains a `.marker` directory
+//! with the source replacement config printed by `cargo vendor` and the
+//! lockfile of the dummy crate. Cargo ignores hidden directories in vendored
+//! sources.
+
+use super::fetch;
+use crate::backend::Config;
+use crate::error::prelude::*;
+use crate::observability::prelude::*;
+use camino::Utf8Path;
+use std::process::Stdio;
+
+const MARKER_FILES_DIR: &str = ".marker";
+const SOURCE_CONFIG_FILE: &str = "config.toml";
+const LOCKFILE: &str = "Cargo.lock";
+
+pub fn vendor_lints(config: &Config, dir: &Utf8Path) -> Result {
+    // This validates the lint crates and writes the dummy crate, with all
+    // lint crates as dependencies.
+    fetch::fetch_crates(config)?;
+
+    std::fs::create_dir_all(dir).context(|| format!("Failed to create the vendor directory {dir}"))?;
+    let dir = dir
+        .canonicalize_utf8()
+        .context(|| format!("Failed to resolve the vendor directory {dir}"))?;
+
+    let mut cmd = config.toolchain.cargo.command();
+    cmd.arg("vendor");
+    cmd.arg("--manifest-path");
+    cmd.arg(config.dummy_manifest().as_os_str());
+    cmd.args(config.lint_crate_cargo_args());
+    cmd.arg(dir.as_os_str());
+    cmd.stdout(Stdio::piped());
+
+    let output = cmd
+        .log()
+        .spawn()
+        .expect("unable to start `cargo vendor` to vendor lint crates")
+        .wait_with_output()
+        .expect("unable to wait for `cargo vendor` to vendor lint crates");
+    if !output.status.success() {
+        return Err(Error::root("cargo vendor failed for lint crates"));
+    }
+
+    // The printed config is empty, if all lint crates and their dependencies
+    // are local crates.
+    let source_config = String::from_utf8(output.stdout).context(|| "The output of `cargo vendor` is not UTF-8")?;
+    let marker_files = dir.join(MARKER_FILES_DIR);
+    std::fs::create_dir_all(&marker_files).context(|| format!("Failed to create the directory {marker_files}"))?;
+    std::fs::write(marker_files.join(SOURCE_CONFIG_FILE), source_config)
+        .context(|| format!("Failed to write the source config to {marker_files}"))?;
+
+    let lockfile = config.dummy_manifest().with_file_name(LOCKFILE);
+    std::fs::copy(&lockfile, marker_files.join(LOCKFILE))
+        .context(|| format!("Failed to copy the lockfile {lockfile} to {marker_files}"))?;
+
+    Ok(())
+}
+
+/// Writes the source replacement config for the vendor directory, which is
+/// passed to all Cargo commands of the dummy crate. The lockfile of the vendor
+/// directory is used for the dummy crate, to select the vendored versions.
+pub(super) fn setup_vendored_sources(config: &Config) -> Result {
+    let dir = config
+        .vendor_dir
+        .as_ref()
+        .expect("this is only called with a vendor directory");
+    let marker_files = dir.join(MARKER_FILES_DIR);
+
+    let source_config_path = marker_files.join(SOURCE_CONFIG_FILE);
+    let content = std::fs::read_to_string(&source_config_path).context(|| {
+        format!(
+            "Failed to read {source_config_path}. Make sure that the directory was created by `cargo marker vendor`"
+        )
+    })?;
+    let mut source_config: toml::Table =
+        toml::from_str(&content).context(|| format!("Failed to parse the source config at {source_config_path}"))?;
+
+    // `cargo vendor` prints the absolute path of the vendor directory. It's
+    // replaced, to allow moving the directory, for example to another machine.
+    if let Some(sources) = source_config.get_mut("source").and_then(toml::Value::as_table_mut) {
+        for (_name, source) in sources.iter_mut() {
+            if let Some(directory) = source.get_mut("directory") {
+                *directory = dir.as_str().into();
+            }
+        }
+    }
+
+    std::fs::create_dir_all(&config.marker_dir)
+        .context(|| format!("Failed to create the directory {}", config.marker_dir))?;
+    let vendor_config = config.vendor_config();
+    std::fs::write(&vendor_config, source_config.to_string())
+        .context(|| format!("Failed to write the source config to {vendor_config}"))?;
+
+    let lockfile = marker_files.join(LOCKFILE);
+    let target = config.dummy_manifest().with_file_name(LOCKFILE);
+    std::fs::copy(&lockfile, &target).context(|| format!("Failed to copy the lockfile {lockfile} to {target}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,424 @@
+//! This module converts the diagnostics emitted by Marker lints into the
+//! [SARIF] format, which is used by code scanning tools.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use super::{fix, lint_explanation};
+use cargo_metadata::diagnostic::{Applicability, Diagnostic, DiagnosticLevel, DiagnosticSpan};
+use marker_api::{common::Level, Lint};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Serialize, Debug)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize, Debug)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize, Debug)]
+struct Tool {
+    driver: ToolComponent,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    short_description: Message,
+    full_description: Message,
+    help: Message,
+    default_configuration: RuleConfiguration,
+}
+
+#[derive(Serialize, Debug)]
+struct RuleConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Message {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    markdown: Option<String>,
+}
+
+impl Message {
+    fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            markdown: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize, Debug)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: InsertedContent,
+}
+
+#[derive(Serialize, Debug)]
+struct InsertedContent {
+    text: String,
+}
+
+impl SarifLog {
+    /// Creates a SARIF log from the diagnostics of Marker lints. The given lints
+    /// are used as the rules of the log. Diagnostics, which weren't emitted by
+    /// Marker lints, are ignored.
+    pub fn new(lints: &[&'static Lint], diags: &[Diagnostic]) -> Self {
+        let rules: Vec<_> = lints.iter().map(|lint| to_rule(lint)).collect();
+        let results = diags
+            .iter()
+            .filter(|diag| fix::is_marker_lint(diag))
+            .filter_map(|diag| to_result(diag, &rules))
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "marker",
+                        information_uri: "https://github.com/rust-marker/marker",
+                        version: env!("CARGO_PKG_VERSION"),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+fn to_rule(lint: &Lint) -> Rule {
+    let explanation = lint_explanation(lint);
+    // The first line, which isn't a heading, is used as the short description
+    let short_description = explanation
+        .lines()
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .unwrap_or_default();
+
+    Rule {
+        id: lint.name.to_ascii_lowercase(),
+        short_description: Message::text(short_description),
+        full_description: Message::text(explanation.clone()),
+        help: Message {
+            text: explanation.clone(),
+            markdown: Some(explanation),
+        },
+        default_configuration: RuleConfiguration {
+            level: match lint.default_level {
+                Level::Allow => "none",
+                Level::Warn => "warning",
+                _ => "error",
+            },
+        },
+    }
+}
+
+fn to_result(diag: &Diagnostic, rules: &[Rule]) -> Option<SarifResult> {
+    let rule_id = diag.code.as_ref()?.code.clone();
+    let primary = diag.spans.iter().find(|span| span.is_primary)?;
+
+    let fixes = diag.children.iter().filter_map(to_fix).collect();
+
+    Some(SarifResult {
+        rule_index: rules.iter().position(|rule| rule.id == rule_id),
+        rule_id,
+        level: match diag.level {
+            DiagnosticLevel::Error | DiagnosticLevel::Ice => "error",
+            DiagnosticLevel::Warning => "warning",
+            _ => "note",
+        },
+        message: Message::text(diag.message.clone()),
+        locations: vec![Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: primary.file_name.clone(),
+                },
+                region: to_region(primary),
+            },
+        }],
+        fixes,
+    })
+}
+
+/// Rustc attaches suggestions to the child diagnostics. Only machine-applicable
+/// suggestions are converted, as SARIF consumers might apply fixes automatically.
+fn to_fix(child: &Diagnostic) -> Option<Fix> {
+    let spans: Vec<_> = child
+        .spans
+        .iter()
+        .filter(|span| span.suggested_replacement.is_some())
+        .collect();
+    let machine_applicable = spans
+        .iter()
+        .all(|span| matches!(span.suggestion_applicability, Some(Applicability::MachineApplicable)));
+    if spans.is_empty() || !machine_applicable {
+        return None;
+    }
+
+    // A suggestion can change multiple files
+    let mut replacements: BTreeMap<&str, Vec<Replacement>> = BTreeMap::new();
+    for span in spans {
+        replacements.entry(&span.file_name).or_default().push(Replacement {
+            deleted_region: to_region(span),
+            inserted_content: InsertedContent {
+                text: span.suggested_replacement.clone().unwrap_or_default(),
+            },
+        });
+    }
+
+    Some(Fix {
+        description: Message::text(child.message.clone()),
+        artifact_changes: replacements
+            .into_iter()
+            .map(|(file, replacements)| ArtifactChange {
+                artifact_location: ArtifactLocation { uri: file.to_string() },
+                replacements,
+            })
+            .collect(),
+    })
+}
+
+fn to_region(span: &DiagnosticSpan) -> Region {
+    Region {
+        start_line: span.line_start,
+        start_column: span.column_start,
+        end_line: span.line_end,
+        end_column: span.column_end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::test_utils;
+
+    marker_api::declare_lint! {
+        /// # What it does
+        /// Quacks.
+        QUACK,
+        Warn,
+    }
+
+    fn diag(code: &str) -> Diagnostic {
+        let span = |file: &str| test_utils::span(file).line(2);
+        test_utils::diagnostic(code, "quack")
+            .span(span("src/lib.rs"))
+            .help(
+                "try",
+                [
+                    span("src/lib.rs").suggestion("duck", "MachineApplicable"),
+                    span("src/main.rs").suggestion("goose", "MachineApplicable"),
+                ],
+            )
+            .help("maybe", [span("src/lib.rs").suggestion("swan", "MaybeIncorrect")])
+            .build()
+    }
+
+    #[test]
+    fn test_sarif_log() {
+        let log = SarifLog::new(
+            &[QUACK],
+            &[diag("marker::cargo_marker::quack"), diag("unused_variables")],
+        );
+        let mut log = serde_json::to_value(log).unwrap();
+        log["runs"][0]["tool"]["driver"]["version"] = "<version>".into();
+        // The fixes are tested separately
+        log["runs"][0]["results"][0].as_object_mut().unwrap().remove("fixes");
+
+        expect_test::expect![[r##"
+            {
+              "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+              "runs": [
+                {
+                  "results": [
+                    {
+                      "level": "warning",
+                      "locations": [
+                        {
+                          "physicalLocation": {
+                            "artifactLocation": {
+                              "uri": "src/lib.rs"
+                            },
+                            "region": {
+                              "endColumn": 6,
+                              "endLine": 2,
+                              "startColumn": 5,
+                              "startLine": 2
+                            }
+                          }
+                        }
+                      ],
+                      "message": {
+                        "text": "quack"
+                      },
+                      "ruleId": "marker::cargo_marker::quack",
+                      "ruleIndex": 0
+                    }
+                  ],
+                  "tool": {
+                    "driver": {
+                      "informationUri": "https://github.com/rust-marker/marker",
+                      "name": "marker",
+                      "rules": [
+                        {
+                          "defaultConfiguration": {
+                            "level": "warning"
+                          },
+                          "fullDescription": {
+                            "text": "# What it does\nQuacks."
+                          },
+                          "help": {
+                            "markdown": "# What it does\nQuacks.",
+                            "text": "# What it does\nQuacks."
+                          },
+                          "id": "marker::cargo_marker::quack",
+                          "shortDescription": {
+                            "text": "Quacks."
+                          }
+                        }
+                      ],
+                      "version": "<version>"
+                    }
+                  }
+                }
+              ],
+              "version": "2.1.0"
+            }"##]]
+        .assert_eq(&serde_json::to_string_pretty(&log).unwrap());
+    }
+
+    #[test]
+    fn test_sarif_fixes() {
+        let result = to_result(&diag("marker::cargo_marker::quack"), &[]).unwrap();
+        let fixes = serde_json::to_value(result.fixes).unwrap();
+
+        expect_test::expect![[r#"
+            [
+              {
+                "artifactChanges": [
+                  {
+                    "artifactLocation": {
+                      "uri": "src/lib.rs"
+                    },
+                    "replacements": [
+                      {
+                        "deletedRegion": {
+                          "endColumn": 6,
+                          "endLine": 2,
+                          "startColumn": 5,
+                          "startLine": 2
+                        },
+                        "insertedContent": {
+                          "text": "duck"
+                        }
+                      }
+                    ]
+                  },
+                  {
+                    "artifactLocation": {
+                      "uri": "src/main.rs"
+                    },
+                    "replacements": [
+                      {
+                        "deletedRegion": {
+                          "endColumn": 6,
+                          "endLine": 2,
+                          "startColumn": 5,
+                          "startLine": 2
+                        },
+                        "insertedContent": {
+                          "text": "goose"
+                        }
+                      }
+                    ]
+                  }
+                ],
+                "description": {
+                  "text": "try"
+                }
+              }
+            ]"#]]
+        .assert_eq(&serde_json::to_string_pretty(&fixes).unwrap());
+    }
+}
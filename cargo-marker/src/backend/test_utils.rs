@@ -0,0 +1,94 @@
+//! Builders for the [`Diagnostic`]s used by the tests of the backend.
+
+use cargo_metadata::diagnostic::Diagnostic;
+use serde_json::{json, Value};
+
+/// Creates a warning with the given lint code and message, without any spans.
+pub(crate) fn diagnostic(code: &str, message: &str) -> DiagnosticBuilder {
+    DiagnosticBuilder {
+        value: json!({
+            "message": message,
+            "code": { "code": code, "explanation": null },
+            "level": "warning",
+            "spans": [],
+            "children": [],
+            "rendered": null,
+        }),
+    }
+}
+
+/// Creates a primary span in `file`, which covers the fifth character of the
+/// first line.
+pub(crate) fn span(file: &str) -> SpanBuilder {
+    SpanBuilder {
+        value: json!({
+            "file_name": file,
+            "byte_start": 4,
+            "byte_end": 5,
+            "line_start": 1,
+            "line_end": 1,
+            "column_start": 5,
+            "column_end": 6,
+            "is_primary": true,
+            "text": [],
+            "label": null,
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null,
+        }),
+    }
+}
+
+pub(crate) struct DiagnosticBuilder {
+    value: Value,
+}
+
+impl DiagnosticBuilder {
+    pub(crate) fn span(mut self, span: SpanBuilder) -> Self {
+        push(&mut self.value["spans"], span.value);
+        self
+    }
+
+    /// Adds a `help` message with the given spans.
+    pub(crate) fn help(mut self, message: &str, spans: impl IntoIterator<Item = SpanBuilder>) -> Self {
+        let spans: Vec<_> = spans.into_iter().map(|span| span.value).collect();
+        let mut child = diagnostic("", message).value;
+        child["code"] = Value::Null;
+        child["level"] = "help".into();
+        child["spans"] = spans.into();
+        push(&mut self.value["children"], child);
+        self
+    }
+
+    pub(crate) fn build(self) -> Diagnostic {
+        serde_json::from_value(self.value).unwrap()
+    }
+}
+
+pub(crate) struct SpanBuilder {
+    value: Value,
+}
+
+impl SpanBuilder {
+    pub(crate) fn line(mut self, line: usize) -> Self {
+        self.value["line_start"] = line.into();
+        self.value["line_end"] = line.into();
+        self
+    }
+
+    /// Sets the source line of this span and highlights all of it.
+    pub(crate) fn text(mut self, text: &str) -> Self {
+        self.value["text"] = json!([{ "text": text, "highlight_start": 1, "highlight_end": text.len() + 1 }]);
+        self
+    }
+
+    pub(crate) fn suggestion(mut self, replacement: &str, applicability: &str) -> Self {
+        self.value["suggested_replacement"] = replacement.into();
+        self.value["suggestion_applicability"] = applicability.into();
+        self
+    }
+}
+
+fn push(array: &mut Value, value: Value) {
+    array.as_array_mut().expect("the field should be an array").push(value);
+}
@@ -47,6 +47,9 @@ impl Toolchain {
             cmd.arg("--release");
         }
 
+        // Network and source configuration
+        cmd.args(config.lint_crate_cargo_args());
+
         // Environment
         cmd.env("RUSTFLAGS", &config.build_rustc_flags);
 
@@ -1,6 +1,9 @@
 mod check;
+mod fix;
+mod lints;
 mod setup;
 mod test_setup;
+mod vendor;
 
 use crate::config::Config;
 use crate::error::prelude::*;
@@ -40,9 +43,18 @@ pub(crate) enum CliCommand {
     /// Run Marker on the current package
     Check(check::CheckCommand),
 
+    /// Run Marker and apply machine-applicable suggestions of lint crates
+    Fix(fix::FixCommand),
+
+    /// Build the lint crates and list the lints they provide
+    Lints(lints::LintsCommand),
+
     /// Setup the rustc driver for Marker
     Setup(setup::SetupCommand),
 
+    /// Copy the lint crates and their dependencies into a local directory
+    Vendor(vendor::VendorCommand),
+
     /// **UNSTABLE** Setup the specified lint crate for ui tests
     #[command(hide = true)]
     TestSetup(test_setup::TestSetupCommand),
@@ -65,6 +77,9 @@ impl MarkerCli {
         match command {
             CliCommand::Setup(cmd) => cmd.run(),
             CliCommand::Check(cmd) => cmd.run(config),
+            CliCommand::Fix(cmd) => cmd.run(config, &manifest_path),
+            CliCommand::Lints(cmd) => cmd.run(config),
+            CliCommand::Vendor(cmd) => cmd.run(config),
             CliCommand::TestSetup(cmd) => cmd.run(config),
         }
     }
@@ -103,5 +118,61 @@ mod tests {
         } else {
             panic!("the `check` subcommand was not detected");
         }
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "-D", "marker::ducks::*", "-A", "marker::ducks::quack"]);
+        let levels = cli.check.lint_levels.lint_levels_from_cli().unwrap();
+        assert!(levels[0] == ("marker::ducks".to_string(), crate::config::LintLevel::Deny));
+        assert!(levels[1] == ("marker::ducks::quack".to_string(), crate::config::LintLevel::Allow));
+
+        // The flags keep their order, later flags override earlier ones
+        let cli = MarkerCli::parse_from([
+            "cargo-marker",
+            "-W",
+            "marker::ducks::quack",
+            "--forbid=marker::ducks::pedantic",
+            "-A",
+            "marker::ducks::quack",
+            "-W",
+            "marker::ducks::*",
+        ]);
+        let levels = cli.check.lint_levels.lint_levels_from_cli().unwrap();
+        assert!(
+            levels
+                == [
+                    ("marker::ducks::quack".to_string(), crate::config::LintLevel::Warn),
+                    ("marker::ducks::pedantic".to_string(), crate::config::LintLevel::Forbid),
+                    ("marker::ducks::quack".to_string(), crate::config::LintLevel::Allow),
+                    ("marker::ducks".to_string(), crate::config::LintLevel::Warn),
+                ]
+        );
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "fix", "--allow-dirty", "--", "ducks"]);
+        if let Some(CliCommand::Fix(fix_args)) = cli.command {
+            assert!(fix_args.allow_dirty);
+            assert!(!fix_args.allow_staged);
+            assert!(fix_args.cargo_args == ["ducks"]);
+        } else {
+            panic!("the `fix` subcommand was not detected");
+        }
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "fix", "--message-format", "sarif"]).is_err());
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "vendor", "--offline"]);
+        if let Some(CliCommand::Vendor(vendor_args)) = cli.command {
+            assert!(vendor_args.dir == "marker-vendor");
+            assert!(vendor_args.lint_crates.offline);
+        } else {
+            panic!("the `vendor` subcommand was not detected");
+        }
+        let cli = MarkerCli::parse_from(["cargo-marker", "lints", "--format", "json", "--offline"]);
+        if let Some(CliCommand::Lints(lints_args)) = cli.command {
+            assert!(lints_args.format == lints::LintsFormat::Json);
+            assert!(lints_args.lint_crates.offline);
+        } else {
+            panic!("the `lints` subcommand was not detected");
+        }
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "lints", "--", "ducks"]).is_err());
+
+        // Arguments, which only affect the linting, are rejected
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "vendor", "--baseline", "baseline.json"]).is_err());
     }
 }
@@ -1,13 +1,45 @@
-use crate::config::{Config, LintDependency};
+use crate::config::{self, Config, LintDependency, LintLevel};
 use crate::error::prelude::*;
 use crate::{backend, utils};
-use camino::Utf8Path;
-use clap::Args;
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{ArgMatches, Args, FromArgMatches, ValueEnum};
 use std::collections::BTreeMap;
+use yansi::Paint;
 
 #[derive(Args, Debug)]
 #[command(override_usage = "cargo marker check [OPTIONS] -- <CARGO ARGS>")]
 pub(crate) struct CheckCommand {
+    #[clap(flatten)]
+    pub(crate) lint_crates: LintCrateArgs,
+
+    #[clap(flatten)]
+    pub(crate) lint_levels: LintLevelArgs,
+
+    /// Use the lint crates from a directory created by `cargo marker vendor`
+    #[arg(long, value_name = "DIR")]
+    pub(crate) vendor_dir: Option<Utf8PathBuf>,
+
+    /// The format, which should be used to emit diagnostics
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    pub(crate) message_format: MessageFormat,
+
+    /// Suppress diagnostics, which are recorded in the given baseline file
+    #[arg(long, value_name = "FILE")]
+    pub(crate) baseline: Option<Utf8PathBuf>,
+
+    /// Record all diagnostics of Marker lints in the given baseline file
+    #[arg(long, value_name = "FILE", conflicts_with = "baseline")]
+    pub(crate) write_baseline: Option<Utf8PathBuf>,
+
+    /// Arguments which will be forwarded to Cargo. See `cargo check --help`
+    #[clap(last = true)]
+    pub(crate) cargo_args: Vec<String>,
+}
+
+/// The arguments, which select and fetch the lint crates. These are shared by
+/// all commands, that build lint crates.
+#[derive(Args, Debug)]
+pub(crate) struct LintCrateArgs {
     /// Specifies lint crates which should be used. (Lints in `Cargo.toml` will be ignored)
     #[arg(short, long)]
     pub(crate) lints: Vec<String>,
@@ -16,9 +48,101 @@ pub(crate) struct CheckCommand {
     #[arg(long)]
     pub(crate) forward_rust_flags: bool,
 
-    /// Arguments which will be forwarded to Cargo. See `cargo check --help`
-    #[clap(last = true)]
-    pub(crate) cargo_args: Vec<String>,
+    /// Fetch and build the lint crates without accessing the network
+    #[arg(long)]
+    pub(crate) offline: bool,
+
+    /// Like `--offline`, but also require the lockfile of the lint crates to be up to date
+    #[arg(long)]
+    pub(crate) frozen: bool,
+}
+
+/// The arguments, which set the level of lints. These are shared by all
+/// commands, that run lint crates.
+///
+/// The levels are collected in the order of the flags on the command line, as
+/// later flags override earlier ones, like in rustc. The derived [`FromArgMatches`]
+/// implementation would group them by level, [`LintLevelFlags`] is therefore
+/// only used to declare the flags.
+#[derive(Debug, Default)]
+pub(crate) struct LintLevelArgs {
+    pub(crate) levels: Vec<(String, LintLevel)>,
+}
+
+#[derive(Args)]
+struct LintLevelFlags {
+    /// Set the given lints to `allow`. Use `marker::lint_crate::*` for all lints of a lint crate
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    allow: Vec<String>,
+
+    /// Set the given lints to `warn`. Use `marker::lint_crate::*` for all lints of a lint crate
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    warn: Vec<String>,
+
+    /// Set the given lints to `deny`. Use `marker::lint_crate::*` for all lints of a lint crate
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    deny: Vec<String>,
+
+    /// Set the given lints to `forbid`. Use `marker::lint_crate::*` for all lints of a lint crate
+    #[arg(short = 'F', long = "forbid", value_name = "LINT")]
+    forbid: Vec<String>,
+}
+
+impl Args for LintLevelArgs {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        LintLevelFlags::augment_args(cmd)
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        LintLevelFlags::augment_args_for_update(cmd)
+    }
+}
+
+impl FromArgMatches for LintLevelArgs {
+    fn from_arg_matches(matches: &ArgMatches) -> std::result::Result<Self, clap::Error> {
+        let mut levels = vec![];
+        for (id, level) in [
+            ("allow", LintLevel::Allow),
+            ("warn", LintLevel::Warn),
+            ("deny", LintLevel::Deny),
+            ("forbid", LintLevel::Forbid),
+        ] {
+            let (Some(indices), Some(names)) = (matches.indices_of(id), matches.get_many::<String>(id)) else {
+                continue;
+            };
+            levels.extend(indices.zip(names).map(|(index, name)| (index, name.clone(), level)));
+        }
+        levels.sort_by_key(|(index, ..)| *index);
+
+        Ok(Self {
+            levels: levels.into_iter().map(|(_, name, level)| (name, level)).collect(),
+        })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> std::result::Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MessageFormat {
+    /// The human readable diagnostics of rustc
+    Human,
+    /// The JSON messages of Cargo, like `cargo check --message-format=json`
+    Json,
+    /// A SARIF log, with the diagnostics and rules of Marker lints, printed to stdout
+    Sarif,
+}
+
+impl From<MessageFormat> for backend::MessageFormat {
+    fn from(format: MessageFormat) -> Self {
+        match format {
+            MessageFormat::Human => Self::Human,
+            MessageFormat::Json => Self::Json,
+            MessageFormat::Sarif => Self::Sarif,
+        }
+    }
 }
 
 impl CheckCommand {
@@ -27,6 +151,57 @@ impl CheckCommand {
     }
 
     pub(crate) fn compile_lints(self, config: Option<Config>) -> Result<CompiledLints> {
+        // Cargo's JSON messages are forwarded as is, they can't be filtered
+        let uses_baseline = self.baseline.is_some() || self.write_baseline.is_some();
+        if uses_baseline && self.message_format == MessageFormat::Json {
+            return Err(Error::root(format!(
+                "{} can't be combined with baseline files",
+                "--message-format json".red()
+            )));
+        }
+
+        let lint_levels = self.lint_levels.lint_levels_from_cli()?;
+        let backend_conf = self
+            .lint_crates
+            .backend_config(config, lint_levels, self.vendor_dir.as_deref())?;
+
+        // Prepare backend
+        let info = backend::prepare_check(&backend_conf)?;
+
+        Ok(CompiledLints {
+            backend_conf,
+            info,
+            cargo_args: self.cargo_args,
+            options: backend::CheckOptions {
+                message_format: self.message_format.into(),
+                baseline: self.baseline,
+                write_baseline: self.write_baseline,
+            },
+        })
+    }
+}
+
+impl LintCrateArgs {
+    /// Creates the backend configuration from the given config and command line
+    /// arguments.
+    pub(crate) fn backend_config(
+        &self,
+        config: Option<Config>,
+        cli_lint_levels: Vec<(String, LintLevel)>,
+        vendor_dir: Option<&Utf8Path>,
+    ) -> Result<backend::Config> {
+        // The lint crate configuration is always taken from the manifest, as
+        // it can't be specified on the command line.
+        let lint_crate_configs = config
+            .as_ref()
+            .filter(|config| !config.config.is_empty())
+            .map(|config| backend::LintCrateConfigs {
+                manifest_path: config.manifest_path.clone(),
+                config: config.config.clone(),
+            });
+
+        let lint_levels = config.as_ref().map(Config::lint_levels).unwrap_or_default();
+
         // determine lints
         let lints: BTreeMap<_, _> = self
             .lints_from_cli()?
@@ -46,20 +221,24 @@ impl CheckCommand {
             backend::driver::install_driver(false, None)?;
         }
 
+        let vendor_dir = vendor_dir
+            .map(|dir| {
+                dir.canonicalize_utf8()
+                    .context(|| format!("The vendor directory {} doesn't exist", dir.red()))
+            })
+            .transpose()?;
+
         // Configure backend
         let toolchain = backend::toolchain::Toolchain::try_find_toolchain()?;
-        let backend_conf = backend::Config {
+        Ok(backend::Config {
             lints,
+            lint_crate_configs,
+            lint_levels,
+            cli_lint_levels,
+            offline: self.offline,
+            frozen: self.frozen,
+            vendor_dir,
             ..backend::Config::try_base_from(toolchain)?
-        };
-
-        // Prepare backend
-        let info = backend::prepare_check(&backend_conf)?;
-
-        Ok(CompiledLints {
-            backend_conf,
-            info,
-            cargo_args: self.cargo_args,
         })
     }
 
@@ -76,7 +255,7 @@ impl CheckCommand {
 
         let path = Utf8Path::new(".");
 
-        let Config { lints } = Config::try_from_str(&virtual_manifest, path)?.unwrap_or_else(|| {
+        let Config { lints, .. } = Config::try_from_str(&virtual_manifest, path)?.unwrap_or_else(|| {
             panic!(
                 "BUG: the config must definitely contain the marker metadata:\
                 \n---\n{virtual_manifest}\n---"
@@ -87,16 +266,38 @@ impl CheckCommand {
     }
 }
 
+impl LintLevelArgs {
+    /// The lint levels specified on the command line, translated to rustc
+    /// names, in the order they were specified.
+    pub(crate) fn lint_levels_from_cli(&self) -> Result<Vec<(String, LintLevel)>> {
+        self.levels
+            .iter()
+            .map(|(name, level)| {
+                let rustc_name = config::to_rustc_lint_name(name).context(|| {
+                    format!(
+                        "The lint name {} is invalid. Lint names have to look like {} or {}",
+                        name.red(),
+                        "marker::lint_crate::lint_name".green(),
+                        "marker::lint_crate::*".green(),
+                    )
+                })?;
+                Ok((rustc_name, *level))
+            })
+            .collect()
+    }
+}
+
 /// The result of discovering and compiling the lint libraries
 #[derive(Debug)]
 pub(crate) struct CompiledLints {
     pub(crate) backend_conf: backend::Config,
     pub(crate) info: backend::CheckInfo,
     pub(crate) cargo_args: Vec<String>,
+    pub(crate) options: backend::CheckOptions,
 }
 
 impl CompiledLints {
     fn lint(self) -> Result {
-        backend::run_check(&self.backend_conf, self.info, &self.cargo_args)
+        backend::run_check(&self.backend_conf, self.info, &self.cargo_args, &self.options)
     }
 }
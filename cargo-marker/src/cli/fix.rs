@@ -0,0 +1,119 @@
+use super::check::{LintCrateArgs, LintLevelArgs};
+use crate::backend;
+use crate::config::Config;
+use crate::error::prelude::*;
+use crate::observability::prelude::*;
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use std::process::Command;
+
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker fix [OPTIONS] -- <CARGO ARGS>")]
+pub(crate) struct FixCommand {
+    /// Fix code even if the working directory has uncommitted changes
+    #[arg(long)]
+    pub(crate) allow_dirty: bool,
+
+    /// Fix code even if the working directory has staged changes
+    #[arg(long)]
+    pub(crate) allow_staged: bool,
+
+    /// Fix code even if a VCS was not detected
+    #[arg(long)]
+    pub(crate) allow_no_vcs: bool,
+
+    #[clap(flatten)]
+    pub(crate) lint_crates: LintCrateArgs,
+
+    #[clap(flatten)]
+    pub(crate) lint_levels: LintLevelArgs,
+
+    /// Use the lint crates from a directory created by `cargo marker vendor`
+    #[arg(long, value_name = "DIR")]
+    pub(crate) vendor_dir: Option<Utf8PathBuf>,
+
+    /// Arguments which will be forwarded to Cargo. See `cargo check --help`
+    #[clap(last = true)]
+    pub(crate) cargo_args: Vec<String>,
+}
+
+impl FixCommand {
+    pub(crate) fn run(self, config: Option<Config>, manifest_path: &Utf8Path) -> Result {
+        let workspace_root = manifest_path
+            .parent()
+            .expect("the path of a `Cargo.toml` file must have a parent");
+
+        self.check_vcs(workspace_root)?;
+
+        let lint_levels = self.lint_levels.lint_levels_from_cli()?;
+        let backend_conf = self
+            .lint_crates
+            .backend_config(config, lint_levels, self.vendor_dir.as_deref())?;
+        let info = backend::prepare_check(&backend_conf)?;
+        backend::run_fix(&backend_conf, info, &self.cargo_args, workspace_root)
+    }
+
+    /// Fixes will modify the source files in place. Similar to `cargo fix`, this
+    /// refuses to run, if the changes can't be reviewed or reverted via git.
+    fn check_vcs(&self, workspace_root: &Utf8Path) -> Result {
+        if self.allow_no_vcs {
+            return Ok(());
+        }
+
+        let mut cmd = Command::new("git");
+        cmd.current_dir(workspace_root).args(["status", "--porcelain"]);
+        let output = cmd.log().output();
+        let Some(output) = output.ok().filter(|output| output.status.success()) else {
+            return Err(Error::from_kind(ErrorKind::FixNoVcs));
+        };
+
+        let status = String::from_utf8_lossy(&output.stdout);
+        let files = VcsStatus::parse(&status);
+
+        let mut blocking = vec![];
+        if !self.allow_dirty {
+            blocking.extend(files.dirty.iter().map(|file| format!("{file} (dirty)")));
+            if !self.allow_staged {
+                blocking.extend(files.staged.iter().map(|file| format!("{file} (staged)")));
+            }
+        }
+
+        if blocking.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::from_kind(ErrorKind::FixUncommittedChanges { files: blocking }))
+    }
+}
+
+/// The files with uncommitted changes, as reported by `git status --porcelain`
+#[derive(Debug, Default)]
+struct VcsStatus<'a> {
+    dirty: Vec<&'a str>,
+    staged: Vec<&'a str>,
+}
+
+impl<'a> VcsStatus<'a> {
+    fn parse(status: &'a str) -> Self {
+        let mut files = Self::default();
+
+        // Each line has the format `XY PATH`, where `X` is the status of the
+        // index and `Y` the status of the working tree.
+        for line in status.lines() {
+            let (Some(code), Some(file)) = (line.get(..2), line.get(3..)) else {
+                continue;
+            };
+            let mut code = code.chars();
+            let index = code.next().unwrap_or(' ');
+            let worktree = code.next().unwrap_or(' ');
+
+            if index == '?' || worktree != ' ' {
+                files.dirty.push(file);
+            } else if index != ' ' {
+                files.staged.push(file);
+            }
+        }
+
+        files
+    }
+}
@@ -0,0 +1,192 @@
+use super::check::LintCrateArgs;
+use crate::backend;
+use crate::config::Config;
+use crate::error::prelude::*;
+use camino::Utf8PathBuf;
+use clap::{Args, ValueEnum};
+use marker_api::{common::MacroReport, Lint};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker lints [OPTIONS]")]
+pub(crate) struct LintsCommand {
+    /// The format, which should be used to print the lints
+    #[arg(long, value_enum, default_value_t = LintsFormat::Text)]
+    pub(crate) format: LintsFormat,
+
+    #[clap(flatten)]
+    pub(crate) lint_crates: LintCrateArgs,
+
+    /// Use the lint crates from a directory created by `cargo marker vendor`
+    #[arg(long, value_name = "DIR")]
+    pub(crate) vendor_dir: Option<Utf8PathBuf>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LintsFormat {
+    /// A human readable list of all lints
+    Text,
+    /// A JSON array, with an object for every lint
+    Json,
+    /// A Markdown document, which can be used as a page of a lint book
+    Markdown,
+}
+
+impl LintsCommand {
+    pub(crate) fn run(self, config: Option<Config>) -> Result {
+        let backend_conf = self
+            .lint_crates
+            .backend_config(config, vec![], self.vendor_dir.as_deref())?;
+        let mut lints: Vec<_> = backend::collect_lints(&backend_conf)?
+            .into_iter()
+            .map(LintEntry::new)
+            .collect();
+        lints.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let output = match self.format {
+            LintsFormat::Text => to_text(&lints),
+            LintsFormat::Json => serde_json::to_string_pretty(&lints).expect("lint entries are valid JSON"),
+            LintsFormat::Markdown => to_markdown(&lints),
+        };
+        println!("{output}");
+
+        Ok(())
+    }
+}
+
+/// The information of a single lint, as it's printed by this command
+#[derive(Serialize, Debug)]
+struct LintEntry {
+    /// The lowercase name of the lint, like `marker::lint_crate::lint_name`
+    name: String,
+    default_level: String,
+    report_in_macro: String,
+    group: Option<&'static str>,
+    /// The explanation of the lint, as Markdown
+    explanation: String,
+}
+
+impl LintEntry {
+    fn new(lint: &'static Lint) -> Self {
+        Self {
+            name: lint.name.to_ascii_lowercase(),
+            default_level: format!("{:?}", lint.default_level).to_ascii_lowercase(),
+            report_in_macro: match lint.report_in_macro {
+                MacroReport::Crates(crates) => format!("local, {}", crates.join(", ")),
+                report => format!("{report:?}").to_ascii_lowercase(),
+            },
+            group: lint.group,
+            explanation: backend::lint_explanation(lint),
+        }
+    }
+}
+
+fn to_text(lints: &[LintEntry]) -> String {
+    let mut output = String::new();
+    for lint in lints {
+        writeln!(output, "{}", lint.name).unwrap();
+        write!(
+            output,
+            "    default level: {}, report in macros: {}",
+            lint.default_level, lint.report_in_macro
+        )
+        .unwrap();
+        if let Some(group) = lint.group {
+            write!(output, ", group: {group}").unwrap();
+        }
+        output.push('\n');
+        for line in lint.explanation.lines() {
+            if line.is_empty() {
+                output.push('\n');
+            } else {
+                writeln!(output, "    {line}").unwrap();
+            }
+        }
+        output.push('\n');
+    }
+    output.trim_end().to_string()
+}
+
+fn to_markdown(lints: &[LintEntry]) -> String {
+    let mut output = "# Lints\n".to_string();
+    for lint in lints {
+        writeln!(output, "\n## `{}`\n", lint.name).unwrap();
+        writeln!(output, "| Default level | Report in macros | Group |").unwrap();
+        writeln!(output, "| --- | --- | --- |").unwrap();
+        writeln!(
+            output,
+            "| `{}` | `{}` | {} |\n",
+            lint.default_level,
+            lint.report_in_macro,
+            lint.group
+                .map(|group| format!("`{group}`"))
+                .unwrap_or_else(|| "-".to_string())
+        )
+        .unwrap();
+
+        // The headings of the explanation are nested under the heading of the lint
+        let mut in_code_block = false;
+        for line in lint.explanation.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if !in_code_block && line.starts_with('#') {
+                output.push_str("##");
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_entry() -> LintEntry {
+        LintEntry {
+            name: "marker::ducks::quack".to_string(),
+            default_level: "warn".to_string(),
+            report_in_macro: "no".to_string(),
+            group: Some("pedantic"),
+            explanation: "# What it does\nQuacks.\n\n```\n# fn main() {}\n```".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_to_text() {
+        expect_test::expect![[r"
+            marker::ducks::quack
+                default level: warn, report in macros: no, group: pedantic
+                # What it does
+                Quacks.
+
+                ```
+                # fn main() {}
+                ```"]]
+        .assert_eq(&to_text(&[test_entry()]));
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        expect_test::expect![[r"
+            # Lints
+
+            ## `marker::ducks::quack`
+
+            | Default level | Report in macros | Group |
+            | --- | --- | --- |
+            | `warn` | `no` | `pedantic` |
+
+            ### What it does
+            Quacks.
+
+            ```
+            # fn main() {}
+            ```
+        "]]
+        .assert_eq(&to_markdown(&[test_entry()]));
+    }
+}
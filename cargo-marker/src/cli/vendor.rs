@@ -0,0 +1,33 @@
+use super::check::LintCrateArgs;
+use crate::backend;
+use crate::config::Config;
+use crate::error::prelude::*;
+use camino::Utf8PathBuf;
+use clap::Args;
+use yansi::Paint;
+
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker vendor [OPTIONS] [DIR]")]
+pub(crate) struct VendorCommand {
+    /// The directory, which the lint crates and their dependencies should be copied to
+    #[arg(default_value = "marker-vendor")]
+    pub(crate) dir: Utf8PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) lint_crates: LintCrateArgs,
+}
+
+impl VendorCommand {
+    pub(crate) fn run(self, config: Option<Config>) -> Result {
+        let backend_conf = self.lint_crates.backend_config(config, vec![], None)?;
+        backend::vendor_lints(&backend_conf, &self.dir)?;
+
+        eprintln!(
+            "The lint crates were vendored into {}. They can be used with:\n{}",
+            self.dir,
+            format!("cargo marker check --offline --vendor-dir {}", self.dir).green()
+        );
+
+        Ok(())
+    }
+}
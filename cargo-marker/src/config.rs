@@ -7,7 +7,7 @@
 
 use crate::error::prelude::*;
 use crate::observability::display;
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, fs};
 use yansi::Paint;
@@ -36,6 +36,60 @@ struct WorkspaceMetadata {
 pub struct Config {
     /// A list of lints.
     pub lints: BTreeMap<String, LintDependency>,
+    /// The configuration of lint crates, like:
+    /// `[workspace.metadata.marker.config.lint_crate]`
+    ///
+    /// The keys are the names of the lint crates, as specified in the
+    /// [`lints`](Self::lints) table.
+    #[serde(default)]
+    #[allow(clippy::struct_field_names)] // The name matches the TOML table
+    pub config: BTreeMap<String, toml::Value>,
+    /// The levels of lints and lint groups, like:
+    /// `"marker::lint_crate::lint_name" = "deny"`
+    #[serde(default)]
+    pub levels: BTreeMap<String, LintLevel>,
+    /// The path of the `Cargo.toml` file, this config was loaded from.
+    #[serde(skip)]
+    pub manifest_path: Utf8PathBuf,
+}
+
+/// A lint level, which can be specified in the `[workspace.metadata.marker.levels]`
+/// table or with the `-A`, `-W`, `-D` and `-F` flags.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+impl LintLevel {
+    /// The rustc argument, which sets the given lint to this level.
+    pub fn to_rustc_arg(self, lint: &str) -> String {
+        let flag = match self {
+            LintLevel::Allow => "--allow",
+            LintLevel::Warn => "--warn",
+            LintLevel::Deny => "--deny",
+            LintLevel::Forbid => "--forbid",
+        };
+        format!("{flag}={lint}")
+    }
+}
+
+/// Translates a lint name, as specified by the user, into the name used by
+/// rustc. Names like `marker::lint_crate::*` are translated into the name of
+/// the group `marker::lint_crate`, which contains all lints of the lint crate.
+///
+/// Returns `None`, if the name doesn't belong to a Marker lint or group.
+pub(crate) fn to_rustc_lint_name(name: &str) -> Option<String> {
+    let rest = name.strip_prefix("marker::")?;
+    let rest = rest.strip_suffix("::*").unwrap_or(rest);
+    if rest.is_empty() || rest.contains('*') {
+        return None;
+    }
+
+    Some(format!("marker::{rest}"))
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -78,9 +132,10 @@ pub struct LintDependencyEntry {
     #[serde(flatten)]
     pub(crate) source: Source,
     pub(crate) package: Option<String>,
-    // FIXME: Features are not supported yet, see https://github.com/rust-marker/marker/issues/81
+    /// Indicates if the default features of the lint crate should be enabled
     #[serde(rename = "default-features")]
     pub(crate) default_features: Option<bool>,
+    /// The features of the lint crate, which should be enabled
     pub(crate) features: Option<Vec<String>>,
 }
 
@@ -147,10 +202,58 @@ impl Config {
             .parent()
             .expect("path must have a parent after reading the `Cargo.toml` file");
         config.normalize(workspace_path)?;
+        config.validate(path)?;
+        config.manifest_path = path.to_path_buf();
 
         Ok(Some(config))
     }
 
+    /// This function validates that every lint crate configuration belongs
+    /// to a lint crate and that all lint levels are set for Marker lints.
+    fn validate(&self, path: &Utf8Path) -> Result {
+        let config_errors = self
+            .config
+            .keys()
+            .filter(|name| !self.lints.contains_key(*name))
+            .map(|name| {
+                Error::root(format!(
+                    "The config table {} in {} doesn't belong to any lint crate in {}",
+                    display::toml(&format!("[workspace.metadata.marker.config.{name}]")),
+                    path.red(),
+                    display::toml("[workspace.metadata.marker.lints]"),
+                ))
+            });
+        let level_errors = self
+            .levels
+            .keys()
+            .filter(|name| to_rustc_lint_name(name).is_none())
+            .map(|name| {
+                Error::root(format!(
+                    "The lint name {} in {} of {} is invalid. Lint names have to \
+                    look like {} or {}",
+                    name.red(),
+                    display::toml("[workspace.metadata.marker.levels]"),
+                    path.red(),
+                    "marker::lint_crate::lint_name".green(),
+                    "marker::lint_crate::*".green(),
+                ))
+            });
+
+        Error::try_many(config_errors.chain(level_errors), "Found invalid Marker configurations")
+    }
+
+    /// The lint levels of this config, translated to rustc names. The backend
+    /// orders them, as lint groups are only known after loading the lint crates.
+    pub fn lint_levels(&self) -> Vec<(String, LintLevel)> {
+        self.levels
+            .iter()
+            .map(|(name, level)| {
+                let name = to_rustc_lint_name(name).expect("lint names are validated when the config is loaded");
+                (name, *level)
+            })
+            .collect()
+    }
+
     /// This function normalizes the config, to be generally applicable. Currently,
     /// it normalizes all relative paths to be absolute paths instead.
     fn normalize(&mut self, workspace_path: &Utf8Path) -> Result {
@@ -48,6 +48,23 @@ or:
         display::cli("rustup toolchain install {toolchain} --component rustc-dev llvm-tools")
     ))]
     BuildDriver,
+
+    #[error("No VCS was found for the current workspace")]
+    #[diagnostic(help(
+        "{} modifies files in place. Use a VCS, like git, to review and revert \
+        the changes, or run the command with {} to proceed anyway",
+        display::cli("cargo marker fix"),
+        display::cli("--allow-no-vcs"),
+    ))]
+    FixNoVcs,
+
+    #[error("The working directory has uncommitted changes:\n{}", files.join("\n"))]
+    #[diagnostic(help(
+        "Commit or stash the changes first, or run the command with {} or {} to proceed anyway",
+        display::cli("--allow-dirty"),
+        display::cli("--allow-staged"),
+    ))]
+    FixUncommittedChanges { files: Vec<String> },
 }
 
 // region replace marker version stable
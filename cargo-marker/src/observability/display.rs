@@ -37,9 +37,10 @@ pub(crate) fn toml(toml: &str) -> String {
 
 /// Displays a stage of work that `cargo-marker` performs.
 /// This is specifically formatted to be aligned with the other cargo
-/// output like `Compiling` or `Checking`.
+/// output like `Compiling` or `Checking`. Like Cargo's output, it's printed
+/// to `stderr`, to keep `stdout` free for the output of commands.
 pub(crate) fn print_stage(name: &str) {
-    println!("\n      {} {}", "Marker".bold().green(), stage(name));
+    eprintln!("\n      {} {}", "Marker".bold().green(), stage(name));
 }
 
 pub(crate) fn stage(name: &str) -> impl fmt::Display + '_ {
@@ -4,3 +4,14 @@ pub mod utf8;
 pub fn is_local_driver() -> bool {
     std::env::var("MARKER_NO_LOCAL_DRIVER").is_err() && cfg!(debug_assertions)
 }
+
+/// Computes a hash of the given data, which is stable across Rust versions and
+/// platforms, unlike the hasher of the standard library. This uses FNV-1a.
+pub fn stable_hash(data: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in data {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
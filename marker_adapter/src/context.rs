@@ -92,12 +92,13 @@ mod map;
 pub use map::*;
 
 use marker_api::{
-    common::{ExpnId, ExprId, SpanId, SymbolId},
+    common::{ExpnId, ExprId, MacroId, SpanId, SymbolId, VarId},
     context::{MarkerContextCallbacks, MarkerContextData},
     diagnostic::Diagnostic,
     ffi::{self, FfiOption},
     prelude::*,
-    span::{ExpnInfo, FileInfo, FilePos, SpanPos, SpanSource},
+    sem::{Adjustment, ConstValue, LocalUsage, TyLayout, TyProperty},
+    span::{Comment, ExpnInfo, FileInfo, FilePos, SpanPos, SpanSource},
 };
 
 /// ### Safety
@@ -127,14 +128,24 @@ impl<'ast> MarkerContextWrapper<'ast> {
             data: unsafe { &*(self as *const MarkerContextWrapper).cast::<MarkerContextData>() },
             emit_diag,
             resolve_ty_ids,
+            resolve_item_ids,
+            resolve_macro_ids,
+            ty_implements_trait,
+            ty_layout,
+            eval_const,
+            local_usages,
             expr_ty,
+            expr_adjustments,
+            trait_ref_path,
             span,
             span_snippet,
             span_source,
             span_pos_to_file_loc,
             span_expn_info,
+            file_comments,
             symbol_str,
             resolve_method_target,
+            ty_has_property,
         }
     }
 }
@@ -143,15 +154,30 @@ pub trait MarkerContextDriver<'ast> {
     fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>);
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn resolve_item_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn resolve_macro_ids(&'ast self, path: &str) -> &'ast [MacroId];
+    fn ty_implements_trait(
+        &'ast self,
+        ty: marker_api::sem::TyKind<'ast>,
+        trait_id: TyDefId,
+        generic_args: &[marker_api::sem::TyKind<'ast>],
+    ) -> bool;
+    fn ty_layout(&'ast self, ty: marker_api::sem::TyKind<'ast>) -> Option<&'ast TyLayout<'ast>>;
+    fn eval_const(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>>;
+    fn local_usages(&'ast self, var: VarId) -> &'ast [LocalUsage];
 
     fn expr_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast>;
+    fn expr_adjustments(&'ast self, expr: ExprId) -> &'ast [Adjustment<'ast>];
+    fn trait_ref_path(&'ast self, trait_ref: &ast::TraitRef<'ast>) -> &'ast ast::AstQPath<'ast>;
     fn span(&'ast self, owner: SpanId) -> &'ast Span<'ast>;
     fn span_snippet(&'ast self, span: &Span<'_>) -> Option<&'ast str>;
     fn span_source(&'ast self, span: &Span<'_>) -> SpanSource<'ast>;
     fn span_expn_info(&'ast self, expn_id: ExpnId) -> Option<&'ast ExpnInfo<'ast>>;
     fn span_pos_to_file_loc(&'ast self, file: &FileInfo<'ast>, pos: SpanPos) -> Option<FilePos<'ast>>;
+    fn file_comments(&'ast self, file: &FileInfo<'ast>) -> &'ast [Comment<'ast>];
     fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str;
-    fn resolve_method_target(&'ast self, id: ExprId) -> ItemId;
+    fn resolve_method_target(&'ast self, id: ExprId) -> &'ast ast::MethodTarget<'ast>;
+    fn ty_has_property(&'ast self, ty: marker_api::sem::TyKind<'ast>, property: TyProperty) -> bool;
 }
 
 extern "C" fn emit_diag<'a, 'ast>(data: &'ast MarkerContextData, diag: &Diagnostic<'a, 'ast>) {
@@ -165,12 +191,68 @@ extern "C" fn resolve_ty_ids<'ast>(
     unsafe { as_driver(data) }.resolve_ty_ids((&path).into()).into()
 }
 
+extern "C" fn resolve_item_ids<'ast>(
+    data: &'ast MarkerContextData,
+    path: ffi::FfiStr<'_>,
+) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver(data) }.resolve_item_ids((&path).into()).into()
+}
+
+extern "C" fn resolve_macro_ids<'ast>(
+    data: &'ast MarkerContextData,
+    path: ffi::FfiStr<'_>,
+) -> ffi::FfiSlice<'ast, MacroId> {
+    unsafe { as_driver(data) }.resolve_macro_ids((&path).into()).into()
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn ty_implements_trait<'ast>(
+    data: &'ast MarkerContextData,
+    ty: marker_api::sem::TyKind<'ast>,
+    trait_id: TyDefId,
+    generic_args: ffi::FfiSlice<'_, marker_api::sem::TyKind<'ast>>,
+) -> bool {
+    unsafe { as_driver(data) }.ty_implements_trait(ty, trait_id, generic_args.get())
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn ty_layout<'ast>(
+    data: &'ast MarkerContextData,
+    ty: marker_api::sem::TyKind<'ast>,
+) -> FfiOption<&'ast TyLayout<'ast>> {
+    unsafe { as_driver(data) }.ty_layout(ty).into()
+}
+
+extern "C" fn eval_const<'ast>(data: &'ast MarkerContextData, expr: ExprId) -> FfiOption<&'ast ConstValue<'ast>> {
+    unsafe { as_driver(data) }.eval_const(expr).into()
+}
+
+extern "C" fn local_usages<'ast>(data: &'ast MarkerContextData, var: VarId) -> ffi::FfiSlice<'ast, LocalUsage> {
+    unsafe { as_driver(data) }.local_usages(var).into()
+}
+
 // False positive because `SemTyKind` is non-exhaustive
 #[allow(improper_ctypes_definitions)]
 extern "C" fn expr_ty<'ast>(data: &'ast MarkerContextData, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
     unsafe { as_driver(data) }.expr_ty(expr)
 }
 
+extern "C" fn expr_adjustments<'ast>(
+    data: &'ast MarkerContextData,
+    expr: ExprId,
+) -> ffi::FfiSlice<'ast, Adjustment<'ast>> {
+    unsafe { as_driver(data) }.expr_adjustments(expr).into()
+}
+
+extern "C" fn trait_ref_path<'ast>(
+    data: &'ast MarkerContextData,
+    trait_ref: &ast::TraitRef<'ast>,
+) -> &'ast ast::AstQPath<'ast> {
+    unsafe { as_driver(data) }.trait_ref_path(trait_ref)
+}
+
 extern "C" fn span<'ast>(data: &'ast MarkerContextData, span_id: SpanId) -> &'ast Span<'ast> {
     unsafe { as_driver(data) }.span(span_id)
 }
@@ -200,14 +282,31 @@ extern "C" fn span_expn_info<'ast>(data: &'ast MarkerContextData, expn_id: ExpnI
     unsafe { as_driver(data) }.span_expn_info(expn_id).into()
 }
 
+extern "C" fn file_comments<'ast>(
+    data: &'ast MarkerContextData,
+    file: &FileInfo<'ast>,
+) -> ffi::FfiSlice<'ast, Comment<'ast>> {
+    unsafe { as_driver(data) }.file_comments(file).into()
+}
+
 extern "C" fn symbol_str<'ast>(data: &'ast MarkerContextData, sym: SymbolId) -> ffi::FfiStr<'ast> {
     unsafe { as_driver(data) }.symbol_str(sym).into()
 }
 
-extern "C" fn resolve_method_target<'ast>(data: &'ast MarkerContextData, id: ExprId) -> ItemId {
+extern "C" fn resolve_method_target<'ast>(data: &'ast MarkerContextData, id: ExprId) -> &'ast ast::MethodTarget<'ast> {
     unsafe { as_driver(data) }.resolve_method_target(id)
 }
 
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn ty_has_property<'ast>(
+    data: &'ast MarkerContextData,
+    ty: marker_api::sem::TyKind<'ast>,
+    property: TyProperty,
+) -> bool {
+    unsafe { as_driver(data) }.ty_has_property(ty, property)
+}
+
 /// # Safety
 /// The `data` must be a valid pointer to a [`MarkerContextWrapper`]
 unsafe fn as_driver<'ast>(data: &'ast MarkerContextData) -> &'ast dyn MarkerContextDriver<'ast> {
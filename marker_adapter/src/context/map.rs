@@ -1,5 +1,5 @@
 use marker_api::{
-    ast::{EnumVariant, ItemField},
+    ast::{Attribute, EnumVariant, ItemField},
     common::Level,
     context::{AstMap, AstMapCallbacks, AstMapData},
     ffi,
@@ -27,6 +27,7 @@ impl<'ast> AstMapWrapper<'ast> {
                 body,
                 stmt,
                 expr,
+                attrs,
                 lint_level_at,
             })
             .build()
@@ -41,6 +42,7 @@ pub trait AstMapDriver<'ast> {
     fn body(&'ast self, id: BodyId) -> &'ast ast::Body<'ast>;
     fn stmt(&'ast self, id: StmtId) -> StmtKind<'ast>;
     fn expr(&'ast self, id: ExprId) -> ExprKind<'ast>;
+    fn attrs(&'ast self, node: NodeId) -> &'ast [Attribute<'ast>];
 
     fn lint_level_at(&'ast self, lint: &'static Lint, node: NodeId) -> Level;
 }
@@ -67,6 +69,11 @@ extern "C" fn expr<'ast>(data: &'ast AstMapData, id: ExprId) -> ExprKind<'ast> {
     unsafe { as_driver(data) }.expr(id)
 }
 
+#[allow(improper_ctypes_definitions)] // FP because `NodeId` is non-exhaustive
+extern "C" fn attrs<'ast>(data: &'ast AstMapData, node: NodeId) -> ffi::FfiSlice<'ast, Attribute<'ast>> {
+    unsafe { as_driver(data) }.attrs(node).into()
+}
+
 #[allow(improper_ctypes_definitions)] // FP because `NodeId` is non-exhaustive
 extern "C" fn lint_level_at<'ast>(data: &'ast AstMapData, lint: &'static Lint, node: NodeId) -> Level {
     unsafe { as_driver(data) }.lint_level_at(lint, node)
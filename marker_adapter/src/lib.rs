@@ -9,12 +9,14 @@ mod loader;
 pub mod context;
 
 pub use error::{Error, Result};
-pub use loader::LintCrateInfo;
+pub use loader::{LintCrateConfigInfo, LintCrateInfo, LintCrateRegistry};
 
-use loader::LintCrateRegistry;
 use marker_api::Lint;
 use marker_api::{
-    ast::{Body, Crate, EnumVariant, ExprKind, ItemField, ItemKind, StmtKind},
+    ast::{
+        AstQPath, Body, Crate, EnumVariant, ExprKind, GenericParamKind, ItemField, ItemKind, MatchArm, PatKind,
+        StmtKind, TyKind,
+    },
     context::MarkerContext,
     LintPass, LintPassInfo,
 };
@@ -22,6 +24,12 @@ use marker_utils::visitor::{self, Visitor};
 use std::{cell::RefCell, ops::ControlFlow};
 
 pub const LINT_CRATES_ENV: &str = "MARKER_LINT_CRATES";
+pub const LINT_CRATES_CONFIG_ENV: &str = "MARKER_LINT_CRATES_CONFIG";
+
+/// The note, which drivers add to diagnostics of lints with the error level,
+/// if the lint levels are capped to `warn` via `MARKER_CAP_LINTS`. This allows
+/// `cargo-marker` to identify these diagnostics.
+pub const CAPPED_LINT_NOTE: &str = "the error level of this lint was capped to `warn` by Marker";
 
 /// This struct is the interface used by lint drivers to load lint crates, pass
 /// `marker_api` objects to external lint passes and all other magic you can think of.
@@ -56,16 +64,19 @@ impl Adapter {
     }
 
     pub fn marker_lints(&self) -> Vec<&'static Lint> {
-        self.lint_pass_infos()
-            .iter()
-            .flat_map(marker_api::LintPassInfo::lints)
+        self.inner
+            .borrow()
+            .external_lint_crates
+            .lint_pass_infos()
+            .flat_map(LintPassInfo::lints)
             .copied()
             .collect()
     }
 
-    #[must_use]
-    fn lint_pass_infos(&self) -> Vec<LintPassInfo> {
-        self.inner.borrow().external_lint_crates.collect_lint_pass_info()
+    /// Returns the lint groups declared by the lint crates, with their full
+    /// names, like `marker::lint_crate::pedantic`.
+    pub fn marker_lint_groups(&self) -> Vec<(String, &'static [&'static Lint])> {
+        self.inner.borrow().external_lint_crates.lint_groups()
     }
 
     pub fn process_krate<'ast>(&self, cx: &'ast MarkerContext<'ast>, krate: &'ast Crate<'ast>) {
@@ -75,6 +86,7 @@ impl Adapter {
 
         inner.external_lint_crates.check_crate(cx, krate);
         visitor::traverse_item::<()>(cx, inner, ItemKind::Mod(krate.root_mod()));
+        inner.external_lint_crates.check_crate_post(cx, krate);
     }
 }
 
@@ -88,6 +100,11 @@ impl Visitor<()> for AdapterInner {
         ControlFlow::Continue(())
     }
 
+    fn visit_item_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_item_post(cx, item);
+        ControlFlow::Continue(())
+    }
+
     fn visit_field<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, field: &'ast ItemField<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_field(cx, field);
         ControlFlow::Continue(())
@@ -107,6 +124,11 @@ impl Visitor<()> for AdapterInner {
         ControlFlow::Continue(())
     }
 
+    fn visit_body_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, body: &'ast Body<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_body_post(cx, body);
+        ControlFlow::Continue(())
+    }
+
     fn visit_stmt<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_stmt(cx, stmt);
         ControlFlow::Continue(())
@@ -116,4 +138,38 @@ impl Visitor<()> for AdapterInner {
         self.external_lint_crates.check_expr(cx, expr);
         ControlFlow::Continue(())
     }
+
+    fn visit_expr_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_expr_post(cx, expr);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, pat: PatKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_pat(cx, pat);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: TyKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_ty(cx, ty);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_generic_param<'ast>(
+        &mut self,
+        cx: &'ast MarkerContext<'ast>,
+        param: GenericParamKind<'ast>,
+    ) -> ControlFlow<()> {
+        self.external_lint_crates.check_generic_param(cx, param);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_match_arm<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, arm: &'ast MatchArm<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_match_arm(cx, arm);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_path<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, path: &'ast AstQPath<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_path(cx, path);
+        ControlFlow::Continue(())
+    }
 }
@@ -2,10 +2,12 @@ use crate::error::prelude::*;
 use camino::Utf8PathBuf;
 use itertools::Itertools;
 use libloading::Library;
-use marker_api::{LintCrateBindings, MarkerContext};
-use marker_api::{LintPass, LintPassInfo, MARKER_API_VERSION};
+use marker_api::{Lint, LintGroup, LintPass, LintPassInfo, MARKER_API_VERSION};
+use marker_api::{LintCrateBindings, LintCrateConfig, MarkerContext};
+use serde::Deserialize;
+use std::collections::HashMap;
 
-use super::LINT_CRATES_ENV;
+use super::{LINT_CRATES_CONFIG_ENV, LINT_CRATES_ENV};
 
 /// A struct describing a lint crate that can be loaded.
 #[derive(Debug, Clone)]
@@ -14,6 +16,25 @@ pub struct LintCrateInfo {
     pub name: String,
     /// The absolute path of the compiled dynamic library, which can be loaded as a lint crate.
     pub path: Utf8PathBuf,
+    /// The user configuration of the lint crate, if one was specified.
+    pub config: Option<LintCrateConfigInfo>,
+}
+
+/// The configuration of a lint crate, as specified in the
+/// `[workspace.metadata.marker.config.<lint_crate>]` table.
+#[derive(Debug, Clone)]
+pub struct LintCrateConfigInfo {
+    /// The path of the `Cargo.toml` file, which contains the configuration.
+    pub manifest_path: Utf8PathBuf,
+    /// The configuration value, serialized as JSON.
+    pub json: String,
+}
+
+/// The content of the [`LINT_CRATES_CONFIG_ENV`] environment value.
+#[derive(Deserialize)]
+struct LintCratesConfigEnv {
+    manifest_path: Utf8PathBuf,
+    config: HashMap<String, serde_json::Value>,
 }
 
 impl LintCrateInfo {
@@ -42,8 +63,26 @@ impl LintCrateInfo {
             lint_crates.push(LintCrateInfo {
                 name: name.to_string(),
                 path: path.into(),
+                config: None,
             });
         }
+
+        if let Ok(config_str) = std::env::var(LINT_CRATES_CONFIG_ENV) {
+            let mut config_env: LintCratesConfigEnv = serde_json::from_str(&config_str).context(|| {
+                format!(
+                    "The content of the `{LINT_CRATES_CONFIG_ENV}` environment variable is malformed. \
+                    Dumped its content on the next line:\n---\n{config_str}\n---",
+                )
+            })?;
+
+            for krate in &mut lint_crates {
+                krate.config = config_env.config.remove(&krate.name).map(|value| LintCrateConfigInfo {
+                    manifest_path: config_env.manifest_path.clone(),
+                    json: value.to_string(),
+                });
+            }
+        }
+
         Ok(Some(lint_crates))
     }
 }
@@ -56,6 +95,12 @@ pub struct LintCrateRegistry {
 }
 
 impl LintCrateRegistry {
+    /// Loads the given lint crates.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error, if a lint crate can't be loaded or
+    /// if several lints or lint groups have the same name.
     pub fn new(lint_crates: &[LintCrateInfo]) -> Result<Self> {
         let mut new_self = Self::default();
 
@@ -63,11 +108,11 @@ impl LintCrateRegistry {
             new_self.passes.push(LoadedLintCrate::try_from_info(krate.clone())?);
         }
 
-        let lint_passes = new_self.collect_lint_pass_info();
+        let lint_passes: Vec<_> = new_self.lint_pass_infos().collect();
 
         let errors = lint_passes
             .iter()
-            .flat_map(LintPassInfo::lints)
+            .flat_map(|pass| pass.lints())
             .into_group_map_by(|lint| lint.name.to_ascii_lowercase())
             .into_iter()
             .filter(|(_, lints)| lints.len() > 1)
@@ -79,18 +124,72 @@ impl LintCrateRegistry {
 
         Error::try_many(errors, "Found several lint name conflicts")?;
 
+        let errors = lint_passes.iter().flat_map(|pass| {
+            pass.groups()
+                .iter()
+                .filter(|group| {
+                    pass.lints()
+                        .iter()
+                        .filter_map(|lint| lint.name.rsplit_once("::"))
+                        .any(|(_, name)| name.eq_ignore_ascii_case(group.name()))
+                })
+                .map(|group| {
+                    Error::root(format!(
+                        "The lint group `{}` has the same name as a lint of the same lint crate",
+                        group.name()
+                    ))
+                })
+        });
+
+        Error::try_many(errors, "Found several lint group name conflicts")?;
+
+        // Groups of different lint crates could otherwise override each other
+        let lint_crate_groups: Vec<_> = lint_passes
+            .iter()
+            .flat_map(|pass| pass.lints())
+            .filter_map(|lint| Some(lint.name.rsplit_once("::")?.0.to_ascii_lowercase()))
+            .collect();
+        let errors = lint_passes
+            .iter()
+            .flat_map(|pass| pass.groups())
+            .filter_map(|group| group_full_name(group).map(|name| name.to_ascii_lowercase()))
+            .counts()
+            .into_iter()
+            .filter(|(name, count)| *count > 1 || lint_crate_groups.contains(name))
+            .map(|(name, _)| Error::root(format!("The lint group `{name}` is defined multiple times")));
+
+        Error::try_many(errors, "Found several lint group name conflicts")?;
+
         Ok(new_self)
     }
 
     pub(super) fn set_ast_context<'ast>(&self, cx: &'ast MarkerContext<'ast>) {
         for lint_pass in &self.passes {
             (lint_pass.bindings.set_ast_context)(cx);
+            (lint_pass.bindings.set_lint_crate_config)(lint_pass.config.into());
         }
     }
 
-    pub(crate) fn collect_lint_pass_info(&self) -> Vec<LintPassInfo> {
-        self.passes.iter().map(|pass| (pass.bindings.info)()).collect()
+    /// Returns the [`LintPassInfo`]s of all loaded lint crates.
+    pub fn lint_pass_infos(&self) -> impl Iterator<Item = &LintPassInfo> {
+        self.passes.iter().map(|pass| &pass.lint_pass_info)
     }
+
+    /// Returns the lint groups declared by the loaded lint crates, with their
+    /// full names, like `marker::lint_crate::pedantic`.
+    pub fn lint_groups(&self) -> Vec<(String, &'static [&'static Lint])> {
+        self.lint_pass_infos()
+            .flat_map(LintPassInfo::groups)
+            .filter_map(|group| Some((group_full_name(group)?, group.lints())))
+            .collect()
+    }
+}
+
+/// Returns the full name of the lint group, like `marker::lint_crate::pedantic`.
+/// The lint crate is taken from the names of the contained lints.
+fn group_full_name(group: &LintGroup) -> Option<String> {
+    let (lint_crate, _) = group.lints().first()?.name.rsplit_once("::")?;
+    Some(format!("{lint_crate}::{}", group.name()))
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -105,12 +204,24 @@ impl LintPass for LintCrateRegistry {
         }
     }
 
+    fn check_crate_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, krate: &'ast marker_api::ast::Crate<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_crate_post)(cx, krate);
+        }
+    }
+
     fn check_item<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: marker_api::ast::ItemKind<'ast>) {
         for lp in &self.passes {
             (lp.bindings.check_item)(cx, item);
         }
     }
 
+    fn check_item_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: marker_api::ast::ItemKind<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_item_post)(cx, item);
+        }
+    }
+
     fn check_field<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, field: &'ast marker_api::ast::ItemField<'ast>) {
         for lp in &self.passes {
             (lp.bindings.check_field)(cx, field);
@@ -133,6 +244,12 @@ impl LintPass for LintCrateRegistry {
         }
     }
 
+    fn check_body_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, body: &'ast marker_api::ast::Body<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_body_post)(cx, body);
+        }
+    }
+
     fn check_stmt<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, stmt: marker_api::ast::StmtKind<'ast>) {
         for lp in &self.passes {
             (lp.bindings.check_stmt)(cx, stmt);
@@ -144,12 +261,56 @@ impl LintPass for LintCrateRegistry {
             (lp.bindings.check_expr)(cx, expr);
         }
     }
+
+    fn check_expr_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, expr: marker_api::ast::ExprKind<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_expr_post)(cx, expr);
+        }
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, pat: marker_api::ast::PatKind<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_pat)(cx, pat);
+        }
+    }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: marker_api::ast::TyKind<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_ty)(cx, ty);
+        }
+    }
+
+    fn check_generic_param<'ast>(
+        &mut self,
+        cx: &'ast MarkerContext<'ast>,
+        param: marker_api::ast::GenericParamKind<'ast>,
+    ) {
+        for lp in &self.passes {
+            (lp.bindings.check_generic_param)(cx, param);
+        }
+    }
+
+    fn check_match_arm<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, arm: &'ast marker_api::ast::MatchArm<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_match_arm)(cx, arm);
+        }
+    }
+
+    fn check_path<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, path: &'ast marker_api::ast::AstQPath<'ast>) {
+        for lp in &self.passes {
+            (lp.bindings.check_path)(cx, path);
+        }
+    }
 }
 
 struct LoadedLintCrate {
     _lib: &'static Library,
     info: LintCrateInfo,
     bindings: LintCrateBindings,
+    config: Option<&'static LintCrateConfig<'static>>,
+    /// The information is requested once, since the lint crate has to leak
+    /// its data to provide it.
+    lint_pass_info: LintPassInfo,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -191,10 +352,28 @@ impl LoadedLintCrate {
 
         let bindings = get_lint_crate_bindings();
 
+        // The configuration is leaked, like the library, as it has to be
+        // available for the entire lifetime of the lint crate.
+        let config = info.config.as_ref().map(|config| {
+            let name: &'static str = Box::leak(info.name.clone().into_boxed_str());
+            let manifest_path: &'static str = Box::leak(config.manifest_path.to_string().into_boxed_str());
+            let json: &'static str = Box::leak(config.json.clone().into_boxed_str());
+            let config: &'static LintCrateConfig<'static> =
+                Box::leak(Box::new(LintCrateConfig::new(name, manifest_path, json)));
+            config
+        });
+
+        // The config is set before the lint pass is constructed, to make it
+        // available in the constructor and `LintPass::info()`.
+        (bindings.set_lint_crate_config)(config.into());
+        let lint_pass_info = (bindings.info)();
+
         Ok(Self {
             _lib: lib,
             info,
             bindings,
+            config,
+            lint_pass_info,
         })
     }
 }
@@ -0,0 +1,330 @@
+use std::marker::PhantomData;
+
+use crate::{
+    common::{SpanId, SymbolId},
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+    span::{HasSpan, Ident, Span},
+};
+
+/// An attribute attached to a node, like `#[must_use]`, `#![allow(unused)]` or
+/// a doc comment like `/// Docs`.
+///
+/// ```
+/// //  vvvvvvvvvvvvvvvvv An outer attribute with delimited arguments
+///     #[inline(always)]
+/// //  vvvvvvvvvvvvvvvvvvvvvvv An outer doc comment
+///     /// Some documentation.
+///     pub fn foo() {}
+/// //  ^^^^^^^^^^^^^^^ The node, that this attribute is attached to
+/// ```
+///
+/// Attributes are provided after `#[cfg_attr(...)]` expansion. An attribute
+/// like `#[cfg_attr(feature = "std", derive(Debug))]` will therefore show up as
+/// `#[derive(Debug)]` if the predicate is true, and not at all otherwise.
+/// [`Attribute::is_from_cfg_attr`] can be used to check if an attribute was
+/// produced this way.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct Attribute<'ast> {
+    span: SpanId,
+    style: AttrStyle,
+    kind: AttrKind<'ast>,
+    #[cfg_attr(feature = "driver-api", builder(default))]
+    from_cfg_attr: bool,
+}
+
+impl<'ast> Attribute<'ast> {
+    /// Returns `true` if this is an inner attribute, like `#![allow(unused)]`
+    /// or `//! Docs`.
+    pub fn is_inner(&self) -> bool {
+        matches!(self.style, AttrStyle::Inner)
+    }
+
+    /// Returns `true` if this is an outer attribute, like `#[allow(unused)]`
+    /// or `/// Docs`.
+    pub fn is_outer(&self) -> bool {
+        matches!(self.style, AttrStyle::Outer)
+    }
+
+    pub fn style(&self) -> AttrStyle {
+        self.style
+    }
+
+    pub fn kind(&self) -> AttrKind<'ast> {
+        self.kind
+    }
+
+    /// Returns `true` if this attribute was produced by expanding a
+    /// `#[cfg_attr(predicate, attr)]` attribute. The [`Span`] of these
+    /// attributes only covers the `attr` part of the original attribute.
+    pub fn is_from_cfg_attr(&self) -> bool {
+        self.from_cfg_attr
+    }
+
+    /// Returns `true` if this attribute is a doc comment, like `/// Docs`, or
+    /// a written `#[doc = "..."]` attribute.
+    pub fn is_doc(&self) -> bool {
+        match self.kind {
+            AttrKind::DocComment(_) => true,
+            AttrKind::Normal(attr) => attr.path_matches("doc"),
+        }
+    }
+
+    /// Returns the documentation text of doc comments and `#[doc = "..."]`
+    /// attributes. The text of doc comments doesn't include the comment
+    /// markers like `///` or `/**`.
+    ///
+    /// Returns [`None`] for all other attributes.
+    pub fn doc_str(&self) -> Option<&str> {
+        match self.kind {
+            AttrKind::DocComment(doc) => Some(doc.text()),
+            AttrKind::Normal(attr) if attr.path_matches("doc") => match attr.args() {
+                AttrArgs::Eq(args) => args.str_value(),
+                _ => None,
+            },
+            AttrKind::Normal(_) => None,
+        }
+    }
+
+    /// Returns `true` if the path of this attribute matches the given path.
+    /// Doc comments are treated like `#[doc = "..."]` attributes and will
+    /// therefore match the path `doc`.
+    ///
+    /// ```ignore
+    /// // Matches `#[must_use]` and `#[must_use = "reason"]`
+    /// attr.path_matches("must_use");
+    /// // Matches `#[rustfmt::skip]`
+    /// attr.path_matches("rustfmt::skip");
+    /// ```
+    pub fn path_matches(&self, path: &str) -> bool {
+        match self.kind {
+            AttrKind::Normal(attr) => attr.path_matches(path),
+            AttrKind::DocComment(_) => path == "doc",
+        }
+    }
+}
+
+impl<'ast> HasSpan<'ast> for Attribute<'ast> {
+    fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+/// The style of an [`Attribute`], which determines the node it's attached to.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrStyle {
+    /// Outer attributes, like `#[inline]` or `/// Docs`, are attached to the
+    /// node following them.
+    Outer,
+    /// Inner attributes, like `#![allow(unused)]` or `//! Docs`, are attached
+    /// to the node they are written in.
+    Inner,
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum AttrKind<'ast> {
+    /// A normal attribute, like `#[inline]`, `#[allow(dead_code)]` or
+    /// `#[doc = "Docs"]`.
+    Normal(&'ast NormalAttr<'ast>),
+    /// A doc comment, like `/// Docs` or `/** Docs */`.
+    DocComment(&'ast DocCommentAttr<'ast>),
+}
+
+/// A normal attribute, consisting of a path and optional arguments, like
+/// `#[inline]`, `#[allow(clippy::pedantic)]` or `#[path = "file.rs"]`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NormalAttr<'ast> {
+    path: FfiSlice<'ast, Ident<'ast>>,
+    args: AttrArgs<'ast>,
+}
+
+impl<'ast> NormalAttr<'ast> {
+    /// The segments of the attribute path. For `#[rustfmt::skip]` this would
+    /// be `rustfmt` and `skip`.
+    pub fn path(&self) -> &[Ident<'ast>] {
+        self.path.get()
+    }
+
+    /// Returns `true` if the path of this attribute matches the given path,
+    /// like `must_use` or `rustfmt::skip`.
+    pub fn path_matches(&self, path: &str) -> bool {
+        let mut segments = self.path().iter();
+        path.split("::")
+            .all(|name| segments.next().map_or(false, |seg| seg.name() == name))
+            && segments.next().is_none()
+    }
+
+    pub fn args(&self) -> &AttrArgs<'ast> {
+        &self.args
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> NormalAttr<'ast> {
+    pub fn new(path: &'ast [Ident<'ast>], args: AttrArgs<'ast>) -> Self {
+        Self {
+            path: path.into(),
+            args,
+        }
+    }
+}
+
+/// The arguments of a [`NormalAttr`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum AttrArgs<'ast> {
+    /// The attribute has no arguments, like `#[test]`.
+    Empty,
+    /// The arguments are wrapped in delimiters, like `#[derive(Debug)]`.
+    Delimited(&'ast DelimAttrArgs<'ast>),
+    /// The arguments are specified with an equals sign, like `#[doc = "Docs"]`.
+    Eq(&'ast EqAttrArgs<'ast>),
+}
+
+/// Delimited arguments of an attribute, like the `(clippy::pedantic)` in
+/// `#[allow(clippy::pedantic)]`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DelimAttrArgs<'ast> {
+    span: SpanId,
+    delim: AttrDelim,
+    tokens: SymbolId,
+    _lifetime: PhantomData<&'ast ()>,
+}
+
+impl<'ast> DelimAttrArgs<'ast> {
+    /// The [`Span`] of the arguments, including the delimiters.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    pub fn delim(&self) -> AttrDelim {
+        self.delim
+    }
+
+    /// The token stream inside the delimiters, as a string. This is usually
+    /// the written code. For attributes originating from macros, the tokens
+    /// might be printed with normalized formatting.
+    ///
+    /// For `#[allow(clippy::pedantic, reason = "legacy")]` this would return
+    /// `clippy::pedantic, reason = "legacy"`.
+    pub fn tokens(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.tokens))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> DelimAttrArgs<'ast> {
+    pub fn new(span: SpanId, delim: AttrDelim, tokens: SymbolId) -> Self {
+        Self {
+            span,
+            delim,
+            tokens,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+/// The delimiter wrapping the arguments of an attribute.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrDelim {
+    /// `#[attr(...)]`
+    Paren,
+    /// `#[attr[...]]`
+    Bracket,
+    /// `#[attr{...}]`
+    Brace,
+}
+
+/// The value of an attribute specified with an equals sign, like the
+/// `"file.rs"` in `#[path = "file.rs"]`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct EqAttrArgs<'ast> {
+    span: SpanId,
+    lit: SymbolId,
+    str_value: FfiOption<SymbolId>,
+    _lifetime: PhantomData<&'ast ()>,
+}
+
+impl<'ast> EqAttrArgs<'ast> {
+    /// The [`Span`] of the value.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    /// The value as it was written, for example `"file.rs"` including
+    /// the quotes or `17`.
+    pub fn lit(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.lit))
+    }
+
+    /// The unescaped value, if the value is a string literal.
+    pub fn str_value(&self) -> Option<&str> {
+        self.str_value.copy().map(|sym| with_cx(self, |cx| cx.symbol_str(sym)))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> EqAttrArgs<'ast> {
+    pub fn new(span: SpanId, lit: SymbolId, str_value: Option<SymbolId>) -> Self {
+        Self {
+            span,
+            lit,
+            str_value: str_value.into(),
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+/// A doc comment, like `/// Docs`, `//! Docs` or `/** Docs */`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DocCommentAttr<'ast> {
+    kind: DocCommentKind,
+    text: SymbolId,
+    _lifetime: PhantomData<&'ast ()>,
+}
+
+impl<'ast> DocCommentAttr<'ast> {
+    pub fn comment_kind(&self) -> DocCommentKind {
+        self.kind
+    }
+
+    /// The text of the doc comment without the comment markers. For
+    /// `/// Docs` this would be ` Docs`, including the leading space.
+    pub fn text(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.text))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> DocCommentAttr<'ast> {
+    pub fn new(kind: DocCommentKind, text: SymbolId) -> Self {
+        Self {
+            kind,
+            text,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DocCommentKind {
+    /// A line doc comment, like `/// Docs` or `//! Docs`.
+    Line,
+    /// A block doc comment, like `/** Docs */` or `/*! Docs */`.
+    Block,
+}
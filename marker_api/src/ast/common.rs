@@ -3,7 +3,7 @@ pub use ast_path::*;
 
 use std::fmt::Debug;
 
-use crate::common::ItemId;
+use crate::{common::ItemId, context::with_cx};
 
 use super::generic::GenericArgs;
 
@@ -26,6 +26,11 @@ impl<'ast> TraitRef<'ast> {
         self.item_id
     }
 
+    /// The path of the referenced trait, as written by the user.
+    pub fn path(&self) -> &AstQPath<'ast> {
+        with_cx(self, |cx| cx.trait_ref_path(self))
+    }
+
     pub fn generics(&self) -> &GenericArgs<'ast> {
         &self.generics
     }
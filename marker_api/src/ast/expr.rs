@@ -2,7 +2,7 @@ use crate::{
     common::{ExprId, HasNodeId, SpanId},
     prelude::EmissionNode,
     private::Sealed,
-    sem::TyKind,
+    sem::{Adjustment, TyKind},
     span::{HasSpan, Span},
     CtorBlocker,
 };
@@ -13,6 +13,7 @@ mod block_expr;
 mod call_exprs;
 mod control_flow_expr;
 mod ctor_expr;
+mod format_args_expr;
 mod lit_expr;
 mod op_exprs;
 mod path_expr;
@@ -22,6 +23,7 @@ pub use block_expr::*;
 pub use call_exprs::*;
 pub use control_flow_expr::*;
 pub use ctor_expr::*;
+pub use format_args_expr::*;
 pub use lit_expr::*;
 pub use op_exprs::*;
 pub use path_expr::*;
@@ -37,8 +39,30 @@ pub trait ExprData<'ast>: Debug + EmissionNode<'ast> + HasSpan<'ast> + HasNodeId
     fn id(&self) -> ExprId;
 
     /// Returns the semantic type of this expression.
+    ///
+    /// This is the type before any [`Adjustment`]s have been applied. The type
+    /// after the adjustments is the target of the last adjustment.
     fn ty(&self) -> TyKind<'ast>;
 
+    /// Returns the implicit conversions, which the compiler applied to this
+    /// expression, in the order they're applied. This includes auto-deref,
+    /// auto-borrow, reborrows and coercions.
+    ///
+    /// ```
+    /// fn takes_slice(_: &[i32]) {}
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// // The expression `&vec` has the type `&Vec<i32>`. It's adjusted to
+    /// // `&[i32]` by dereferencing the reference, then calling `Deref::deref`
+    /// // on the `Vec<i32>` and borrowing the resulting slice again.
+    /// takes_slice(&vec);
+    ///
+    /// // The function item is coerced to a function pointer, with the
+    /// // `ReifyFnPointer` adjustment.
+    /// let _ptr: fn(&[i32]) = takes_slice;
+    /// ```
+    fn adjustments(&self) -> &'ast [Adjustment<'ast>];
+
     /// Returns the [`ExprPrecedence`] of this expression.
     fn precedence(&self) -> ExprPrecedence;
 
@@ -85,6 +109,7 @@ pub enum ExprKind<'ast> {
     Loop(&'ast LoopExpr<'ast>),
     While(&'ast WhileExpr<'ast>),
     Await(&'ast AwaitExpr<'ast>),
+    FormatArgs(&'ast FormatArgsExpr<'ast>),
     Unstable(&'ast UnstableExpr<'ast>),
 }
 
@@ -92,6 +117,7 @@ impl<'ast> ExprKind<'ast> {
     impl_expr_kind_fn!(ExprKind: span() -> &Span<'ast>);
     impl_expr_kind_fn!(ExprKind: id() -> ExprId);
     impl_expr_kind_fn!(ExprKind: ty() -> TyKind<'ast>);
+    impl_expr_kind_fn!(ExprKind: adjustments() -> &'ast [Adjustment<'ast>]);
     impl_expr_kind_fn!(ExprKind: precedence() -> ExprPrecedence);
 }
 
@@ -117,6 +143,7 @@ impl<'ast> LitExprKind<'ast> {
     impl_expr_kind_fn!(LitExprKind: span() -> &Span<'ast>);
     impl_expr_kind_fn!(LitExprKind: id() -> ExprId);
     impl_expr_kind_fn!(LitExprKind: ty() -> TyKind<'ast>);
+    impl_expr_kind_fn!(LitExprKind: adjustments() -> &'ast [Adjustment<'ast>]);
     impl_expr_kind_fn!(LitExprKind: precedence() -> ExprPrecedence);
 }
 
@@ -261,6 +288,7 @@ macro_rules! impl_expr_kind_fn {
             Array, Tuple, Ctor, Range,
             If, Let, Match, Break, Return, Continue, For, Loop, While,
             Await,
+            FormatArgs,
             Unstable
         );
     };
@@ -323,6 +351,10 @@ macro_rules! impl_expr_data {
                 $crate::context::with_cx(self, |cx| cx.expr_ty(self.data.id))
             }
 
+            fn adjustments(&self) -> &'ast [$crate::sem::Adjustment<'ast>] {
+                $crate::context::with_cx(self, |cx| cx.expr_adjustments(self.data.id))
+            }
+
             $precedence_fn
 
             fn as_expr(&'ast self) -> crate::ast::ExprKind<'ast> {
@@ -412,6 +444,7 @@ mod test {
         assert_size_of::<ForExpr<'_>>(&expect!["88"]);
         assert_size_of::<LoopExpr<'_>>(&expect!["56"]);
         assert_size_of::<WhileExpr<'_>>(&expect!["72"]);
+        assert_size_of::<FormatArgsExpr<'_>>(&expect!["48"]);
         assert_size_of::<UnstableExpr<'_>>(&expect!["24"]);
     }
 }
@@ -1,4 +1,10 @@
-use crate::{ast::AstPathSegment, ffi::FfiSlice};
+use crate::{
+    ast::AstPathSegment,
+    common::ItemId,
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+    sem::Adjustment,
+};
 
 use super::{CommonExprData, ExprKind};
 
@@ -64,14 +70,11 @@ impl<'ast> MethodExpr<'ast> {
         &self.method
     }
 
-    // FIXME(xFrednet): Add this method again, once `resolve_method_target` is
-    // supported by rustc's driver
-    //
-    // /// This method resolves the [`ItemId`] of the method being called by this
-    // /// expression.
-    // pub fn resolve(&self) -> ItemId {
-    //     with_cx(self, |cx| cx.resolve_method_target(self.data.id))
-    // }
+    /// This method resolves the method being called by this expression. See
+    /// [`MethodTarget`] for more information.
+    pub fn resolve(&self) -> &MethodTarget<'ast> {
+        with_cx(self, |cx| cx.resolve_method_target(self.data.id))
+    }
 
     /// The arguments given to the operand.
     pub fn args(&self) -> &[ExprKind<'ast>] {
@@ -81,6 +84,70 @@ impl<'ast> MethodExpr<'ast> {
 
 super::impl_expr_data!(MethodExpr<'ast>, Method);
 
+/// The resolved target of a [`MethodExpr`].
+///
+/// ```
+/// # use std::rc::Rc;
+/// let mut vec = vec![1, 2, 3];
+///
+/// // An inherent method. `id()` and `impl_item()` both return the
+/// // `ItemId` of `Vec::push`.
+/// vec.push(4);
+///
+/// // A trait method. `id()` returns the `ItemId` of `Clone::clone` and
+/// // `impl_item()` the `clone` method of the `impl Clone for Vec<T>` block.
+/// let _ = vec.clone();
+///
+/// // The receiver has the type `Rc<Vec<i32>>`. It's dereferenced using
+/// // `Deref::deref` and then borrowed, before `Vec::len` is called.
+/// // These adjustments are available via `receiver_adjustments()`.
+/// let rc = Rc::new(vec);
+/// let _ = rc.len();
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct MethodTarget<'ast> {
+    id: ItemId,
+    #[cfg_attr(feature = "driver-api", builder(default))]
+    is_trait_method: bool,
+    #[cfg_attr(feature = "driver-api", builder(setter(into), default))]
+    impl_item: FfiOption<ItemId>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    receiver_adjustments: FfiSlice<'ast, Adjustment<'ast>>,
+}
+
+impl<'ast> MethodTarget<'ast> {
+    /// The [`ItemId`] of the method, as it was resolved during type checking.
+    /// For inherent methods, this is the method itself. For trait methods,
+    /// this is the method declaration inside the trait.
+    pub fn id(&self) -> ItemId {
+        self.id
+    }
+
+    /// Returns `true`, if the called method is declared by a trait.
+    pub fn is_trait_method(&self) -> bool {
+        self.is_trait_method
+    }
+
+    /// The [`ItemId`] of the method implementation that will be executed, if
+    /// it's statically known.
+    ///
+    /// For inherent methods, this is the same as [`MethodTarget::id`]. For trait
+    /// methods, this is the method in the `impl` block of the receiver type,
+    /// or the default implementation in the trait. It's [`None`] if the
+    /// implementation depends on a generic parameter or trait object.
+    pub fn impl_item(&self) -> Option<ItemId> {
+        self.impl_item.copy()
+    }
+
+    /// The adjustments, like auto-deref and auto-borrow, which are applied to
+    /// the receiver, in the order they're applied.
+    pub fn receiver_adjustments(&self) -> &[Adjustment<'ast>] {
+        self.receiver_adjustments.get()
+    }
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> MethodExpr<'ast> {
     pub fn new(
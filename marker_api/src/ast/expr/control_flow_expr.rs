@@ -1,6 +1,6 @@
 use crate::{
-    ast::pat::PatKind,
-    common::{ExprId, SpanId},
+    ast::{pat::PatKind, Attribute},
+    common::{ArmId, ExprId, SpanId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
     span::{Ident, Span},
@@ -196,6 +196,7 @@ impl<'ast> MatchExpr<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct MatchArm<'ast> {
+    id: ArmId,
     span: SpanId,
     pat: PatKind<'ast>,
     guard: FfiOption<ExprKind<'ast>>,
@@ -203,6 +204,10 @@ pub struct MatchArm<'ast> {
 }
 
 impl<'ast> MatchArm<'ast> {
+    pub fn id(&self) -> ArmId {
+        self.id
+    }
+
     pub fn span(&self) -> &Span<'ast> {
         with_cx(self, |cx| cx.span(self.span))
     }
@@ -219,13 +224,25 @@ impl<'ast> MatchArm<'ast> {
         self.expr
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this arm.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 }
 
+crate::common::impl_identifiable_for!(MatchArm<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> MatchArm<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, guard: Option<ExprKind<'ast>>, expr: ExprKind<'ast>) -> Self {
+    pub fn new(
+        id: ArmId,
+        span: SpanId,
+        pat: PatKind<'ast>,
+        guard: Option<ExprKind<'ast>>,
+        expr: ExprKind<'ast>,
+    ) -> Self {
         Self {
+            id,
             span,
             pat,
             guard: guard.into(),
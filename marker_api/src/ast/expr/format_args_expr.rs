@@ -0,0 +1,369 @@
+use std::marker::PhantomData;
+
+use crate::{
+    common::{SpanId, SymbolId},
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+    span::{Ident, Span},
+};
+
+use super::{CommonExprData, ExprKind, ExprPrecedence};
+
+/// An expression created by the [`format_args!`] macro. This macro is used by
+/// all formatting macros of the standard library, like [`format!`],
+/// [`println!`], [`write!`] and [`panic!`]. Instead of the desugared internals,
+/// this expression exposes the template and the arguments, as written by the
+/// user:
+///
+/// ```
+/// # let name = "Marker";
+/// # let width = 5;
+/// println!("Hello {name}! {:>width$} {0:?}", 1);
+/// //        ^^^^^^ A literal piece
+/// //              ^^^^^^ A placeholder with the captured argument `name`
+/// //                      ^^^^^^^^^^ A placeholder with an implicit position,
+/// //                                 a right alignment and the captured
+/// //                                 argument `width` as the width
+/// //                                 ^^^^^ A placeholder using the `Debug`
+/// //                                       trait for the argument `1`
+/// ```
+///
+/// The arguments of this expression are ordered like the arguments of
+/// [`format_args!`]. Explicit arguments come first, followed by the captured
+/// arguments, in the order of their first usage.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct FormatArgsExpr<'ast> {
+    data: CommonExprData<'ast>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    template: FfiSlice<'ast, FormatArgsPiece<'ast>>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    args: FfiSlice<'ast, FormatArg<'ast>>,
+}
+
+impl<'ast> FormatArgsExpr<'ast> {
+    /// The pieces of the template string, consisting of literal strings and
+    /// placeholders. Escaped braces, like `{{`, are unescaped in the literal
+    /// pieces.
+    pub fn template(&self) -> &[FormatArgsPiece<'ast>] {
+        self.template.get()
+    }
+
+    /// The placeholders of the template string.
+    pub fn placeholders(&self) -> impl Iterator<Item = &FormatPlaceholder<'ast>> {
+        self.template().iter().filter_map(|piece| match piece {
+            FormatArgsPiece::Placeholder(placeholder) => Some(*placeholder),
+            FormatArgsPiece::Lit(_) => None,
+        })
+    }
+
+    /// The arguments, which can be referenced by the placeholders of the
+    /// template.
+    pub fn args(&self) -> &[FormatArg<'ast>] {
+        self.args.get()
+    }
+}
+
+super::impl_expr_data!(
+    FormatArgsExpr<'ast>,
+    FormatArgs,
+    fn precedence(&self) -> ExprPrecedence {
+        ExprPrecedence::Lit
+    }
+);
+
+/// A piece of the template string of a [`FormatArgsExpr`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum FormatArgsPiece<'ast> {
+    /// A literal string, like `"Hello "` in `"Hello {name}"`.
+    Lit(&'ast FormatLitPiece<'ast>),
+    /// A placeholder, like `{name}` or `{:>5}`.
+    Placeholder(&'ast FormatPlaceholder<'ast>),
+}
+
+/// A literal string in the template of a [`FormatArgsExpr`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FormatLitPiece<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    value: SymbolId,
+}
+
+impl<'ast> FormatLitPiece<'ast> {
+    pub fn value(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.value))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> FormatLitPiece<'ast> {
+    pub fn new(value: SymbolId) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            value,
+        }
+    }
+}
+
+/// A placeholder in the template of a [`FormatArgsExpr`], like `{}`, `{0:?}`
+/// or `{name:>width$.2}`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct FormatPlaceholder<'ast> {
+    #[cfg_attr(feature = "driver-api", builder(setter(skip), default))]
+    _lifetime: PhantomData<&'ast ()>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    span: FfiOption<SpanId>,
+    arg_index: usize,
+    arg_kind: FormatArgPositionKind,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    arg_span: FfiOption<SpanId>,
+    format_trait: FormatTrait,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    width: FfiOption<FormatCount>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    precision: FfiOption<FormatCount>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    alignment: FfiOption<FormatAlignment>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    fill: FfiOption<char>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    sign: FfiOption<FormatSign>,
+    is_alternate: bool,
+    is_zero_padded: bool,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    debug_hex: FfiOption<FormatDebugHex>,
+}
+
+impl<'ast> FormatPlaceholder<'ast> {
+    /// The [`Span`] of the entire placeholder, including the braces. This
+    /// can be [`None`], if the template string doesn't originate from a
+    /// string literal, for example, if it was created by a macro.
+    pub fn span(&self) -> Option<&Span<'ast>> {
+        self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
+    }
+
+    /// The index of the formatted argument in [`FormatArgsExpr::args`].
+    pub fn arg_index(&self) -> usize {
+        self.arg_index
+    }
+
+    /// Describes how the formatted argument is referenced by this placeholder.
+    pub fn arg_kind(&self) -> FormatArgPositionKind {
+        self.arg_kind
+    }
+
+    /// The [`Span`] of the argument reference inside the placeholder, like the
+    /// `0` in `{0:?}`. This is [`None`] for implicit references, like `{:?}`.
+    pub fn arg_span(&self) -> Option<&Span<'ast>> {
+        self.arg_span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
+    }
+
+    /// The formatting trait, which is used to format the argument.
+    pub fn format_trait(&self) -> FormatTrait {
+        self.format_trait
+    }
+
+    /// The minimum width, like the `5` in `{:5}`.
+    pub fn width(&self) -> Option<FormatCount> {
+        self.width.copy()
+    }
+
+    /// The precision, like the `2` in `{:.2}`.
+    pub fn precision(&self) -> Option<FormatCount> {
+        self.precision.copy()
+    }
+
+    /// The alignment, like the `<` in `{:<5}`.
+    pub fn alignment(&self) -> Option<FormatAlignment> {
+        self.alignment.copy()
+    }
+
+    /// The fill character, like the `-` in `{:-^5}`.
+    pub fn fill(&self) -> Option<char> {
+        self.fill.copy()
+    }
+
+    /// The sign flag, like the `+` in `{:+}`.
+    pub fn sign(&self) -> Option<FormatSign> {
+        self.sign.copy()
+    }
+
+    /// Returns `true`, if the alternate flag `#` is set, like in `{:#?}`.
+    pub fn is_alternate(&self) -> bool {
+        self.is_alternate
+    }
+
+    /// Returns `true`, if the `0` flag is set, like in `{:05}`.
+    pub fn is_zero_padded(&self) -> bool {
+        self.is_zero_padded
+    }
+
+    /// The hex flag for debug formatting, like the `x` in `{:x?}`.
+    pub fn debug_hex(&self) -> Option<FormatDebugHex> {
+        self.debug_hex.copy()
+    }
+
+    /// Returns `true`, if this placeholder uses the default formatting
+    /// options, like `{}`, `{name}` or `{0:?}`.
+    pub fn has_default_options(&self) -> bool {
+        self.width.get().is_none()
+            && self.precision.get().is_none()
+            && self.alignment.get().is_none()
+            && self.fill.get().is_none()
+            && self.sign.get().is_none()
+            && !self.is_alternate
+            && !self.is_zero_padded
+            && self.debug_hex.get().is_none()
+    }
+}
+
+/// Describes how an argument is referenced by a [`FormatPlaceholder`] or a
+/// [`FormatCount`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatArgPositionKind {
+    /// The argument is referenced implicitly by its position, like `{}`.
+    Implicit,
+    /// The argument is referenced by its index, like `{0}`.
+    Number,
+    /// The argument is referenced by its name, like `{name}`.
+    Named,
+}
+
+/// The formatting trait of a [`FormatPlaceholder`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatTrait {
+    /// `{}`, using [`Display`](std::fmt::Display)
+    Display,
+    /// `{:?}`, using [`Debug`](std::fmt::Debug)
+    Debug,
+    /// `{:e}`, using [`LowerExp`](std::fmt::LowerExp)
+    LowerExp,
+    /// `{:E}`, using [`UpperExp`](std::fmt::UpperExp)
+    UpperExp,
+    /// `{:o}`, using [`Octal`](std::fmt::Octal)
+    Octal,
+    /// `{:p}`, using [`Pointer`](std::fmt::Pointer)
+    Pointer,
+    /// `{:b}`, using [`Binary`](std::fmt::Binary)
+    Binary,
+    /// `{:x}`, using [`LowerHex`](std::fmt::LowerHex)
+    LowerHex,
+    /// `{:X}`, using [`UpperHex`](std::fmt::UpperHex)
+    UpperHex,
+}
+
+/// The width or precision of a [`FormatPlaceholder`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatCount {
+    /// A literal value, like the `5` in `{:5}`.
+    Lit(usize),
+    /// The value is taken from an argument, like `{:width$}` or `{:.*}`. The
+    /// value is the index of the argument in [`FormatArgsExpr::args`].
+    Arg(usize),
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatAlignment {
+    /// `{:<}`
+    Left,
+    /// `{:>}`
+    Right,
+    /// `{:^}`
+    Center,
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatSign {
+    /// `{:+}`
+    Plus,
+    /// `{:-}`
+    Minus,
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatDebugHex {
+    /// `{:x?}`
+    Lower,
+    /// `{:X?}`
+    Upper,
+}
+
+/// An argument of a [`FormatArgsExpr`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FormatArg<'ast> {
+    span: SpanId,
+    kind: FormatArgKind,
+    ident: FfiOption<Ident<'ast>>,
+    expr: FfiOption<ExprKind<'ast>>,
+}
+
+impl<'ast> FormatArg<'ast> {
+    /// The [`Span`] of the argument expression. For captured arguments, this
+    /// is the span of the name inside the template string.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    pub fn kind(&self) -> FormatArgKind {
+        self.kind
+    }
+
+    /// The name of this argument, if it's a named or captured argument.
+    pub fn ident(&self) -> Option<&Ident<'ast>> {
+        self.ident.get()
+    }
+
+    /// The argument expression. For captured arguments, this is a
+    /// [`PathExpr`](super::PathExpr), referencing the captured variable.
+    ///
+    /// Rustc inlines some arguments into the template, like string literals or
+    /// nested [`format_args!`] calls. This returns [`None`] for these arguments,
+    /// as the expression is no longer available after the desugaring.
+    pub fn expr(&self) -> Option<ExprKind<'ast>> {
+        self.expr.copy()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> FormatArg<'ast> {
+    pub fn new(span: SpanId, kind: FormatArgKind, ident: Option<Ident<'ast>>, expr: Option<ExprKind<'ast>>) -> Self {
+        Self {
+            span,
+            kind,
+            ident: ident.into(),
+            expr: expr.into(),
+        }
+    }
+}
+
+/// Describes how an argument was passed to a [`FormatArgsExpr`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FormatArgKind {
+    /// A positional argument, like the `1` in `format!("{}", 1)`.
+    Normal,
+    /// A named argument, like the `x = 1` in `format!("{x}", x = 1)`.
+    Named,
+    /// An argument, captured from the surrounding scope, like the `x` in
+    /// `format!("{x}")`.
+    Captured,
+}
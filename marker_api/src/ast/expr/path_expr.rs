@@ -1,4 +1,7 @@
-use crate::ast::AstQPath;
+use crate::{
+    ast::{AstPathTarget, AstQPath},
+    common::VarId,
+};
 
 use super::CommonExprData;
 
@@ -16,6 +19,17 @@ impl<'ast> PathExpr<'ast> {
     pub fn path(&self) -> &AstQPath<'ast> {
         &self.path
     }
+
+    /// Returns the [`VarId`], if this path references a local variable. The
+    /// binding of the variable is the [`IdentPat`](crate::ast::IdentPat) with
+    /// the same [`VarId`]. All usages of the variable can be requested with
+    /// [`MarkerContext::local_usages`](crate::MarkerContext::local_usages).
+    pub fn resolve_local(&self) -> Option<VarId> {
+        match self.path.resolve() {
+            AstPathTarget::Var(id) => Some(id),
+            _ => None,
+        }
+    }
 }
 
 super::impl_expr_data!(PathExpr<'ast>, Path);
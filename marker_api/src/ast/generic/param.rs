@@ -6,6 +6,7 @@ use crate::{
         expr::ConstExpr,
         generic::{GenericParams, Lifetime},
         ty::TyKind,
+        Attribute,
     },
     common::{GenericId, SpanId, SymbolId},
     context::with_cx,
@@ -34,7 +35,7 @@ use super::TyParamBound;
 ///
 /// See: <https://doc.rust-lang.org/reference/items/generics.html>
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
 pub enum GenericParamKind<'ast> {
     Lifetime(&'ast LifetimeParam<'ast>),
@@ -60,6 +61,14 @@ impl<'ast> GenericParamKind<'ast> {
             GenericParamKind::Const(param) => param.id(),
         }
     }
+
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        match self {
+            GenericParamKind::Ty(param) => param.attrs(),
+            GenericParamKind::Lifetime(param) => param.attrs(),
+            GenericParamKind::Const(param) => param.attrs(),
+        }
+    }
 }
 
 /// This trait is a collection of common information that is provided by all
@@ -72,7 +81,8 @@ pub trait SynGenericParamData<'ast>: Debug + Sealed {
     /// code.
     fn span(&self) -> Option<&Span<'ast>>;
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this parameter.
+    fn attrs(&self) -> &[Attribute<'ast>];
 }
 
 /// A type parameter with optional bounds like `T` and `U` in this example:
@@ -117,8 +127,14 @@ impl<'ast> SynGenericParamData<'ast> for TyParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 }
 
+crate::common::impl_identifiable_for!(TyParam<'ast>);
+
 impl<'ast> From<&'ast TyParam<'ast>> for GenericParamKind<'ast> {
     fn from(src: &'ast TyParam<'ast>) -> Self {
         Self::Ty(src)
@@ -167,8 +183,14 @@ impl<'ast> SynGenericParamData<'ast> for LifetimeParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         self.span.get().map(|span| with_cx(self, |cx| cx.span(*span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 }
 
+crate::common::impl_identifiable_for!(LifetimeParam<'ast>);
+
 impl<'ast> From<&'ast LifetimeParam<'ast>> for GenericParamKind<'ast> {
     fn from(src: &'ast LifetimeParam<'ast>) -> Self {
         Self::Lifetime(src)
@@ -213,8 +235,14 @@ impl<'ast> SynGenericParamData<'ast> for ConstParam<'ast> {
     fn span(&self) -> Option<&Span<'ast>> {
         Some(with_cx(self, |cx| cx.span(self.span)))
     }
+
+    fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 }
 
+crate::common::impl_identifiable_for!(ConstParam<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> ConstParam<'ast> {
     pub fn new(
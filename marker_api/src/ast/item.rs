@@ -10,7 +10,7 @@ use crate::{
     CtorBlocker,
 };
 
-use super::expr::ExprKind;
+use super::{expr::ExprKind, Attribute};
 
 // Item implementations
 mod extern_crate_item;
@@ -59,12 +59,9 @@ pub trait ItemData<'ast>: Debug + EmissionNode<'ast> + HasSpan<'ast> + HasNodeId
     /// as a bound to support all items and `ItemKind<'ast>` as parameters.
     fn as_item(&'ast self) -> ItemKind<'ast>;
 
-    /// The attributes attached to this item.
-    ///
-    /// Currently, it's only a placeholder until a proper representation is implemented.
-    /// rust-marker/marker#51 tracks the task of implementing this. You're welcome to
-    /// leave any comments in that issue.
-    fn attrs(&self); // FIXME: Add return type: -> &'ast [&'ast dyn Attribute<'ast>];
+    /// The attributes attached to this item. This includes outer attributes,
+    /// written before the item, and inner attributes, written inside the item.
+    fn attrs(&self) -> &[Attribute<'ast>];
 }
 
 #[repr(C)]
@@ -92,7 +89,7 @@ impl<'ast> ItemKind<'ast> {
     impl_item_type_fn!(ItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ItemKind: attrs() -> ());
+    impl_item_type_fn!(ItemKind: attrs() -> &[Attribute<'ast>]);
 }
 
 crate::span::impl_spanned_for!(ItemKind<'ast>);
@@ -111,7 +108,7 @@ impl<'ast> AssocItemKind<'ast> {
     impl_item_type_fn!(AssocItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(AssocItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(AssocItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(AssocItemKind: attrs() -> ());
+    impl_item_type_fn!(AssocItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(AssocItemKind: as_item() -> ItemKind<'ast>);
     // FIXME: Potentially add a field to the items to optionally store the owner id
 }
@@ -141,7 +138,7 @@ impl<'ast> ExternItemKind<'ast> {
     impl_item_type_fn!(ExternItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ExternItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ExternItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ExternItemKind: attrs() -> ());
+    impl_item_type_fn!(ExternItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(ExternItemKind: as_item() -> ItemKind<'ast>);
 }
 
@@ -217,7 +214,9 @@ macro_rules! impl_item_data {
                 $crate::ast::item::ItemKind::$enum_name(self)
             }
 
-            fn attrs(&self) {}
+            fn attrs(&self) -> &[$crate::ast::Attribute<'ast>] {
+                $crate::context::with_cx(self, |cx| cx.ast().attrs(self))
+            }
         }
 
         impl<'ast> $crate::span::HasSpan<'ast> for $self_name<'ast> {
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{expr::ConstExpr, generic::GenericParams, ty::TyKind},
+    ast::{expr::ConstExpr, generic::GenericParams, ty::TyKind, Attribute},
     common::{FieldId, SpanId, SymbolId, VariantId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
@@ -111,7 +111,10 @@ impl<'ast> EnumVariant<'ast> {
         with_cx(self, |cx| cx.symbol_str(self.ident))
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this variant.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 
     /// Returns `true` if this is a unit variant like:
     ///
@@ -309,7 +312,10 @@ impl<'ast> ItemField<'ast> {
         self.ty
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The attributes attached to this field.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        with_cx(self, |cx| cx.ast().attrs(self))
+    }
 }
 
 impl<'ast> HasSpan<'ast> for ItemField<'ast> {
@@ -3,12 +3,13 @@ use std::marker::PhantomData;
 
 use crate::{
     common::{HasNodeId, SpanId, StmtId},
+    context::with_cx,
     ffi::FfiOption,
     private::Sealed,
     span::{HasSpan, Span},
 };
 
-use super::{expr::ExprKind, item::ItemKind, pat::PatKind, ty::TyKind};
+use super::{expr::ExprKind, item::ItemKind, pat::PatKind, ty::TyKind, Attribute};
 
 /// This trait combines methods, which all statements have in common.
 ///
@@ -45,12 +46,14 @@ impl<'ast> StmtKind<'ast> {
         }
     }
 
-    /// Returns the attributes attached to this statement.
-    ///
-    /// Currently, it's only a placeholder until a proper representation is implemented.
-    /// rust-marker/marker#51 tracks the task of implementing this. You're welcome to
-    /// leave any comments in that issue.
-    pub fn attrs(&self) {}
+    /// Returns the attributes attached to this statement. For item statements,
+    /// these are the attributes of the item.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        match self {
+            StmtKind::Item(node, ..) => with_cx(*node, |cx| cx.ast().attrs(node.item())),
+            _ => with_cx(self, |cx| cx.ast().attrs(self)),
+        }
+    }
 }
 
 crate::span::impl_spanned_for!(StmtKind<'ast>);
@@ -208,19 +208,38 @@ pub enum Level {
     Forbid,
 }
 
-/// FIXME(xFrednet): These settings should be working now, but are still limited
-/// due to the limited [`Span`](crate::span::Span) implementation. Ideally, I would
-/// also like more options, like a `Local` variant that only lints in local marcos.
-/// For libraries it might also be cool to have a `Crate` variant, that only lints
-/// in user code and code from macros from the specified crate.
+/// Specifies if a lint should be reported in code from macro expansions.
 ///
-/// See: rust-marker/marker#149
+/// The macros are determined by the expansions of the linted node's
+/// [`Span`](crate::span::Span). A node is only reported, if every macro in the
+/// expansion chain is allowed. A node created by `vec![]` inside a local
+/// `macro_rules!` macro is therefore not reported with [`MacroReport::Local`],
+/// since `vec` is an external macro. See [`ExpnInfo`](crate::span::ExpnInfo)
+/// to inspect the macro of an expansion.
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum MacroReport {
     /// No reporting in local or external macros.
     No,
+    /// Report in macros, which are defined in the linted crate, but not in
+    /// macros from other crates.
+    Local,
+    /// Report in local macros and macros from the listed crates. The crates
+    /// are identified by their name, with dashes replaced by underscores,
+    /// like `my_macros` for a crate named `my-macros`.
+    ///
+    /// ```
+    /// # use marker_api::common::MacroReport;
+    /// marker_api::declare_lint! {
+    ///     /// # What it does
+    ///     /// Reports in user code and in the macros of `my_macros`.
+    ///     MY_LINT,
+    ///     Warn,
+    ///     MacroReport::Crates(&["my_macros"]),
+    /// }
+    /// ```
+    Crates(&'static [&'static str]),
     /// Report in local and external macros.
     All,
 }
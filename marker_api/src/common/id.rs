@@ -142,11 +142,25 @@ new_id! {
     pub(crate) SymbolId: u32
 }
 
+new_id! {
+    /// **Unstable**
+    ///
+    /// This id is used by drivers to map semantic types back to their internal
+    /// representation. This type is only intended for internal use.
+    #[cfg_attr(feature = "driver-api", visibility::make(pub))]
+    pub(crate) DriverTyId: u64
+}
+
 new_id! {
     /// This ID uniquely identifies a statement during linting.
     pub StmtId: u64
 }
 
+new_id! {
+    /// This ID uniquely identifies an arm of a match expression during linting.
+    pub ArmId: u64
+}
+
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
@@ -157,6 +171,8 @@ pub enum NodeId {
     Body(BodyId),
     Field(FieldId),
     Variant(VariantId),
+    Generic(GenericId),
+    Arm(ArmId),
 }
 
 macro_rules! impl_into_node_id_for {
@@ -181,6 +197,8 @@ impl_into_node_id_for!(Stmt, StmtId);
 impl_into_node_id_for!(Body, BodyId);
 impl_into_node_id_for!(Field, FieldId);
 impl_into_node_id_for!(Variant, VariantId);
+impl_into_node_id_for!(Generic, GenericId);
+impl_into_node_id_for!(Arm, ArmId);
 
 pub trait HasNodeId: Sealed {
     /// Returns the [`NodeId`] of the identifiable node
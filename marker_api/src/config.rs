@@ -0,0 +1,178 @@
+use std::cell::Cell;
+
+use crate::ffi::FfiStr;
+
+thread_local! {
+    /// The configuration of this lint crate. The value is set by the adapter,
+    /// before the lint crate is invoked via [`set_lint_crate_config`].
+    #[doc(hidden)]
+    static LINT_CRATE_CONFIG: Cell<Option<&'static LintCrateConfig<'static>>> = Cell::new(None);
+}
+
+/// **Warning**
+///
+/// This function is unstable and only exported, to enable the adapter to set
+/// the [`LintCrateConfig`] for a lint crate.
+#[doc(hidden)]
+pub fn set_lint_crate_config(config: Option<&'static LintCrateConfig<'static>>) {
+    LINT_CRATE_CONFIG.with(|cell| cell.set(config));
+}
+
+/// Returns the configuration of this lint crate, if the user specified one in
+/// the `[workspace.metadata.marker.config.<lint_crate>]` table of their
+/// `Cargo.toml` file.
+///
+/// Unlike [`MarkerContext::lint_crate_config`](crate::MarkerContext::lint_crate_config),
+/// this function doesn't require a context. It can therefore also be used in
+/// the constructor of the lint pass and in [`LintPass::info`](crate::LintPass::info).
+pub fn lint_crate_config() -> Option<&'static LintCrateConfig<'static>> {
+    LINT_CRATE_CONFIG.with(Cell::get)
+}
+
+/// The configuration of a lint crate. Users can specify it in the
+/// `[workspace.metadata.marker.config.<lint_crate>]` table of their `Cargo.toml`
+/// file, like this:
+///
+/// ```toml
+/// [workspace.metadata.marker.lints]
+/// my_lints = "0.1.0"
+///
+/// [workspace.metadata.marker.config.my_lints]
+/// max_fn_params = 5
+/// banned_names = ["foo", "bar"]
+/// ```
+///
+/// The configuration can be retrieved via [`lint_crate_config`] or
+/// [`MarkerContext::lint_crate_config`](crate::MarkerContext::lint_crate_config).
+/// With the `serde` feature, it can be deserialized into a custom type with
+/// [`LintCrateConfig::deserialize`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct LintCrateConfig<'a> {
+    lint_crate: FfiStr<'a>,
+    manifest_path: FfiStr<'a>,
+    json: FfiStr<'a>,
+}
+
+impl<'a> LintCrateConfig<'a> {
+    /// The name of the lint crate, as specified in the `Cargo.toml` file.
+    pub fn lint_crate(&self) -> &'a str {
+        self.lint_crate.get()
+    }
+
+    /// The path of the `Cargo.toml` file, which contains the configuration.
+    pub fn manifest_path(&self) -> &'a str {
+        self.manifest_path.get()
+    }
+
+    /// The name of the TOML table, which contains the configuration, like
+    /// `workspace.metadata.marker.config.my_lints`. This is useful to point
+    /// users to the location of invalid values.
+    pub fn table(&self) -> String {
+        format!("workspace.metadata.marker.config.{}", self.lint_crate())
+    }
+
+    /// The key, which declares the lint crate, like
+    /// `workspace.metadata.marker.lints.my_lints`.
+    pub fn lint_crate_key(&self) -> String {
+        format!("workspace.metadata.marker.lints.{}", self.lint_crate())
+    }
+
+    /// The configuration value, serialized as JSON.
+    pub fn as_json(&self) -> &'a str {
+        self.json.get()
+    }
+
+    /// Deserializes the configuration into the given type. The returned
+    /// error points to the location of the configuration in the `Cargo.toml`
+    /// file and can be displayed to the user.
+    ///
+    /// ```ignore
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     max_fn_params: usize,
+    /// }
+    ///
+    /// if let Some(config) = cx.lint_crate_config() {
+    ///     match config.deserialize::<Config>() {
+    ///         Ok(config) => { /* ... */ },
+    ///         Err(err) => eprintln!("{err}"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This returns an error, if the configuration doesn't match the given type.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&self) -> Result<T, ConfigError> {
+        serde_json::from_str(self.as_json()).map_err(|err| {
+            // The line and column refer to the JSON representation, which
+            // would only confuse users. They're therefore removed.
+            let message = err.to_string();
+            let position = format!(" at line {} column {}", err.line(), err.column());
+            let message = message.strip_suffix(&position).unwrap_or(&message).to_string();
+            ConfigError {
+                manifest_path: self.manifest_path().to_string(),
+                table: self.table(),
+                lint_crate_key: self.lint_crate_key(),
+                message,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'a> LintCrateConfig<'a> {
+    pub fn new(lint_crate: &'a str, manifest_path: &'a str, json: &'a str) -> Self {
+        Self {
+            lint_crate: lint_crate.into(),
+            manifest_path: manifest_path.into(),
+            json: json.into(),
+        }
+    }
+}
+
+/// An error, which occurred while deserializing a [`LintCrateConfig`].
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    manifest_path: String,
+    table: String,
+    lint_crate_key: String,
+    message: String,
+}
+
+impl ConfigError {
+    /// The path of the `Cargo.toml` file, which contains the invalid configuration.
+    pub fn manifest_path(&self) -> &str {
+        &self.manifest_path
+    }
+
+    /// The name of the TOML table, which contains the invalid configuration.
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// The key, which declares the lint crate of the invalid configuration,
+    /// like `workspace.metadata.marker.lints.my_lints`.
+    pub fn lint_crate_key(&self) -> &str {
+        &self.lint_crate_key
+    }
+
+    /// The description of the error, without location information.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid config in `[{}]` of `{}`, for the lint crate declared as `{}`: {}",
+            self.table, self.manifest_path, self.lint_crate_key, self.message
+        )
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -8,12 +8,13 @@
 use std::{cell::RefCell, mem::transmute};
 
 use crate::{
-    common::{ExpnId, ExprId, ItemId, Level, MacroReport, SpanId, SymbolId, TyDefId},
+    ast::{AstQPath, ExprKind, MethodTarget, TraitRef},
+    common::{ExpnId, ExprId, ItemId, Level, MacroId, MacroReport, SpanId, SymbolId, TyDefId, VarId},
     diagnostic::{Diagnostic, DiagnosticBuilder, EmissionNode},
     ffi,
-    sem::TyKind,
-    span::{ExpnInfo, FileInfo, FilePos, Span, SpanPos, SpanSource},
-    Lint,
+    sem::{Adjustment, ConstValue, LocalUsage, TyKind, TyLayout, TyProperty},
+    span::{Comment, ExpnInfo, FileInfo, FilePos, Span, SpanPos, SpanSource},
+    Lint, LintCrateConfig,
 };
 
 mod map;
@@ -215,7 +216,7 @@ impl<'ast> MarkerContext<'ast> {
     ) -> DiagnosticBuilder<'ast> {
         let id = node.node_id();
         let span = node.span();
-        if matches!(lint.report_in_macro, MacroReport::No) && span.is_from_expansion() {
+        if span.is_from_expansion() && !is_reported_in_macro(lint.report_in_macro, span) {
             return DiagnosticBuilder::dummy();
         }
         if self.ast().lint_level_at(lint, &node) == Level::Allow {
@@ -253,6 +254,153 @@ impl<'ast> MarkerContext<'ast> {
     pub fn resolve_ty_ids(&self, path: &str) -> &[TyDefId] {
         (self.callbacks.resolve_ty_ids)(self.callbacks.data, path.into()).get()
     }
+
+    /// This function tries to resolve the given path to the corresponding [`ItemId`]s.
+    ///
+    /// In contrast to [`MarkerContext::resolve_ty_ids`], this resolves all kinds of
+    /// items, like functions, constants, statics, traits and modules. Associated
+    /// items of traits and inherent `impl` blocks can be resolved by appending
+    /// their name to the path of the trait or type, like `std::vec::Vec::new`.
+    ///
+    /// The slice might be empty if the path could not be resolved. This could be
+    /// due to an error in the path or because the linted crate doesn't have the
+    /// required dependency. The function can also return multiple [`ItemId`]s,
+    /// if there are multiple crates with different versions in the dependency
+    /// tree, or if several inherent `impl` blocks define an item with this name.
+    ///
+    /// The returned ids are unordered and, depending on the driver, can also
+    /// change during different calls. The slice should not be stored across
+    /// `check_*` calls.
+    ///
+    /// Here is a simple example, how the method could be used:
+    /// ```ignore
+    /// // Check if a method call calls `Vec::remove`
+    /// if let ExprKind::Method(call) = expr {
+    ///     if cx.resolve_item_ids("std::vec::Vec::remove").contains(&call.resolve().id()) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_item_ids(&self, path: &str) -> &[ItemId] {
+        (self.callbacks.resolve_item_ids)(self.callbacks.data, path.into()).get()
+    }
+
+    /// This function tries to resolve the given path to the corresponding
+    /// [`MacroId`]s. Macros exported with `#[macro_export]` are resolved at the
+    /// root of their crate, like `std::vec`, while other macros use the module
+    /// path, like `crate::module::local_macro`.
+    ///
+    /// The slice might be empty or contain multiple ids, as described for
+    /// [`MarkerContext::resolve_item_ids`].
+    ///
+    /// ```ignore
+    /// // Check if an expression was created by `format_args!`
+    /// if let SpanSource::Macro(expn) = expr.span().source() {
+    ///     if cx.resolve_macro_ids("std::format_args").contains(&expn.macro_id()) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_macro_ids(&self, path: &str) -> &[MacroId] {
+        (self.callbacks.resolve_macro_ids)(self.callbacks.data, path.into()).get()
+    }
+
+    /// Checks if the given type implements the trait identified by `trait_id`.
+    ///
+    /// The `generic_args` are used as the generic arguments of the trait. They
+    /// have to specify all generic parameters of the trait, excluding `Self`.
+    /// This function will return `false`, if the number of arguments doesn't
+    /// match, or if the `trait_id` doesn't belong to a trait.
+    ///
+    /// Generic parameters in the type are checked against the bounds of the
+    /// surrounding item. Lifetimes are ignored by this check.
+    ///
+    /// Common traits can be checked with utility methods on [`TyKind`], like
+    /// [`TyKind::is_copy`] or [`TyKind::is_send`].
+    ///
+    /// Here is a simple example, how the method could be used:
+    /// ```ignore
+    /// // Check if the type of an expression implements `From<u32>`
+    /// let u32_ty = u32_expr.ty();
+    /// for trait_id in cx.resolve_ty_ids("std::convert::From") {
+    ///     if cx.ty_implements_trait(expr.ty(), *trait_id, &[u32_ty]) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn ty_implements_trait(&self, ty: TyKind<'ast>, trait_id: TyDefId, generic_args: &[TyKind<'ast>]) -> bool {
+        (self.callbacks.ty_implements_trait)(self.callbacks.data, ty, trait_id, generic_args.into())
+    }
+
+    /// Returns the memory layout of the given type, if it can be determined.
+    /// This will return `None`, if the layout depends on generic parameters,
+    /// or if the type is unsized, like `str` or `dyn Trait`.
+    ///
+    /// ```ignore
+    /// // Check if a value is too large to be stored on the stack
+    /// if let Some(layout) = cx.ty_layout(expr.ty()) {
+    ///     if layout.size() > MAX_STACK_SIZE {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn ty_layout(&self, ty: TyKind<'ast>) -> Option<&'ast TyLayout<'ast>> {
+        (self.callbacks.ty_layout)(self.callbacks.data, ty).copy()
+    }
+
+    /// Tries to evaluate the given expression at compile time. This will
+    /// return `None`, if the expression is not a constant expression, or if it
+    /// can't be evaluated, for example due to an overflow.
+    ///
+    /// The evaluation supports literals, paths to `const` items and associated
+    /// constants, as well as unary and binary operations on these values.
+    ///
+    /// ```ignore
+    /// // Check the shift amount of `value << amount`
+    /// if let Some(amount) = cx.eval_const(amount_expr).and_then(|value| value.as_u128()) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn eval_const(&self, expr: ExprKind<'ast>) -> Option<ConstValue<'ast>> {
+        (self.callbacks.eval_const)(self.callbacks.data, expr.id())
+            .copy()
+            .copied()
+    }
+
+    /// Returns all usages of the local variable identified by the [`VarId`],
+    /// in the order they appear in the body. This includes usages inside
+    /// closures, which capture the variable. The binding of the variable, the
+    /// [`IdentPat`](crate::ast::IdentPat), isn't included. See [`LocalUsage`]
+    /// for how usages are classified.
+    ///
+    /// The [`VarId`] of a binding is available via [`IdentPat::var_id`] and
+    /// paths referencing the variable can be resolved with
+    /// [`PathExpr::resolve_local`].
+    ///
+    /// ```ignore
+    /// // Check if a variable declared as `mut` is ever mutated
+    /// if ident_pat.mutability().is_mut()
+    ///     && !cx.local_usages(ident_pat.var_id()).iter().any(|usage| {
+    ///         matches!(usage.kind(), LocalUsageKind::Write | LocalUsageKind::Borrow(Mutability::Mut))
+    ///     })
+    /// {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`IdentPat::var_id`]: crate::ast::IdentPat::var_id
+    /// [`PathExpr::resolve_local`]: crate::ast::PathExpr::resolve_local
+    pub fn local_usages(&self, var: VarId) -> &'ast [LocalUsage] {
+        (self.callbacks.local_usages)(self.callbacks.data, var).get()
+    }
+
+    /// Returns the configuration of the current lint crate, if the user
+    /// specified one in the `[workspace.metadata.marker.config.<lint_crate>]`
+    /// table of their `Cargo.toml` file. See [`LintCrateConfig`] for more
+    /// information.
+    pub fn lint_crate_config(&self) -> Option<&'ast LintCrateConfig<'ast>> {
+        crate::config::lint_crate_config()
+    }
 }
 
 impl<'ast> MarkerContext<'ast> {
@@ -260,6 +408,14 @@ impl<'ast> MarkerContext<'ast> {
         self.callbacks.call_expr_ty(expr)
     }
 
+    pub(crate) fn expr_adjustments(&self, expr: ExprId) -> &'ast [Adjustment<'ast>] {
+        (self.callbacks.expr_adjustments)(self.callbacks.data, expr).get()
+    }
+
+    pub(crate) fn trait_ref_path(&self, trait_ref: &TraitRef<'ast>) -> &'ast AstQPath<'ast> {
+        (self.callbacks.trait_ref_path)(self.callbacks.data, trait_ref)
+    }
+
     // FIXME: This function should probably be removed in favor of a better
     // system to deal with spans. See rust-marker/marker#175
     pub(crate) fn span_snipped(&self, span: &Span<'ast>) -> Option<&'ast str> {
@@ -281,15 +437,47 @@ impl<'ast> MarkerContext<'ast> {
     pub(crate) fn span_expn_info(&self, src_id: ExpnId) -> Option<&'ast ExpnInfo<'ast>> {
         (self.callbacks.span_expn_info)(self.callbacks.data, src_id).into()
     }
+    pub(crate) fn file_comments(&self, file: &FileInfo<'ast>) -> &'ast [Comment<'ast>] {
+        (self.callbacks.file_comments)(self.callbacks.data, file).get()
+    }
 
     pub(crate) fn symbol_str(&self, sym: SymbolId) -> &'ast str {
         self.callbacks.call_symbol_str(sym)
     }
 
-    #[allow(unused)] // Will be used later(or removed)
-    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> ItemId {
+    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> &'ast MethodTarget<'ast> {
         self.callbacks.resolve_method_target(expr)
     }
+
+    pub(crate) fn ty_has_property(&self, ty: TyKind<'ast>, property: TyProperty) -> bool {
+        (self.callbacks.ty_has_property)(self.callbacks.data, ty, property)
+    }
+}
+
+fn is_reported_in_macro(report: MacroReport, span: &Span<'_>) -> bool {
+    // Every macro in the expansion chain has to be allowed. Otherwise, a local
+    // macro could be used to wrap code from a macro of another crate.
+    let is_allowed_crate = |crates: &[&str]| {
+        let SpanSource::Macro(mut expn) = span.source() else {
+            return false;
+        };
+        loop {
+            if !expn.is_local_macro() && !crates.iter().any(|name| *name == expn.macro_crate_name()) {
+                return false;
+            }
+            match expn.parent() {
+                Some(parent) => expn = parent,
+                None => return true,
+            }
+        }
+    };
+
+    match report {
+        MacroReport::No => false,
+        MacroReport::Local => is_allowed_crate(&[]),
+        MacroReport::Crates(crates) => is_allowed_crate(crates),
+        MacroReport::All => true,
+    }
 }
 
 /// This struct holds function pointers to driver implementations of required
@@ -319,17 +507,33 @@ struct MarkerContextCallbacks<'ast> {
 
     // Public utility
     pub resolve_ty_ids: extern "C" fn(&'ast MarkerContextData, path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub resolve_item_ids: extern "C" fn(&'ast MarkerContextData, path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub resolve_macro_ids:
+        extern "C" fn(&'ast MarkerContextData, path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, MacroId>,
+    pub ty_implements_trait: extern "C" fn(
+        &'ast MarkerContextData,
+        ty: TyKind<'ast>,
+        trait_id: TyDefId,
+        generic_args: ffi::FfiSlice<'_, TyKind<'ast>>,
+    ) -> bool,
+    pub ty_layout: extern "C" fn(&'ast MarkerContextData, TyKind<'ast>) -> ffi::FfiOption<&'ast TyLayout<'ast>>,
+    pub eval_const: extern "C" fn(&'ast MarkerContextData, ExprId) -> ffi::FfiOption<&'ast ConstValue<'ast>>,
+    pub local_usages: extern "C" fn(&'ast MarkerContextData, VarId) -> ffi::FfiSlice<'ast, LocalUsage>,
 
     // Internal utility
     pub expr_ty: extern "C" fn(&'ast MarkerContextData, ExprId) -> TyKind<'ast>,
+    pub expr_adjustments: extern "C" fn(&'ast MarkerContextData, ExprId) -> ffi::FfiSlice<'ast, Adjustment<'ast>>,
+    pub trait_ref_path: extern "C" fn(&'ast MarkerContextData, &TraitRef<'ast>) -> &'ast AstQPath<'ast>,
     pub span: extern "C" fn(&'ast MarkerContextData, SpanId) -> &'ast Span<'ast>,
     pub span_snippet: extern "C" fn(&'ast MarkerContextData, &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
     pub span_source: extern "C" fn(&'ast MarkerContextData, &Span<'_>) -> SpanSource<'ast>,
     pub span_pos_to_file_loc:
         extern "C" fn(&'ast MarkerContextData, &FileInfo<'ast>, SpanPos) -> ffi::FfiOption<FilePos<'ast>>,
     pub span_expn_info: extern "C" fn(&'ast MarkerContextData, ExpnId) -> ffi::FfiOption<&'ast ExpnInfo<'ast>>,
+    pub file_comments: extern "C" fn(&'ast MarkerContextData, &FileInfo<'ast>) -> ffi::FfiSlice<'ast, Comment<'ast>>,
     pub symbol_str: extern "C" fn(&'ast MarkerContextData, SymbolId) -> ffi::FfiStr<'ast>,
-    pub resolve_method_target: extern "C" fn(&'ast MarkerContextData, ExprId) -> ItemId,
+    pub resolve_method_target: extern "C" fn(&'ast MarkerContextData, ExprId) -> &'ast MethodTarget<'ast>,
+    pub ty_has_property: extern "C" fn(&'ast MarkerContextData, TyKind<'ast>, TyProperty) -> bool,
 }
 
 impl<'ast> MarkerContextCallbacks<'ast> {
@@ -346,7 +550,7 @@ impl<'ast> MarkerContextCallbacks<'ast> {
     fn call_symbol_str(&self, sym: SymbolId) -> &'ast str {
         (self.symbol_str)(self.data, sym).get()
     }
-    pub fn resolve_method_target(&self, expr: ExprId) -> ItemId {
+    pub fn resolve_method_target(&self, expr: ExprId) -> &'ast MethodTarget<'ast> {
         (self.resolve_method_target)(self.data, expr)
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{Body, EnumVariant, ExprKind, ItemField, ItemKind, StmtKind},
+    ast::{Attribute, Body, EnumVariant, ExprKind, ItemField, ItemKind, StmtKind},
     common::{BodyId, ExprId, FieldId, ItemId, Level, StmtId, VariantId},
     ffi,
     lint::Lint,
@@ -45,6 +45,15 @@ impl<'ast> AstMap<'ast> {
         (self.callbacks.lint_level_at)(self.callbacks.data, lint, node.node_id())
     }
 
+    /// Returns the attributes attached to the given node. Outer attributes are
+    /// listed before inner attributes, each in the order they were written.
+    ///
+    /// Nodes without attributes, and nodes which can't have attributes, will
+    /// return an empty slice.
+    pub fn attrs(&self, node: impl HasNodeId) -> &[Attribute<'ast>] {
+        (self.callbacks.attrs)(self.callbacks.data, node.node_id()).get()
+    }
+
     /// Returns the [`ItemKind`] belonging to the given [`ItemId`], if available.
     ///
     /// Checkout the documentation of [`AstMap`] for more information, when a node
@@ -137,6 +146,8 @@ struct AstMapCallbacks<'ast> {
     pub stmt: extern "C" fn(data: &'ast AstMapData, id: StmtId) -> StmtKind<'ast>,
     pub expr: extern "C" fn(data: &'ast AstMapData, id: ExprId) -> ExprKind<'ast>,
 
+    pub attrs: extern "C" fn(data: &'ast AstMapData, node: NodeId) -> ffi::FfiSlice<'ast, Attribute<'ast>>,
+
     pub lint_level_at: extern "C" fn(data: &'ast AstMapData, lint: &'static Lint, node: NodeId) -> Level,
 }
 
@@ -26,7 +26,7 @@ struct DiagnosticBuilderInner<'ast> {
     node: NodeId,
     msg: String,
     span: Span<'ast>,
-    parts: Vec<DiagnosticPart<String, Span<'ast>>>,
+    parts: Vec<OwnedDiagnosticPart<'ast>>,
 }
 
 impl<'ast> DiagnosticBuilder<'ast> {
@@ -213,6 +213,60 @@ impl<'ast> DiagnosticBuilder<'ast> {
         self
     }
 
+    /// This function adds a help message with a suggestion, which consists of
+    /// multiple edits. Each edit replaces the code of a [`Span`] with the given
+    /// string. All edits belong together and should only be applied as a whole.
+    /// The confidence of the suggestion is expressed with the [`Applicability`].
+    /// A suggestion without any edits is ignored.
+    ///
+    /// This can be used, to suggest adding an import and changing a call at
+    /// the same time. From rustc a suggestion would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:4:5
+    ///   |
+    /// 4 |     buffer.push_str(&format!("{value}"));
+    ///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    /// help: <msg>                                     <-- The suggestion added by this function
+    ///   |
+    /// 1 + use std::fmt::Write;
+    /// 2 |
+    /// ...
+    /// 4 ~     write!(buffer, "{value}");
+    ///   |
+    /// ```
+    pub fn multipart_suggestion<S, T>(
+        &mut self,
+        msg: impl Into<String>,
+        suggestions: impl IntoIterator<Item = (S, T)>,
+        app: Applicability,
+    ) -> &mut Self
+    where
+        S: HasSpan<'ast>,
+        T: Into<String>,
+    {
+        if let Some(inner) = self.inner.as_mut() {
+            let parts: Vec<_> = suggestions
+                .into_iter()
+                .map(|(span, sugg)| SuggestionPart {
+                    span: span.span().clone(),
+                    sugg: sugg.into(),
+                })
+                .collect();
+            if parts.is_empty() {
+                return self;
+            }
+            inner.parts.push(DiagnosticPart::MultipartSuggestion {
+                msg: msg.into(),
+                parts,
+                app,
+            });
+        }
+
+        self
+    }
+
     /// The `decorate` parameter accepts a closure, that is only executed, when the
     /// lint will actually be emitted in the end. Having them in a conditional closure
     /// will speedup the linting process if the lint is suppressed.
@@ -275,7 +329,15 @@ impl<'ast> DiagnosticBuilder<'ast> {
 
     pub(crate) fn emit<'builder>(&'builder self, cx: &MarkerContext<'ast>) {
         if let Some(inner) = &self.inner {
-            let parts: Vec<_> = inner.parts.iter().map(DiagnosticPart::to_ffi_part).collect();
+            // The FFI parts only borrow the edits of multipart suggestions.
+            // These therefore have to be converted first.
+            let sugg_parts: Vec<Vec<_>> = inner.parts.iter().map(DiagnosticPart::to_ffi_sugg_parts).collect();
+            let parts: Vec<_> = inner
+                .parts
+                .iter()
+                .zip(&sugg_parts)
+                .map(|(part, sugg_parts)| part.to_ffi_part(sugg_parts))
+                .collect();
             let diag = Diagnostic {
                 lint: inner.lint,
                 msg: inner.msg.as_str().into(),
@@ -305,7 +367,7 @@ impl<'ast, N: Debug + HasSpan<'ast> + HasNodeId> EmissionNode<'ast> for N {}
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-pub(crate) enum DiagnosticPart<St, Sp> {
+pub(crate) enum DiagnosticPart<St, Sp, Parts> {
     Help {
         msg: St,
     },
@@ -326,10 +388,45 @@ pub(crate) enum DiagnosticPart<St, Sp> {
         sugg: St,
         app: Applicability,
     },
+    MultipartSuggestion {
+        msg: St,
+        parts: Parts,
+        app: Applicability,
+    },
 }
 
-impl<'ast> DiagnosticPart<String, Span<'ast>> {
-    fn to_ffi_part<'part>(&'part self) -> DiagnosticPart<FfiStr<'part>, &'part Span<'ast>> {
+/// A single edit of a multipart suggestion.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct SuggestionPart<St, Sp> {
+    pub span: Sp,
+    pub sugg: St,
+}
+
+type OwnedDiagnosticPart<'ast> = DiagnosticPart<String, Span<'ast>, Vec<SuggestionPart<String, Span<'ast>>>>;
+type FfiDiagnosticPart<'part, 'ast> =
+    DiagnosticPart<FfiStr<'part>, &'part Span<'ast>, FfiSlice<'part, FfiSuggestionPart<'part, 'ast>>>;
+type FfiSuggestionPart<'part, 'ast> = SuggestionPart<FfiStr<'part>, &'part Span<'ast>>;
+
+impl<'ast> OwnedDiagnosticPart<'ast> {
+    fn to_ffi_sugg_parts<'part>(&'part self) -> Vec<FfiSuggestionPart<'part, 'ast>> {
+        match self {
+            DiagnosticPart::MultipartSuggestion { parts, .. } => parts
+                .iter()
+                .map(|part| SuggestionPart {
+                    span: &part.span,
+                    sugg: part.sugg.as_str().into(),
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn to_ffi_part<'part>(
+        &'part self,
+        sugg_parts: &'part [FfiSuggestionPart<'part, 'ast>],
+    ) -> FfiDiagnosticPart<'part, 'ast> {
         match self {
             DiagnosticPart::Help { msg } => DiagnosticPart::Help { msg: msg.into() },
             DiagnosticPart::HelpSpan { msg, span } => DiagnosticPart::HelpSpan { msg: msg.into(), span },
@@ -341,6 +438,11 @@ impl<'ast> DiagnosticPart<String, Span<'ast>> {
                 sugg: sugg.into(),
                 app: *app,
             },
+            DiagnosticPart::MultipartSuggestion { msg, parts: _, app } => DiagnosticPart::MultipartSuggestion {
+                msg: msg.into(),
+                parts: sugg_parts.into(),
+                app: *app,
+            },
         }
     }
 }
@@ -386,7 +488,7 @@ pub(crate) struct Diagnostic<'builder, 'ast> {
     pub msg: FfiStr<'builder>,
     pub node: NodeId,
     pub span: &'builder Span<'ast>,
-    pub parts: FfiSlice<'builder, DiagnosticPart<FfiStr<'builder>, &'builder Span<'ast>>>,
+    pub parts: FfiSlice<'builder, FfiDiagnosticPart<'builder, 'ast>>,
 }
 
 impl<'builder, 'ast> Diagnostic<'builder, 'ast> {
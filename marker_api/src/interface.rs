@@ -1,7 +1,12 @@
 //! A module responsible for generating and exposing an interface from lint crates.
 //! [`export_lint_pass`](crate::export_lint_pass) is the main macro, from this module.
 
-use crate::{context::MarkerContext, ffi::FfiSlice, lint::Lint};
+use crate::{
+    config::LintCrateConfig,
+    context::MarkerContext,
+    ffi::{FfiOption, FfiSlice, FfiStr},
+    lint::Lint,
+};
 
 /// **!Unstable!**
 /// This struct is used to connect lint crates to drivers.
@@ -9,16 +14,26 @@ use crate::{context::MarkerContext, ffi::FfiSlice, lint::Lint};
 #[doc(hidden)]
 pub struct LintCrateBindings {
     pub set_ast_context: for<'ast> extern "C" fn(cx: &'ast MarkerContext<'ast>),
+    pub set_lint_crate_config: extern "C" fn(config: FfiOption<&'static LintCrateConfig<'static>>),
 
     // lint pass functions
     pub info: for<'ast> extern "C" fn() -> LintPassInfo,
     pub check_crate: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Crate<'ast>),
+    pub check_crate_post: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Crate<'ast>),
     pub check_item: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ItemKind<'ast>),
+    pub check_item_post: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ItemKind<'ast>),
     pub check_field: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::ItemField<'ast>),
     pub check_variant: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::EnumVariant<'ast>),
     pub check_body: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Body<'ast>),
+    pub check_body_post: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Body<'ast>),
     pub check_stmt: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::StmtKind<'ast>),
     pub check_expr: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ExprKind<'ast>),
+    pub check_expr_post: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ExprKind<'ast>),
+    pub check_pat: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::PatKind<'ast>),
+    pub check_ty: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::TyKind<'ast>),
+    pub check_generic_param: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::GenericParamKind<'ast>),
+    pub check_match_arm: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::MatchArm<'ast>),
+    pub check_path: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::AstQPath<'ast>),
 }
 
 /// This macro marks the given struct as the main [`LintPass`](`crate::LintPass`)
@@ -76,6 +91,11 @@ macro_rules! export_lint_pass {
                 extern "C" fn set_ast_context<'ast>(cx: &'ast $crate::MarkerContext<'ast>) {
                     $crate::context::set_ast_cx(cx);
                 }
+                extern "C" fn set_lint_crate_config(
+                    config: $crate::ffi::FfiOption<&'static $crate::LintCrateConfig<'static>>,
+                ) {
+                    $crate::set_lint_crate_config(config.copy());
+                }
                 extern "C" fn info() -> $crate::LintPassInfo {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().info())
                 }
@@ -85,12 +105,24 @@ macro_rules! export_lint_pass {
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate(cx, krate));
                 }
+                extern "C" fn check_crate_post<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    krate: &'ast $crate::ast::Crate<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate_post(cx, krate));
+                }
                 extern "C" fn check_item<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     item: $crate::ast::ItemKind<'ast>,
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_item(cx, item));
                 }
+                extern "C" fn check_item_post<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    item: $crate::ast::ItemKind<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_item_post(cx, item));
+                }
                 extern "C" fn check_field<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     field: &'ast $crate::ast::ItemField<'ast>,
@@ -109,6 +141,12 @@ macro_rules! export_lint_pass {
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_body(cx, body));
                 }
+                extern "C" fn check_body_post<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    body: &'ast $crate::ast::Body<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_body_post(cx, body));
+                }
                 extern "C" fn check_stmt<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     stmt: $crate::ast::StmtKind<'ast>,
@@ -121,17 +159,57 @@ macro_rules! export_lint_pass {
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr));
                 }
+                extern "C" fn check_expr_post<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    expr: $crate::ast::ExprKind<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr_post(cx, expr));
+                }
+                extern "C" fn check_pat<'ast>(cx: &'ast $crate::MarkerContext<'ast>, pat: $crate::ast::PatKind<'ast>) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_pat(cx, pat));
+                }
+                extern "C" fn check_ty<'ast>(cx: &'ast $crate::MarkerContext<'ast>, ty: $crate::ast::TyKind<'ast>) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_ty(cx, ty));
+                }
+                extern "C" fn check_generic_param<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    param: $crate::ast::GenericParamKind<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_generic_param(cx, param));
+                }
+                extern "C" fn check_match_arm<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    arm: &'ast $crate::ast::MatchArm<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_match_arm(cx, arm));
+                }
+                extern "C" fn check_path<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    path: &'ast $crate::ast::AstQPath<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_path(cx, path));
+                }
 
                 $crate::LintCrateBindings {
                     set_ast_context,
+                    set_lint_crate_config,
                     info,
                     check_crate,
+                    check_crate_post,
                     check_item,
+                    check_item_post,
                     check_field,
                     check_variant,
                     check_body,
+                    check_body_post,
                     check_stmt,
                     check_expr,
+                    check_expr_post,
+                    check_pat,
+                    check_ty,
+                    check_generic_param,
+                    check_match_arm,
+                    check_path,
                 }
             }
         }
@@ -158,9 +236,32 @@ impl LintPassInfoBuilder {
     }
 
     /// This method builds the [`LintPassInfo`], ready for consumption.
+    ///
+    /// The lint groups are collected from the [`Lint::group`] field of the
+    /// given lints. Their data is leaked, like the lints, since it has to be
+    /// `'static`. The driver therefore only requests the information once.
     pub fn build(self) -> LintPassInfo {
+        let mut groups: Vec<(&'static str, Vec<&'static Lint>)> = vec![];
+        for lint in self.lints.iter().copied() {
+            let Some(group) = lint.group else {
+                continue;
+            };
+            match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, lints)) => lints.push(lint),
+                None => groups.push((group, vec![lint])),
+            }
+        }
+        let groups: Vec<_> = groups
+            .into_iter()
+            .map(|(name, lints)| LintGroup {
+                name: name.into(),
+                lints: (&*Box::leak(lints.into_boxed_slice())).into(),
+            })
+            .collect();
+
         LintPassInfo {
             lints: self.lints.into(),
+            groups: (&*Box::leak(groups.into_boxed_slice())).into(),
         }
     }
 }
@@ -176,6 +277,7 @@ impl LintPassInfoBuilder {
 #[non_exhaustive]
 pub struct LintPassInfo {
     lints: FfiSlice<'static, &'static Lint>,
+    groups: FfiSlice<'static, LintGroup>,
 }
 
 #[cfg(feature = "driver-api")]
@@ -183,4 +285,30 @@ impl LintPassInfo {
     pub fn lints(&self) -> &[&'static Lint] {
         self.lints.get()
     }
+
+    pub fn groups(&self) -> &[LintGroup] {
+        self.groups.get()
+    }
+}
+
+/// A group of lints, declared by a lint crate. See [`Lint::group`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct LintGroup {
+    name: FfiStr<'static>,
+    lints: FfiSlice<'static, &'static Lint>,
+}
+
+#[cfg(feature = "driver-api")]
+impl LintGroup {
+    /// The name of the group, like `pedantic`. This doesn't include the
+    /// `marker::lint_crate::` prefix.
+    pub fn name(&self) -> &'static str {
+        self.name.get()
+    }
+
+    /// The lints, which belong to this group.
+    pub fn lints(&self) -> &'static [&'static Lint] {
+        self.lints.get()
+    }
 }
@@ -11,8 +11,10 @@
 
 pub static MARKER_API_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+mod config;
 mod interface;
 mod private;
+pub use config::*;
 pub use interface::*;
 mod lint;
 pub use lint::*;
@@ -32,7 +34,7 @@ pub mod span;
 pub mod ffi;
 
 pub use context::MarkerContext;
-pub use interface::{LintPassInfo, LintPassInfoBuilder};
+pub use interface::{LintGroup, LintPassInfo, LintPassInfoBuilder};
 
 /// A [`LintPass`] visits every node like a `Visitor`. The difference is that a
 /// [`LintPass`] provides some additional information about the implemented lints.
@@ -42,12 +44,27 @@ pub trait LintPass {
     fn info(&self) -> LintPassInfo;
 
     fn check_crate<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
+    /// This is called once the entire crate has been checked. It's the last
+    /// callback of the [`LintPass`] and can be used to emit lints for
+    /// information collected while checking the crate.
+    fn check_crate_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
     fn check_item<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _item: ast::ItemKind<'ast>) {}
+    /// This is called after all nodes inside the item have been checked.
+    fn check_item_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _item: ast::ItemKind<'ast>) {}
     fn check_field<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _field: &'ast ast::ItemField<'ast>) {}
     fn check_variant<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _variant: &'ast ast::EnumVariant<'ast>) {}
     fn check_body<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _body: &'ast ast::Body<'ast>) {}
+    /// This is called after all nodes inside the body have been checked.
+    fn check_body_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _body: &'ast ast::Body<'ast>) {}
     fn check_stmt<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _stmt: ast::StmtKind<'ast>) {}
     fn check_expr<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ast::ExprKind<'ast>) {}
+    /// This is called after all nodes inside the expression have been checked.
+    fn check_expr_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ast::ExprKind<'ast>) {}
+    fn check_pat<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _pat: ast::PatKind<'ast>) {}
+    fn check_ty<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _ty: ast::TyKind<'ast>) {}
+    fn check_generic_param<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _param: ast::GenericParamKind<'ast>) {}
+    fn check_match_arm<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _arm: &'ast ast::MatchArm<'ast>) {}
+    fn check_path<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _path: &'ast ast::AstQPath<'ast>) {}
 }
 
 /// This struct blocks the construction of enum variants, similar to the `#[non_exhaustive]`
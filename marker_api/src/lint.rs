@@ -65,6 +65,11 @@ pub struct Lint {
     /// It includes the module and the name of the variable.
     pub fqn: &'static str,
 
+    /// The name of the group, this lint belongs to, like `pedantic`. Users can
+    /// set the level of all lints in a group, via the group name, like
+    /// `marker::lint_crate::pedantic`.
+    pub group: Option<&'static str>,
+
     /// This struct should always be instantiated using the [`declare_lint`](crate::declare_lint)
     /// macro. This value is simply here, to force any construction to acknowledge the
     /// instability of manual construction.
@@ -97,6 +102,20 @@ pub struct Lint {
 ///     Warn,
 /// }
 /// ```
+///
+/// Lints can optionally be added to a group, by adding `group = "<name>"` as
+/// the last argument. Users can then set the level of all lints in the group,
+/// like `#[warn(marker::lint_crate::pedantic)]`:
+///
+/// ```
+/// marker_api::declare_lint!{
+///     /// # What it does
+///     /// Here you can describe what your lint does.
+///     ITEM_WITH_PEDANTIC_NAME,
+///     Allow,
+///     group = "pedantic",
+/// }
+/// ```
 #[macro_export]
 macro_rules! declare_lint {
     (
@@ -111,11 +130,58 @@ macro_rules! declare_lint {
             $crate::common::MacroReport::No,
         }
     };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        group = $GROUP: literal $(,)?
+    ) => {
+        $crate::declare_lint!{
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            $crate::common::MacroReport::No,
+            group = $GROUP,
+        }
+    };
     (
         $(#[doc = $doc:literal])+
         $NAME: ident,
         $LEVEL: ident,
         $REPORT_IN_MACRO: expr $(,)?
+    ) => {
+        $crate::declare_lint!{
+            @internal
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            $REPORT_IN_MACRO,
+            None,
+        }
+    };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        $REPORT_IN_MACRO: expr,
+        group = $GROUP: literal $(,)?
+    ) => {
+        $crate::declare_lint!{
+            @internal
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            $REPORT_IN_MACRO,
+            Some($GROUP),
+        }
+    };
+    (
+        @internal
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        $REPORT_IN_MACRO: expr,
+        $GROUP: expr,
     ) => {
         $(#[doc = $doc])+
         pub static $NAME: &$crate::Lint = &$crate::Lint {
@@ -140,6 +206,7 @@ macro_rules! declare_lint {
             explanation: concat!($($doc, '\n',)*),
             report_in_macro: $REPORT_IN_MACRO,
             fqn: concat!(module_path!(), "::", stringify!($NAME)),
+            group: $GROUP,
             _unstable_i_accept_the_risk_of_instability: (),
         };
     };
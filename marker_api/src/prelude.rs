@@ -17,7 +17,7 @@ pub use crate::ast;
 pub use crate::sem;
 
 // IDs
-pub use crate::common::{BodyId, ExprId, FieldId, GenericId, ItemId, NodeId, StmtId, TyDefId, VarId, VariantId};
+pub use crate::common::{ArmId, BodyId, ExprId, FieldId, GenericId, ItemId, NodeId, StmtId, TyDefId, VarId, VariantId};
 
 // Common types
 pub use crate::ast::ExprKind;
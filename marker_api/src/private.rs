@@ -9,6 +9,7 @@ pub trait Sealed {}
 impl<N: Sealed> Sealed for &N {}
 
 impl Sealed for ast::AssocItemKind<'_> {}
+impl Sealed for ast::Attribute<'_> {}
 impl Sealed for ast::ClosureParam<'_> {}
 impl Sealed for ast::ConstParam<'_> {}
 impl Sealed for ast::EnumVariant<'_> {}
@@ -20,9 +21,11 @@ impl Sealed for ast::ItemField<'_> {}
 impl Sealed for ast::ItemKind<'_> {}
 impl Sealed for ast::LifetimeParam<'_> {}
 impl Sealed for ast::LitExprKind<'_> {}
+impl Sealed for ast::MatchArm<'_> {}
 impl Sealed for ast::StmtKind<'_> {}
 impl Sealed for ast::StructFieldPat<'_> {}
 impl Sealed for ast::TyKind<'_> {}
 impl Sealed for ast::TyParam<'_> {}
 impl Sealed for Span<'_> {}
 impl Sealed for Ident<'_> {}
+impl Sealed for crate::span::Comment<'_> {}
@@ -1,12 +1,18 @@
 //! A module containing semantic representations of types, generics and other
 //! parts of the language.
 
+mod adjustment;
 mod common;
 mod generic;
 mod item;
+mod layout;
+mod local_usage;
 mod ty;
 
+pub use adjustment::*;
 pub use common::*;
 pub use generic::*;
 pub use item::*;
+pub use layout::*;
+pub use local_usage::*;
 pub use ty::*;
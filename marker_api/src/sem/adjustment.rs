@@ -0,0 +1,71 @@
+use crate::common::Mutability;
+
+use super::TyKind;
+
+/// An implicit conversion, which the compiler applies to an expression. This
+/// can for example be an auto-deref or auto-borrow of a method receiver:
+///
+/// ```
+/// let vec = vec![1, 2, 3];
+/// let boxed = Box::new(vec);
+///
+/// // The receiver `boxed` has the type `Box<Vec<i32>>`. `Vec::len` takes
+/// // `&self`, therefore the compiler first dereferences the box, to get to
+/// // `Vec<i32>` and then borrows it, to get `&Vec<i32>`.
+/// let _ = boxed.len();
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct Adjustment<'ast> {
+    kind: AdjustmentKind,
+    target: TyKind<'ast>,
+}
+
+impl<'ast> Adjustment<'ast> {
+    pub fn kind(&self) -> AdjustmentKind {
+        self.kind
+    }
+
+    /// The type of the expression after this adjustment has been applied.
+    pub fn target(&self) -> TyKind<'ast> {
+        self.target
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Adjustment<'ast> {
+    pub fn new(kind: AdjustmentKind, target: TyKind<'ast>) -> Self {
+        Self { kind, target }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AdjustmentKind {
+    /// Converts the never type `!` into any other type.
+    NeverToAny,
+    /// A builtin dereference of a reference or raw pointer, or a `Box`.
+    Deref,
+    /// A dereference using the [`Deref`](std::ops::Deref) or
+    /// [`DerefMut`](std::ops::DerefMut) trait. The mutability indicates which
+    /// of the two traits is used.
+    OverloadedDeref(Mutability),
+    /// Takes a reference of the value, like `&value` or `&mut value`.
+    Borrow(Mutability),
+    /// Takes a raw pointer of the value, like `&raw const value`.
+    RawBorrow(Mutability),
+    /// Coerces a sized type into an unsized type, like `&[T; N]` into `&[T]`
+    /// or `Box<T>` into `Box<dyn Trait>`.
+    Unsize,
+    /// Converts a function item into a function pointer.
+    ReifyFnPointer,
+    /// Converts a safe function pointer into an unsafe one.
+    UnsafeFnPointer,
+    /// Converts a closure, which doesn't capture anything, into a function pointer.
+    ClosureFnPointer,
+    /// Converts a `*mut T` into a `*const T`.
+    MutToConstPointer,
+    /// Converts a `*const [T; N]` into a `*const T`.
+    ArrayToPointer,
+}
@@ -1,26 +1,232 @@
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use crate::{
+    common::{GenericId, NumKind, VariantId},
+    ffi::{FfiOption, FfiSlice, FfiStr},
+};
 
-/// The semantic equivalent of a [`ConstExpr`][crate::ast::ConstExpr], at
-/// least theoretically. This part of the API is sadly not done yet, so this is
-/// just a placeholder.
+/// The semantic equivalent of a [`ConstExpr`][crate::ast::ConstExpr]. This is
+/// a value, which was evaluated by the compiler. Constant values can be found
+/// in the length of [`ArrayTy`](crate::sem::ArrayTy)s, in constant generic
+/// arguments or can be requested for expressions with
+/// [`MarkerContext::eval_const`](crate::MarkerContext::eval_const).
 ///
-/// See: rust-marker/marker#179
+/// The values are always normalized, meaning that `1 + 2` and `3` will both
+/// be represented as the integer `3`.
 #[repr(C)]
-pub struct ConstValue<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum ConstValue<'ast> {
+    /// An integer value, like `7_u8` or `-1_isize`
+    Int(IntValue),
+    /// A floating point value, like `1.5_f32`
+    Float(FloatValue),
+    /// A boolean value, either `true` or `false`
+    Bool(bool),
+    /// A character value, like `'x'`
+    Char(char),
+    /// A string slice, like `"Hello, World"`
+    Str(StrValue<'ast>),
+    /// A composite value, like a tuple, array, struct or enum variant.
+    Aggregate(&'ast AggregateValue<'ast>),
+    /// A value, which depends on a constant generic parameter, like `N` in
+    /// `fn foo<const N: usize>() -> [u8; N]`. The [`GenericId`] identifies
+    /// the parameter.
+    Param(GenericId),
+    /// A value, which can't be represented by Marker yet. This includes
+    /// references, pointers, unions and values, that the compiler was unable
+    /// to evaluate.
+    Unknown,
 }
 
-impl<'ast> Debug for ConstValue<'ast> {
+impl<'ast> ConstValue<'ast> {
+    /// Returns the value as an `u128`, if this is an integer, which fits into
+    /// an `u128`. This is a shortcut for [`IntValue::to_u128`].
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            ConstValue::Int(value) => value.to_u128(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `i128`, if this is an integer, which fits into
+    /// an `i128`. This is a shortcut for [`IntValue::to_i128`].
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            ConstValue::Int(value) => value.to_i128(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value, if this is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ConstValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl<'ast> std::fmt::Display for ConstValue<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstValue::Int(value) => value.fmt(f),
+            ConstValue::Float(value) => value.fmt(f),
+            ConstValue::Bool(value) => value.fmt(f),
+            ConstValue::Char(value) => write!(f, "{value:?}"),
+            ConstValue::Str(value) => write!(f, "{:?}", value.value()),
+            ConstValue::Aggregate(_) => write!(f, "{{ .. }}"),
+            ConstValue::Param(_) | ConstValue::Unknown => write!(f, "_"),
+        }
+    }
+}
+
+/// An integer value with its type.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntValue {
+    /// The value of the integer. Signed values are sign extended to 128 bits.
+    bits: u128,
+    kind: NumKind,
+}
+
+impl IntValue {
+    /// The type of this integer.
+    pub fn kind(&self) -> NumKind {
+        self.kind
+    }
+
+    /// Returns the value as an `u128`, or `None`, if the value is negative.
+    pub fn to_u128(self) -> Option<u128> {
+        if self.kind.is_signed() && self.signed_value() < 0 {
+            None
+        } else {
+            Some(self.bits)
+        }
+    }
+
+    /// Returns the value as an `i128`, or `None`, if the value is an unsigned
+    /// integer, that exceeds [`i128::MAX`].
+    pub fn to_i128(self) -> Option<i128> {
+        if self.kind.is_unsigned() && self.signed_value() < 0 {
+            None
+        } else {
+            Some(self.signed_value())
+        }
+    }
+
+    /// Interprets the bits as a signed integer.
+    fn signed_value(self) -> i128 {
+        i128::from_ne_bytes(self.bits.to_ne_bytes())
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl IntValue {
+    /// Signed values have to be sign extended to 128 bits.
+    pub fn new(bits: u128, kind: NumKind) -> Self {
+        Self { bits, kind }
+    }
+}
+
+impl std::fmt::Display for IntValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.kind.is_signed() {
+            write!(f, "{}", self.signed_value())
+        } else {
+            write!(f, "{}", self.bits)
+        }
+    }
+}
+
+#[allow(clippy::missing_fields_in_debug)]
+impl std::fmt::Debug for IntValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstValue {{ /* WIP: See rust-marker/marker#179 */}}")
+        f.debug_struct("IntValue")
+            .field("value", &format_args!("{self}"))
+            .field("kind", &self.kind)
             .finish()
     }
 }
 
+/// A floating point value with its type.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatValue {
+    value: f64,
+    kind: NumKind,
+}
+
+impl FloatValue {
+    /// The type of this float.
+    pub fn kind(&self) -> NumKind {
+        self.kind
+    }
+
+    /// The value of this float. Values of [`f32`] are converted losslessly.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
 #[cfg(feature = "driver-api")]
-impl<'ast> ConstValue<'ast> {
-    pub fn new() -> Self {
-        Self { _lifetime: PhantomData }
+impl FloatValue {
+    pub fn new(value: f64, kind: NumKind) -> Self {
+        Self { value, kind }
+    }
+}
+
+impl std::fmt::Display for FloatValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.value)
+    }
+}
+
+/// A string slice value.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct StrValue<'ast> {
+    value: FfiStr<'ast>,
+}
+
+impl<'ast> StrValue<'ast> {
+    pub fn value(&self) -> &'ast str {
+        self.value.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> StrValue<'ast> {
+    pub fn new(value: &'ast str) -> Self {
+        Self { value: value.into() }
+    }
+}
+
+/// A composite value, like a tuple, array, struct or enum variant.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AggregateValue<'ast> {
+    variant: FfiOption<VariantId>,
+    fields: FfiSlice<'ast, ConstValue<'ast>>,
+}
+
+impl<'ast> AggregateValue<'ast> {
+    /// The variant of the value, if this is the value of an enum.
+    pub fn variant(&self) -> Option<VariantId> {
+        self.variant.copy()
+    }
+
+    /// The values of the fields, in the order they are declared. For tuples
+    /// and arrays, this will be the values of the elements.
+    pub fn fields(&self) -> &'ast [ConstValue<'ast>] {
+        self.fields.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> AggregateValue<'ast> {
+    pub fn new(variant: Option<VariantId>, fields: &'ast [ConstValue<'ast>]) -> Self {
+        Self {
+            variant: variant.into(),
+            fields: fields.into(),
+        }
     }
 }
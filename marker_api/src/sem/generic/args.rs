@@ -51,8 +51,8 @@ pub struct ConstArg<'ast> {
 
 impl<'ast> ConstArg<'ast> {
     /// The value that is used as an argument.
-    pub fn value(&self) -> &ConstValue<'ast> {
-        &self.value
+    pub fn value(&self) -> ConstValue<'ast> {
+        self.value
     }
 }
 
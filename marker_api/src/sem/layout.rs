@@ -0,0 +1,130 @@
+use crate::{
+    common::VariantId,
+    ffi::{FfiOption, FfiSlice},
+};
+
+/// The memory layout of a type, as computed by the compiler. The layout can
+/// be requested with [`MarkerContext::ty_layout`](crate::MarkerContext::ty_layout).
+///
+/// The layout of types is mostly unspecified in Rust. The values provided
+/// here are only valid for the current compilation and target. They might
+/// change between compiler versions, targets or compiler flags.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TyLayout<'ast> {
+    size: u64,
+    align: u64,
+    variants: FfiSlice<'ast, VariantLayout>,
+    niche: FfiOption<Niche>,
+}
+
+impl<'ast> TyLayout<'ast> {
+    /// The size of the type in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The ABI-required minimum alignment of the type in bytes.
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// The layouts of all variants, if this is the layout of an enum. The
+    /// variants are ordered as they are declared. This will be empty for all
+    /// other types.
+    ///
+    /// Note that the size of the enum can be larger than the size of its
+    /// largest variant, as the enum might require additional space for the
+    /// discriminant.
+    pub fn variants(&self) -> &'ast [VariantLayout] {
+        self.variants.get()
+    }
+
+    /// The largest niche of this type, if available. A niche is a range of
+    /// invalid bit patterns, which can be used by the compiler to store
+    /// the discriminant of an enum wrapping this type. For example,
+    /// `Option<&T>` has the same size as `&T`, as the `None` variant is
+    /// represented by the null pointer.
+    pub fn niche(&self) -> Option<&Niche> {
+        self.niche.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> TyLayout<'ast> {
+    pub fn new(size: u64, align: u64, variants: &'ast [VariantLayout], niche: Option<Niche>) -> Self {
+        Self {
+            size,
+            align,
+            variants: variants.into(),
+            niche: niche.into(),
+        }
+    }
+}
+
+/// The layout of a single enum variant.
+#[repr(C)]
+#[derive(Debug)]
+pub struct VariantLayout {
+    variant: VariantId,
+    size: u64,
+}
+
+impl VariantLayout {
+    /// The id of the variant, which this layout belongs to.
+    pub fn variant(&self) -> VariantId {
+        self.variant
+    }
+
+    /// The size of this variant in bytes. This includes the space required by
+    /// the discriminant, if it's stored in front of the fields of the variant.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl VariantLayout {
+    pub fn new(variant: VariantId, size: u64) -> Self {
+        Self { variant, size }
+    }
+}
+
+/// A range of invalid bit patterns of a scalar value inside a type. See
+/// [`TyLayout::niche`] for more information.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Niche {
+    offset: u64,
+    size: u64,
+    available: u128,
+}
+
+impl Niche {
+    /// The offset of the scalar value, containing the niche, in bytes.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The size of the scalar value, containing the niche, in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The number of invalid values, which are available to store the
+    /// discriminants of an enum.
+    pub fn available(&self) -> u128 {
+        self.available
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl Niche {
+    pub fn new(offset: u64, size: u64, available: u128) -> Self {
+        Self {
+            offset,
+            size,
+            available,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::common::{ExprId, Mutability};
+
+/// A single usage of a local variable, as returned by
+/// [`MarkerContext::local_usages`](crate::MarkerContext::local_usages).
+///
+/// ```
+/// let mut vec = vec![1, 2];   // The binding, this isn't a usage
+/// vec.push(3);                // `Borrow(Mut)`, the receiver is auto-borrowed
+/// let first = vec[0];         // `Borrow(Unmut)`, for the call of `Index::index`
+/// vec = Vec::new();           // `Write`
+/// let len = (&vec).len();     // `Borrow(Unmut)`
+/// drop(vec);                  // `Read`, this moves the value
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LocalUsage {
+    expr: ExprId,
+    kind: LocalUsageKind,
+}
+
+impl LocalUsage {
+    /// The [`ExprId`] of the [`PathExpr`](crate::ast::PathExpr), which
+    /// references the variable.
+    pub fn expr(&self) -> ExprId {
+        self.expr
+    }
+
+    pub fn kind(&self) -> LocalUsageKind {
+        self.kind
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl LocalUsage {
+    pub fn new(expr: ExprId, kind: LocalUsageKind) -> Self {
+        Self { expr, kind }
+    }
+}
+
+/// Describes how a local variable is used. Usages of a field or an element of
+/// the variable, like `var.field` or `var[0]`, are classified by how the field
+/// or element is used.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalUsageKind {
+    /// The value is read, by copying or moving it.
+    Read,
+    /// A new value is assigned to the variable. Compound assignments, like
+    /// `var += 1`, are also classified as writes.
+    Write,
+    /// The variable is borrowed, either explicitly, like `&mut var`, or
+    /// implicitly, for example by auto-borrowing a method receiver.
+    Borrow(Mutability),
+}
@@ -1,3 +1,7 @@
+use std::marker::PhantomData;
+
+use crate::{common::DriverTyId, context::with_cx};
+
 mod fn_ty;
 mod other_ty;
 mod prim_ty;
@@ -109,4 +113,116 @@ impl<'ast> TyKind<'ast> {
         }
         ty
     }
+
+    /// Returns `true`, if this type implements the [`Copy`] trait.
+    ///
+    /// Checkout [`MarkerContext::ty_implements_trait`](crate::context::MarkerContext::ty_implements_trait)
+    /// to check for other traits.
+    pub fn is_copy(&self) -> bool {
+        self.has_property(TyProperty::Copy)
+    }
+
+    /// Returns `true`, if this type implements the [`Send`] trait.
+    pub fn is_send(&self) -> bool {
+        self.has_property(TyProperty::Send)
+    }
+
+    /// Returns `true`, if this type implements the [`Sync`] trait.
+    pub fn is_sync(&self) -> bool {
+        self.has_property(TyProperty::Sync)
+    }
+
+    /// Returns `true`, if this type has a statically known size, meaning that
+    /// it implements the [`Sized`] trait.
+    pub fn is_sized(&self) -> bool {
+        self.has_property(TyProperty::Sized)
+    }
+
+    /// Returns `true`, if dropping a value of this type might run code. This
+    /// is the case for types which implement [`Drop`] or contain such types.
+    ///
+    /// See [`std::mem::needs_drop`] for more information.
+    pub fn needs_drop(&self) -> bool {
+        self.has_property(TyProperty::NeedsDrop)
+    }
+
+    fn has_property(&self, property: TyProperty) -> bool {
+        with_cx(self, |cx| cx.ty_has_property(*self, property))
+    }
+
+    fn data(&self) -> &CommonTyData<'ast> {
+        match self {
+            TyKind::Bool(ty) => ty.data(),
+            TyKind::Num(ty) => ty.data(),
+            TyKind::Text(ty) => ty.data(),
+            TyKind::Never(ty) => ty.data(),
+            TyKind::Tuple(ty) => ty.data(),
+            TyKind::Array(ty) => ty.data(),
+            TyKind::Slice(ty) => ty.data(),
+            TyKind::FnTy(ty) => ty.data(),
+            TyKind::ClosureTy(ty) => ty.data(),
+            TyKind::Ref(ty) => ty.data(),
+            TyKind::RawPtr(ty) => ty.data(),
+            TyKind::FnPtr(ty) => ty.data(),
+            TyKind::TraitObj(ty) => ty.data(),
+            TyKind::Adt(ty) => ty.data(),
+            TyKind::Generic(ty) => ty.data(),
+            TyKind::Alias(ty) => ty.data(),
+            TyKind::Unstable(ty) => ty.data(),
+        }
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> TyKind<'ast> {
+    pub fn driver_id(&self) -> DriverTyId {
+        self.data().driver_id
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct CommonTyData<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    driver_id: DriverTyId,
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> CommonTyData<'ast> {
+    pub fn new(driver_id: DriverTyId) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            driver_id,
+        }
+    }
+}
+
+/// This trait provides access to the [`CommonTyData`] of all semantic types.
+/// It's implemented by the [`impl_ty_data`] macro.
+trait TyData<'ast> {
+    fn data(&self) -> &CommonTyData<'ast>;
+}
+
+macro_rules! impl_ty_data {
+    ($self_ty:ty) => {
+        impl<'ast> $crate::sem::ty::TyData<'ast> for $self_ty {
+            fn data(&self) -> &$crate::sem::ty::CommonTyData<'ast> {
+                &self.data
+            }
+        }
+    };
+}
+use impl_ty_data;
+
+/// Properties of semantic types, which are answered by the driver.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) enum TyProperty {
+    Copy,
+    Send,
+    Sync,
+    Sized,
+    NeedsDrop,
 }
@@ -3,11 +3,14 @@ use crate::{
     sem::generic::GenericArgs,
 };
 
+use super::CommonTyData;
+
 /// A [function item type](https://doc.rust-lang.org/reference/types/function-item.html)
 /// identifying a specific function and potentualy additional generics.
 #[repr(C)]
 #[derive(Debug)]
 pub struct FnTy<'ast> {
+    data: CommonTyData<'ast>,
     fn_id: ItemId,
     generics: GenericArgs<'ast>,
 }
@@ -26,11 +29,13 @@ impl<'ast> FnTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FnTy<'ast> {
-    pub fn new(fn_id: ItemId, generics: GenericArgs<'ast>) -> Self {
-        Self { fn_id, generics }
+    pub fn new(data: CommonTyData<'ast>, fn_id: ItemId, generics: GenericArgs<'ast>) -> Self {
+        Self { data, fn_id, generics }
     }
 }
 
+super::impl_ty_data!(FnTy<'ast>);
+
 /// The semantic representation of a
 /// [closure type](https://doc.rust-lang.org/reference/types/closure.html).
 ///
@@ -39,6 +44,8 @@ impl<'ast> FnTy<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct ClosureTy<'ast> {
+    data: CommonTyData<'ast>,
+    #[allow(clippy::struct_field_names)]
     closure_ty_id: TyDefId,
     generics: GenericArgs<'ast>,
 }
@@ -60,10 +67,13 @@ impl<'ast> ClosureTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ClosureTy<'ast> {
-    pub fn new(closure_ty_id: TyDefId, generics: GenericArgs<'ast>) -> Self {
+    pub fn new(data: CommonTyData<'ast>, closure_ty_id: TyDefId, generics: GenericArgs<'ast>) -> Self {
         Self {
+            data,
             closure_ty_id,
             generics,
         }
     }
 }
+
+super::impl_ty_data!(ClosureTy<'ast>);
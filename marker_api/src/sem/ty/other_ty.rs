@@ -1,16 +1,18 @@
-use std::marker::PhantomData;
+use super::CommonTyData;
 
 /// The placeholder type, signalling that the semantic type is still unstable
 /// and therefor not represented as part of the API.
 #[repr(C)]
 #[derive(Debug)]
 pub struct UnstableTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> UnstableTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonTyData<'ast>) -> Self {
+        Self { data }
     }
 }
+
+super::impl_ty_data!(UnstableTy<'ast>);
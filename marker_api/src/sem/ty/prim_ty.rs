@@ -1,21 +1,23 @@
-use std::marker::PhantomData;
-
 use crate::common::{NumKind, TextKind};
 
+use super::CommonTyData;
+
 /// The semantic representation of the [`bool`] type.
 #[repr(C)]
 #[derive(Debug)]
 pub struct BoolTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> BoolTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonTyData<'ast>) -> Self {
+        Self { data }
     }
 }
 
+super::impl_ty_data!(BoolTy<'ast>);
+
 impl<'ast> std::fmt::Display for BoolTy<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("bool").finish()
@@ -26,20 +28,19 @@ impl<'ast> std::fmt::Display for BoolTy<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct NumTy<'ast> {
-    _ast: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
     numeric_kind: NumKind,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> NumTy<'ast> {
-    pub fn new(numeric_kind: NumKind) -> Self {
-        Self {
-            _ast: PhantomData,
-            numeric_kind,
-        }
+    pub fn new(data: CommonTyData<'ast>, numeric_kind: NumKind) -> Self {
+        Self { data, numeric_kind }
     }
 }
 
+super::impl_ty_data!(NumTy<'ast>);
+
 impl<'ast> NumTy<'ast> {
     pub fn numeric_kind(&self) -> NumKind {
         self.numeric_kind
@@ -71,20 +72,19 @@ impl<'ast> std::fmt::Display for NumTy<'ast> {
 /// The semantic representation of a textual type like [`char`] or [`str`].
 #[repr(C)]
 pub struct TextTy<'ast> {
-    _ast: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
     textual_kind: TextKind,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> TextTy<'ast> {
-    pub fn new(textual_kind: TextKind) -> Self {
-        Self {
-            _ast: PhantomData,
-            textual_kind,
-        }
+    pub fn new(data: CommonTyData<'ast>, textual_kind: TextKind) -> Self {
+        Self { data, textual_kind }
     }
 }
 
+super::impl_ty_data!(TextTy<'ast>);
+
 impl<'ast> TextTy<'ast> {
     pub fn textual_kind(&self) -> TextKind {
         self.textual_kind
@@ -108,16 +108,18 @@ impl<'ast> std::fmt::Debug for TextTy<'ast> {
 /// The semantic representation of the never type [`!`](prim@never).
 #[repr(C)]
 pub struct NeverTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> NeverTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonTyData<'ast>) -> Self {
+        Self { data }
     }
 }
 
+super::impl_ty_data!(NeverTy<'ast>);
+
 impl<'ast> std::fmt::Debug for NeverTy<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("!").finish()
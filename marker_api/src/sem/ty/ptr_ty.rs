@@ -3,7 +3,7 @@ use crate::{
     ffi::FfiSlice,
 };
 
-use super::TyKind;
+use super::{CommonTyData, TyKind};
 
 /// The semantic representation of a reference like [`&T`](prim@reference)
 /// or [`&mut T`](prim@reference)
@@ -14,6 +14,7 @@ use super::TyKind;
 #[repr(C)]
 #[derive(Debug)]
 pub struct RefTy<'ast> {
+    data: CommonTyData<'ast>,
     mutability: Mutability,
     inner_ty: TyKind<'ast>,
 }
@@ -32,16 +33,23 @@ impl<'ast> RefTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> RefTy<'ast> {
-    pub fn new(mutability: Mutability, inner_ty: TyKind<'ast>) -> Self {
-        Self { mutability, inner_ty }
+    pub fn new(data: CommonTyData<'ast>, mutability: Mutability, inner_ty: TyKind<'ast>) -> Self {
+        Self {
+            data,
+            mutability,
+            inner_ty,
+        }
     }
 }
 
+super::impl_ty_data!(RefTy<'ast>);
+
 /// The semantic representation of a raw pointer like [`*const T`](prim@pointer)
 /// or [`*mut T`](prim@pointer)
 #[repr(C)]
 #[derive(Debug)]
 pub struct RawPtrTy<'ast> {
+    data: CommonTyData<'ast>,
     mutability: Mutability,
     inner_ty: TyKind<'ast>,
 }
@@ -58,15 +66,22 @@ impl<'ast> RawPtrTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> RawPtrTy<'ast> {
-    pub fn new(mutability: Mutability, inner_ty: TyKind<'ast>) -> Self {
-        Self { mutability, inner_ty }
+    pub fn new(data: CommonTyData<'ast>, mutability: Mutability, inner_ty: TyKind<'ast>) -> Self {
+        Self {
+            data,
+            mutability,
+            inner_ty,
+        }
     }
 }
 
+super::impl_ty_data!(RawPtrTy<'ast>);
+
 /// The semantic representation of a function pointer, like [`fn (T) -> U`](prim@fn)
 #[repr(C)]
 #[derive(Debug)]
 pub struct FnPtrTy<'ast> {
+    data: CommonTyData<'ast>,
     safety: Safety,
     abi: Abi,
     params: FfiSlice<'ast, TyKind<'ast>>,
@@ -93,8 +108,15 @@ impl<'ast> FnPtrTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FnPtrTy<'ast> {
-    pub fn new(safety: Safety, abi: Abi, params: &'ast [TyKind<'ast>], return_ty: TyKind<'ast>) -> Self {
+    pub fn new(
+        data: CommonTyData<'ast>,
+        safety: Safety,
+        abi: Abi,
+        params: &'ast [TyKind<'ast>],
+        return_ty: TyKind<'ast>,
+    ) -> Self {
         Self {
+            data,
             safety,
             abi,
             params: params.into(),
@@ -102,3 +124,5 @@ impl<'ast> FnPtrTy<'ast> {
         }
     }
 }
+
+super::impl_ty_data!(FnPtrTy<'ast>);
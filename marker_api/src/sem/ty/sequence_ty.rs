@@ -1,11 +1,12 @@
 use crate::{ffi::FfiSlice, sem::ConstValue};
 
-use super::TyKind;
+use super::{CommonTyData, TyKind};
 
 /// The semantic representation of a tuple type like [`()`](prim@tuple) or [`(T, U)`](prim@tuple)
 #[repr(C)]
 #[derive(Debug)]
 pub struct TupleTy<'ast> {
+    data: CommonTyData<'ast>,
     types: FfiSlice<'ast, TyKind<'ast>>,
 }
 
@@ -17,11 +18,16 @@ impl<'ast> TupleTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> TupleTy<'ast> {
-    pub fn new(types: &'ast [TyKind<'ast>]) -> Self {
-        Self { types: types.into() }
+    pub fn new(data: CommonTyData<'ast>, types: &'ast [TyKind<'ast>]) -> Self {
+        Self {
+            data,
+            types: types.into(),
+        }
     }
 }
 
+super::impl_ty_data!(TupleTy<'ast>);
+
 impl<'ast> std::fmt::Display for TupleTy<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut f = f.debug_tuple("");
@@ -37,6 +43,7 @@ impl<'ast> std::fmt::Display for TupleTy<'ast> {
 /// The semantic representation of a variable length slice like [`[T]`](prim@slice)
 #[repr(C)]
 pub struct SliceTy<'ast> {
+    data: CommonTyData<'ast>,
     inner_ty: TyKind<'ast>,
 }
 
@@ -48,11 +55,13 @@ impl<'ast> SliceTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SliceTy<'ast> {
-    pub fn new(inner_ty: TyKind<'ast>) -> Self {
-        Self { inner_ty }
+    pub fn new(data: CommonTyData<'ast>, inner_ty: TyKind<'ast>) -> Self {
+        Self { data, inner_ty }
     }
 }
 
+super::impl_ty_data!(SliceTy<'ast>);
+
 impl<'ast> std::fmt::Debug for SliceTy<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(std::iter::once(self.inner_ty())).finish()
@@ -63,6 +72,7 @@ impl<'ast> std::fmt::Debug for SliceTy<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct ArrayTy<'ast> {
+    data: CommonTyData<'ast>,
     inner_ty: TyKind<'ast>,
     len: ConstValue<'ast>,
 }
@@ -72,21 +82,25 @@ impl<'ast> ArrayTy<'ast> {
         self.inner_ty
     }
 
-    pub fn len(&self) -> &ConstValue<'ast> {
-        &self.len
+    /// The length of the array. This will be a [`ConstValue::Int`] for most
+    /// arrays, or a [`ConstValue::Param`], if the length is specified by a
+    /// generic parameter.
+    pub fn len(&self) -> ConstValue<'ast> {
+        self.len
     }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ArrayTy<'ast> {
-    pub fn new(inner_ty: TyKind<'ast>, len: ConstValue<'ast>) -> Self {
-        Self { inner_ty, len }
+    pub fn new(data: CommonTyData<'ast>, inner_ty: TyKind<'ast>, len: ConstValue<'ast>) -> Self {
+        Self { data, inner_ty, len }
     }
 }
 
+super::impl_ty_data!(ArrayTy<'ast>);
+
 impl<'ast> std::fmt::Display for ArrayTy<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // FIXME: Add length expression
-        f.debug_list().entries(std::iter::once(self.inner_ty())).finish()
+        write!(f, "[{:?}; {}]", self.inner_ty(), self.len)
     }
 }
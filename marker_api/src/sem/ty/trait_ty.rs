@@ -1,11 +1,14 @@
 use crate::{ffi::FfiSlice, sem::generic::TraitBound};
 
+use super::CommonTyData;
+
 /// The semantic representation of a [trait object].
 ///
 /// [trait object]: https://doc.rust-lang.org/reference/types/trait-object.html
 #[repr(C)]
 #[derive(Debug)]
 pub struct TraitObjTy<'ast> {
+    data: CommonTyData<'ast>,
     bound: FfiSlice<'ast, TraitBound<'ast>>,
 }
 
@@ -17,7 +20,12 @@ impl<'ast> TraitObjTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> TraitObjTy<'ast> {
-    pub fn new(bound: &'ast [TraitBound<'ast>]) -> Self {
-        Self { bound: bound.into() }
+    pub fn new(data: CommonTyData<'ast>, bound: &'ast [TraitBound<'ast>]) -> Self {
+        Self {
+            data,
+            bound: bound.into(),
+        }
     }
 }
+
+super::impl_ty_data!(TraitObjTy<'ast>);
@@ -1,10 +1,10 @@
-use std::marker::PhantomData;
-
 use crate::{
     common::{GenericId, ItemId, TyDefId},
     sem::generic::GenericArgs,
 };
 
+use super::CommonTyData;
+
 /// The semantic representation of an abstract data type. This can be an
 /// [`Enum`], [`Struct`], or [`Union`].
 ///
@@ -14,6 +14,7 @@ use crate::{
 #[repr(C)]
 #[derive(Debug)]
 pub struct AdtTy<'ast> {
+    data: CommonTyData<'ast>,
     def_id: TyDefId,
     generics: GenericArgs<'ast>,
 }
@@ -32,11 +33,13 @@ impl<'ast> AdtTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> AdtTy<'ast> {
-    pub fn new(def_id: TyDefId, generics: GenericArgs<'ast>) -> Self {
-        Self { def_id, generics }
+    pub fn new(data: CommonTyData<'ast>, def_id: TyDefId, generics: GenericArgs<'ast>) -> Self {
+        Self { data, def_id, generics }
     }
 }
 
+super::impl_ty_data!(AdtTy<'ast>);
+
 /// The semantic representation of a generic type. For example
 ///
 /// ```
@@ -48,7 +51,7 @@ impl<'ast> AdtTy<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct GenericTy<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
     generic_id: GenericId,
 }
 
@@ -63,14 +66,13 @@ impl<'ast> GenericTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> GenericTy<'ast> {
-    pub fn new(generic_id: GenericId) -> Self {
-        Self {
-            _lifetime: PhantomData,
-            generic_id,
-        }
+    pub fn new(data: CommonTyData<'ast>, generic_id: GenericId) -> Self {
+        Self { data, generic_id }
     }
 }
 
+super::impl_ty_data!(GenericTy<'ast>);
+
 /// The semantic representation of a type alias.
 ///
 /// Aliases in semantic type representations are usually resolved directly. This
@@ -79,7 +81,7 @@ impl<'ast> GenericTy<'ast> {
 #[repr(C)]
 #[derive(Debug)]
 pub struct AliasTy<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+    data: CommonTyData<'ast>,
     alias_item: ItemId,
 }
 
@@ -92,10 +94,9 @@ impl<'ast> AliasTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> AliasTy<'ast> {
-    pub fn new(alias_item: ItemId) -> Self {
-        Self {
-            _lifetime: PhantomData,
-            alias_item,
-        }
+    pub fn new(data: CommonTyData<'ast>, alias_item: ItemId) -> Self {
+        Self { data, alias_item }
     }
 }
+
+super::impl_ty_data!(AliasTy<'ast>);
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 
 use crate::{
-    common::{ExpnId, MacroId, SpanId, SpanSrcId, SymbolId},
+    ast::AttrStyle,
+    common::{CrateId, ExpnId, MacroId, SpanId, SpanSrcId, SymbolId},
     context::with_cx,
     diagnostic::Applicability,
     ffi,
@@ -138,11 +139,20 @@ impl SpanPos {
 /// this with some trickery. (Please use this forbidden knowledge carefully.)
 #[repr(C)]
 #[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
 pub struct ExpnInfo<'ast> {
+    #[cfg_attr(feature = "driver-api", builder(setter(skip), default))]
     _lifetime: PhantomData<&'ast ()>,
     parent: ExpnId,
     call_site: SpanId,
+    def_site: SpanId,
     macro_id: MacroId,
+    macro_kind: MacroKind,
+    macro_name: SymbolId,
+    macro_path: SymbolId,
+    macro_crate: CrateId,
+    macro_crate_name: SymbolId,
+    is_local_macro: bool,
 }
 
 impl<'ast> ExpnInfo<'ast> {
@@ -158,22 +168,65 @@ impl<'ast> ExpnInfo<'ast> {
         with_cx(self, |cx| cx.span(self.call_site))
     }
 
+    /// The [`Span`] of the macro definition. For macros from other crates,
+    /// this can point to a file of the dependency.
+    #[must_use]
+    pub fn def_site(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.def_site))
+    }
+
+    /// The [`MacroId`] of the expanded macro. It can be compared with the ids
+    /// returned by [`MarkerContext::resolve_macro_ids`](crate::MarkerContext::resolve_macro_ids),
+    /// to check if the expansion comes from a specific macro.
     pub fn macro_id(&self) -> MacroId {
         self.macro_id
     }
-}
 
-#[cfg(feature = "driver-api")]
-impl<'ast> ExpnInfo<'ast> {
-    #[must_use]
-    pub fn new(parent: ExpnId, call_site: SpanId, macro_id: MacroId) -> Self {
-        Self {
-            _lifetime: PhantomData,
-            parent,
-            call_site,
-            macro_id,
-        }
+    pub fn macro_kind(&self) -> MacroKind {
+        self.macro_kind
     }
+
+    /// The name of the macro, as it was defined. For example, `vec` for the
+    /// expansion of `std::vec![]` and `Clone` for `#[derive(Clone)]`.
+    pub fn macro_name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.macro_name))
+    }
+
+    /// The path that was used to invoke the macro, like `std::vec` for
+    /// `std::vec![]`. For derives, this is the path in the `#[derive]`
+    /// attribute.
+    pub fn macro_path(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.macro_path))
+    }
+
+    /// The [`CrateId`] of the crate that defines the macro.
+    pub fn macro_crate(&self) -> CrateId {
+        self.macro_crate
+    }
+
+    /// The name of the crate that defines the macro, like `alloc` for `vec![]`.
+    pub fn macro_crate_name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.macro_crate_name))
+    }
+
+    /// Returns `true`, if the macro is defined in the crate that is currently
+    /// being linted.
+    pub fn is_local_macro(&self) -> bool {
+        self.is_local_macro
+    }
+}
+
+/// The kind of a macro, which created an [`ExpnInfo`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MacroKind {
+    /// A function-like macro, like `vec![]` or `println!()`
+    Bang,
+    /// An attribute macro, like `#[test]`
+    Attr,
+    /// A derive macro, like `#[derive(Clone)]`
+    Derive,
 }
 
 /// A region of code, used for snipping, lint emission, and the retrieval of
@@ -389,6 +442,54 @@ impl<'ast> Span<'ast> {
     pub fn source(&self) -> SpanSource<'ast> {
         with_cx(self, |cx| cx.span_source(self))
     }
+
+    /// Returns the comments directly preceding this [`Span`]. Comments are
+    /// considered to be directly preceding, if they're only separated from
+    /// the start of this [`Span`] and each other by whitespace. Trailing
+    /// comments, which follow code on the same line, are not included.
+    ///
+    /// ```ignore
+    /// // This comment is not included, since it's followed by code
+    /// let a = 1; // This trailing comment is also not included
+    /// /* This block comment is included */
+    /// // SAFETY: And this line comment as well
+    /// unsafe { foo(a) }
+    /// // <-- span_of_unsafe_block
+    ///
+    /// span_of_unsafe_block.preceding_comments(); // -> [Block, Line]
+    /// ```
+    ///
+    /// The returned comments are ordered by their position. This will always be
+    /// empty for spans, which don't originate from a [`SpanSource::File`].
+    ///
+    /// Note that the [`Span`] of most nodes doesn't include their attributes.
+    /// Comments in front of an attribute are therefore not directly preceding
+    /// the node. Use the [`Span`] of the first attribute in such cases.
+    #[must_use]
+    pub fn preceding_comments(&self) -> &'ast [Comment<'ast>] {
+        let SpanSource::File(file) = self.source() else {
+            return &[];
+        };
+        let comments = file.comments();
+        let end = comments.partition_point(|comment| comment.span.end.0 <= self.start.0);
+
+        let mut start = end;
+        let mut next_start = self.start;
+        while start > 0 {
+            let comment = &comments[start - 1];
+            let mut gap = self.clone();
+            gap.start = comment.span.end;
+            gap.end = next_start;
+            if comment.is_trailing() || !gap.snippet().map_or(false, |snip| snip.trim().is_empty()) {
+                break;
+            }
+
+            start -= 1;
+            next_start = comment.span.start;
+        }
+
+        &comments[start..end]
+    }
 }
 
 impl<'ast> HasSpan<'ast> for Span<'ast> {
@@ -425,10 +526,21 @@ pub enum SpanSource<'ast> {
 }
 
 #[repr(C)]
-#[derive(Debug)]
 pub struct FileInfo<'ast> {
     file: ffi::FfiStr<'ast>,
     span_src: SpanSrcId,
+    start_pos: SpanPos,
+}
+
+// The start position is an implementation detail of the driver and depends on
+// the order in which files are loaded. It's therefore not included.
+impl<'ast> std::fmt::Debug for FileInfo<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileInfo")
+            .field("file", &self.file)
+            .field("span_src", &self.span_src)
+            .finish()
+    }
 }
 
 impl<'ast> FileInfo<'ast> {
@@ -452,21 +564,132 @@ impl<'ast> FileInfo<'ast> {
             )
         })
     }
+
+    /// Returns all comments in this file, ordered by their position. This
+    /// includes normal comments, like `// Comment` and `/* Comment */`, as
+    /// well as doc comments, like `/// Docs` or `//! Docs`.
+    ///
+    /// Use [`Span::preceding_comments`] to only get the comments in front of
+    /// a specific node.
+    pub fn comments(&self) -> &'ast [Comment<'ast>] {
+        with_cx(self, |cx| cx.file_comments(self))
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FileInfo<'ast> {
     #[must_use]
-    pub fn new(file: &'ast str, span_src: SpanSrcId) -> Self {
+    pub fn new(file: &'ast str, span_src: SpanSrcId, start_pos: SpanPos) -> Self {
         Self {
             file: file.into(),
             span_src,
+            start_pos,
         }
     }
 
     pub fn span_src(&self) -> SpanSrcId {
         self.span_src
     }
+
+    /// The [`SpanPos`] of the first byte in this file. This can be used by
+    /// drivers to identify the file.
+    pub fn start_pos(&self) -> SpanPos {
+        self.start_pos
+    }
+}
+
+/// A comment in the source code, like `// Comment`, `/* Comment */` or `/// Docs`.
+///
+/// Comments are not part of the AST. They can be requested for an entire file
+/// with [`FileInfo::comments`] or for a specific node with
+/// [`Span::preceding_comments`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct Comment<'ast> {
+    kind: CommentKind,
+    doc_style: ffi::FfiOption<AttrStyle>,
+    is_trailing: bool,
+    text: ffi::FfiStr<'ast>,
+    span: Span<'ast>,
+}
+
+impl<'ast> Comment<'ast> {
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this is a doc comment, like `/// Docs` or `//! Docs`.
+    /// Doc comments are also available as [`Attribute`](crate::ast::Attribute)s
+    /// of the node they're attached to.
+    pub fn is_doc(&self) -> bool {
+        self.doc_style.get().is_some()
+    }
+
+    /// Returns the [`AttrStyle`] of this comment, if it's a doc comment. Outer
+    /// doc comments, like `/// Docs`, will return [`AttrStyle::Outer`] and
+    /// inner doc comments, like `//! Docs`, will return [`AttrStyle::Inner`].
+    pub fn doc_style(&self) -> Option<AttrStyle> {
+        self.doc_style.copy()
+    }
+
+    /// Returns `true`, if this comment follows code on the same line, like
+    /// `let a = 1; // Comment`.
+    pub fn is_trailing(&self) -> bool {
+        self.is_trailing
+    }
+
+    /// The text of the comment, including the comment markers. For
+    /// `// SAFETY: ...` this would be `// SAFETY: ...`.
+    pub fn text(&self) -> &'ast str {
+        self.text.get()
+    }
+
+    /// The text of the comment without the comment markers. For
+    /// `// SAFETY: ...` this would be ` SAFETY: ...`, including the leading space.
+    pub fn content(&self) -> &'ast str {
+        let text = self.text.get();
+        let prefix_len = if self.is_doc() { 3 } else { 2 };
+        let content = &text[prefix_len..];
+        match self.kind {
+            CommentKind::Line => content,
+            CommentKind::Block => content.strip_suffix("*/").unwrap_or(content),
+        }
+    }
+}
+
+impl<'ast> HasSpan<'ast> for Comment<'ast> {
+    fn span(&self) -> &Span<'ast> {
+        &self.span
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Comment<'ast> {
+    pub fn new(
+        kind: CommentKind,
+        doc_style: Option<AttrStyle>,
+        is_trailing: bool,
+        text: &'ast str,
+        span: Span<'ast>,
+    ) -> Self {
+        Self {
+            kind,
+            doc_style: doc_style.into(),
+            is_trailing,
+            text: text.into(),
+            span,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CommentKind {
+    /// A line comment, like `// Comment`, `/// Docs` or `//! Docs`.
+    Line,
+    /// A block comment, like `/* Comment */`, `/** Docs */` or `/*! Docs */`.
+    Block,
 }
 
 /// A location inside a file.
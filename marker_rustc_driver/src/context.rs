@@ -1,15 +1,24 @@
 use std::cell::{OnceCell, RefCell};
 
-use marker_adapter::context::{AstMapWrapper, MarkerContextDriver, MarkerContextWrapper};
+use marker_adapter::{
+    context::{AstMapWrapper, MarkerContextDriver, MarkerContextWrapper},
+    CAPPED_LINT_NOTE,
+};
 use marker_api::{
-    common::{SpanId, SymbolId},
+    common::{MacroId, SpanId, SymbolId, VarId},
     diagnostic::Diagnostic,
     prelude::*,
+    sem::{Adjustment, ConstValue, LocalUsage, TyLayout, TyProperty},
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
-use rustc_lint::LintStore;
-use rustc_middle::ty::TyCtxt;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{Level, LintStore};
+use rustc_middle::{
+    lint::struct_lint_level,
+    ty::{self as mid_ty, TyCtxt, TypeVisitableExt},
+};
+use rustc_trait_selection::infer::InferCtxtExt;
 
 use crate::conversion::{marker::MarkerConverter, rustc::RustcConverter};
 
@@ -40,6 +49,11 @@ pub struct RustcContext<'ast, 'tcx> {
     /// which makes it safe to access afterwards.
     ast_cx: OnceCell<&'ast MarkerContext<'ast>>,
     resolved_ty_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    resolved_item_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    resolved_macro_ids: RefCell<FxHashMap<&'ast str, &'ast [MacroId]>>,
+    /// Marker lints with the error level are emitted as warnings, if this is
+    /// set. See [`CAP_LINTS_ENV`](crate::CAP_LINTS_ENV).
+    cap_lints: bool,
 }
 
 impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
@@ -53,6 +67,9 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
             rustc_converter: RustcConverter::new(rustc_cx),
             ast_cx: OnceCell::new(),
             resolved_ty_ids: RefCell::default(),
+            resolved_item_ids: RefCell::default(),
+            resolved_macro_ids: RefCell::default(),
+            cap_lints: std::env::var(crate::CAP_LINTS_ENV).is_ok_and(|cap| cap == "warn"),
         });
 
         // Create and link `MarkerContext`
@@ -73,6 +90,75 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
         // The `OnceCell` is filled in the new function and can never be not set.
         self.ast_cx.get().unwrap()
     }
+
+    /// Resolves the given path to all matching definitions. The first segment
+    /// is the name of the crate or `crate` for the local crate. Every crate
+    /// with a matching name is searched, which means that the result can
+    /// contain multiple items, if several versions of a crate are in the
+    /// dependency tree.
+    ///
+    /// This code is inspired by `clippy_utils::def_path_res` without the special
+    /// handling for primitive types.
+    fn resolve_path(&self, path: &str) -> Vec<hir::def::Res<hir::def_id::DefId>> {
+        // Path splitting and "validation"
+        let mut splits = path.split("::");
+        let Some(krate_name) = splits.next() else {
+            return vec![];
+        };
+        let segs: Vec<_> = splits.collect();
+        if segs.is_empty() {
+            return vec![];
+        }
+
+        let tcx = self.rustc_cx;
+        let krate_name = rustc_span::Symbol::intern(krate_name);
+        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
+            &[hir::def_id::LOCAL_CRATE]
+        } else {
+            &[]
+        };
+        let krates = tcx
+            .crates(())
+            .iter()
+            .copied()
+            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
+            .filter(|id| tcx.crate_name(*id) == krate_name)
+            .chain(additional_krate.iter().copied());
+        let mut searches: Vec<_> = krates
+            .map(rustc_span::def_id::CrateNum::as_def_id)
+            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
+            .collect();
+
+        let mut rest = &segs[..];
+        while let [seg, next_rest @ ..] = rest {
+            rest = next_rest;
+            let seg = rustc_span::Symbol::intern(seg);
+            searches = select_children_with_name(tcx, &searches, seg);
+        }
+
+        searches
+    }
+
+    fn rustc_ty_implements_trait(
+        &self,
+        ty: mid_ty::Ty<'tcx>,
+        param_env: mid_ty::ParamEnv<'tcx>,
+        trait_def_id: hir::def_id::DefId,
+        args: Vec<mid_ty::GenericArg<'tcx>>,
+    ) -> bool {
+        // Bound variables can only be handled inside their binder
+        if ty.has_escaping_bound_vars() || args.iter().any(TypeVisitableExt::has_escaping_bound_vars) {
+            return false;
+        }
+
+        let tcx = self.rustc_cx;
+        let ty = tcx.erase_regions(ty);
+        let args = std::iter::once(ty.into()).chain(args.into_iter().map(|arg| tcx.erase_regions(arg)));
+        tcx.infer_ctxt()
+            .build()
+            .type_implements_trait(trait_def_id, args, param_env)
+            .must_apply_modulo_regions()
+    }
 }
 
 impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
@@ -81,10 +167,17 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
             return;
         };
         let lint = self.rustc_converter.to_lint(diag.lint);
-        self.rustc_cx.struct_span_lint_hir(
+        let (mut level, src) = self.rustc_cx.lint_level_at_node(lint, id);
+        let capped = self.cap_lints && matches!(level, Level::Deny | Level::Forbid);
+        if capped {
+            level = Level::Warn;
+        }
+        struct_lint_level(
+            self.rustc_cx.sess,
             lint,
-            id,
-            self.rustc_converter.to_span(diag.span),
+            level,
+            src,
+            Some(self.rustc_converter.to_span(diag.span).into()),
             diag.msg().to_string(),
             |builder| {
                 for part in diag.parts.get() {
@@ -109,9 +202,23 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
                                 self.rustc_converter.to_applicability(*app),
                             );
                         },
+                        marker_api::diagnostic::DiagnosticPart::MultipartSuggestion { msg, parts, app } => {
+                            builder.multipart_suggestion(
+                                msg.get().to_string(),
+                                parts
+                                    .get()
+                                    .iter()
+                                    .map(|part| (self.rustc_converter.to_span(part.span), part.sugg.get().to_string()))
+                                    .collect(),
+                                self.rustc_converter.to_applicability(*app),
+                            );
+                        },
                         _ => unreachable!(),
                     }
                 }
+                if capped {
+                    builder.note(CAPPED_LINT_NOTE);
+                }
                 builder
             },
         );
@@ -123,78 +230,119 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
             return ids;
         }
 
-        // Path splitting and "validation"
-        let mut splits = path.split("::");
-        let Some(krate_name) = splits.next() else {
-            return &[];
-        };
-        let segs: Vec<_> = splits.collect();
-        if segs.is_empty() {
-            return &[];
-        }
         // This method is only intended to resolve `TyDefId`s, this means we can
         // ignore primitive types and all others which are specificity handled in
         // the `*TyKind` enums. Basically, we only need to find the ids of Enums,
         // Structs, Unions and maybe type aliases.
-        //
-        // This code is inspired by `clippy_utils::def_path_res` without the special
-        // handling for primitive types and other items
         let tcx = self.rustc_cx;
-        let krate_name = rustc_span::Symbol::intern(krate_name);
-        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
-            &[hir::def_id::LOCAL_CRATE]
-        } else {
-            &[]
-        };
-        let krates = tcx
-            .crates(())
-            .iter()
-            .copied()
-            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
-            .filter(|id| tcx.crate_name(*id) == krate_name)
-            .chain(additional_krate.iter().copied());
-        let mut searches: Vec<_> = krates
-            .map(rustc_span::def_id::CrateNum::as_def_id)
-            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
+        let ids: Vec<_> = self
+            .resolve_path(path)
+            .into_iter()
+            .filter_map(|res| res.opt_def_id())
+            .filter(|def_id| {
+                matches!(
+                    tcx.def_kind(def_id),
+                    hir::def::DefKind::Struct
+                        | hir::def::DefKind::Union
+                        | hir::def::DefKind::Enum
+                        | hir::def::DefKind::Trait
+                        | hir::def::DefKind::TyAlias { .. }
+                )
+            })
+            .map(|def_id| self.marker_converter.to_ty_def_id(def_id))
             .collect();
 
-        let mut rest = &segs[..];
-        while let [seg, next_rest @ ..] = rest {
-            rest = next_rest;
-            let seg = rustc_span::Symbol::intern(seg);
-            searches = select_children_with_name(tcx, &searches, seg);
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.resolved_ty_ids
+            .borrow_mut()
+            .insert(self.storage.alloc_str(path), ids);
+        ids
+    }
+
+    fn resolve_item_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.resolved_item_ids.borrow().get(path) {
+            return ids;
         }
 
-        // Filtering to only take `DefId`s which are also `TyDefId`s
-        let ids: Vec<_> = searches
+        let tcx = self.rustc_cx;
+        let ids: Vec<_> = self
+            .resolve_path(path)
             .into_iter()
             .filter_map(|res| res.opt_def_id())
             .filter(|def_id| {
                 matches!(
                     tcx.def_kind(def_id),
-                    hir::def::DefKind::Struct
+                    hir::def::DefKind::Mod
+                        | hir::def::DefKind::Struct
                         | hir::def::DefKind::Union
                         | hir::def::DefKind::Enum
                         | hir::def::DefKind::Trait
+                        | hir::def::DefKind::TraitAlias
                         | hir::def::DefKind::TyAlias { .. }
+                        | hir::def::DefKind::ForeignTy
+                        | hir::def::DefKind::AssocTy
+                        | hir::def::DefKind::Fn
+                        | hir::def::DefKind::AssocFn
+                        | hir::def::DefKind::Const
+                        | hir::def::DefKind::AssocConst
+                        | hir::def::DefKind::Static(_)
                 )
             })
-            .map(|def_id| self.marker_converter.to_ty_def_id(def_id))
+            .map(|def_id| self.marker_converter.to_item_id(def_id))
             .collect();
 
         // Allocation and caching
         let ids = self.storage.alloc_slice(ids);
-        self.resolved_ty_ids
+        self.resolved_item_ids
+            .borrow_mut()
+            .insert(self.storage.alloc_str(path), ids);
+        ids
+    }
+
+    fn resolve_macro_ids(&'ast self, path: &str) -> &'ast [MacroId] {
+        // Caching
+        if let Some(ids) = self.resolved_macro_ids.borrow().get(path) {
+            return ids;
+        }
+
+        let tcx = self.rustc_cx;
+        let ids: Vec<_> = self
+            .resolve_path(path)
+            .into_iter()
+            .filter_map(|res| res.opt_def_id())
+            .filter(|def_id| matches!(tcx.def_kind(def_id), hir::def::DefKind::Macro(_)))
+            .map(|def_id| self.marker_converter.to_macro_id(def_id))
+            .collect();
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.resolved_macro_ids
             .borrow_mut()
             .insert(self.storage.alloc_str(path), ids);
         ids
     }
 
+    fn local_usages(&'ast self, var: VarId) -> &'ast [LocalUsage] {
+        let hir_id = self.rustc_converter.to_hir_id(var);
+        self.marker_converter.local_usages(hir_id)
+    }
+
     fn expr_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
         let hir_id = self.rustc_converter.to_hir_id(expr);
         self.marker_converter.expr_ty(hir_id)
     }
 
+    fn expr_adjustments(&'ast self, expr: ExprId) -> &'ast [Adjustment<'ast>] {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.expr_adjustments(hir_id)
+    }
+
+    fn trait_ref_path(&'ast self, trait_ref: &ast::TraitRef<'ast>) -> &'ast ast::AstQPath<'ast> {
+        self.marker_converter.trait_ref_path(trait_ref)
+    }
+
     fn span(&'ast self, span_id: SpanId) -> &'ast Span<'ast> {
         let rustc_span = self.rustc_converter.to_span_from_id(span_id);
         self.storage.alloc(self.marker_converter.to_span(rustc_span))
@@ -222,6 +370,11 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
         )
     }
 
+    fn file_comments(&'ast self, file: &marker_api::span::FileInfo<'ast>) -> &'ast [marker_api::span::Comment<'ast>] {
+        self.marker_converter
+            .file_comments(self.rustc_converter.to_byte_pos(file.start_pos()))
+    }
+
     fn span_expn_info(
         &'ast self,
         expn_id: marker_api::common::ExpnId,
@@ -242,8 +395,76 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
         api_str
     }
 
-    fn resolve_method_target(&'ast self, _id: ExprId) -> ItemId {
-        todo!()
+    fn resolve_method_target(&'ast self, id: ExprId) -> &'ast ast::MethodTarget<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.method_target(hir_id)
+    }
+
+    fn ty_implements_trait(
+        &'ast self,
+        ty: marker_api::sem::TyKind<'ast>,
+        trait_id: TyDefId,
+        generic_args: &[marker_api::sem::TyKind<'ast>],
+    ) -> bool {
+        let tcx = self.rustc_cx;
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        if !matches!(tcx.def_kind(trait_def_id), hir::def::DefKind::Trait) {
+            return false;
+        }
+
+        // The user provided arguments have to be mapped to the generic parameters
+        // of the trait. Lifetimes are erased and `Self` is the checked type.
+        let mut arg_tys = generic_args.iter();
+        let mut args = vec![];
+        for param in tcx.generics_of(trait_def_id).params.iter().skip(1) {
+            match param.kind {
+                mid_ty::GenericParamDefKind::Lifetime => args.push(tcx.lifetimes.re_erased.into()),
+                mid_ty::GenericParamDefKind::Type { .. } => {
+                    let Some(arg) = arg_tys.next() else {
+                        return false;
+                    };
+                    args.push(self.marker_converter.rustc_ty(arg.driver_id()).0.into());
+                },
+                mid_ty::GenericParamDefKind::Const { .. } => return false,
+            }
+        }
+        if arg_tys.next().is_some() {
+            return false;
+        }
+
+        let (ty, param_env) = self.marker_converter.rustc_ty(ty.driver_id());
+        self.rustc_ty_implements_trait(ty, param_env, trait_def_id, args)
+    }
+
+    fn ty_layout(&'ast self, ty: marker_api::sem::TyKind<'ast>) -> Option<&'ast TyLayout<'ast>> {
+        self.marker_converter.ty_layout(ty.driver_id())
+    }
+
+    fn eval_const(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter
+            .eval_const(hir_id)
+            .map(|value| self.storage.alloc(value))
+    }
+
+    fn ty_has_property(&'ast self, ty: marker_api::sem::TyKind<'ast>, property: TyProperty) -> bool {
+        let tcx = self.rustc_cx;
+        let (ty, param_env) = self.marker_converter.rustc_ty(ty.driver_id());
+        if ty.has_escaping_bound_vars() {
+            return false;
+        }
+
+        match property {
+            TyProperty::Copy => ty.is_copy_modulo_regions(tcx, param_env),
+            TyProperty::Sized => ty.is_sized(tcx, param_env),
+            TyProperty::NeedsDrop => ty.needs_drop(tcx, param_env),
+            TyProperty::Send => tcx.get_diagnostic_item(rustc_span::sym::Send).map_or(false, |send| {
+                self.rustc_ty_implements_trait(ty, param_env, send, vec![])
+            }),
+            TyProperty::Sync => tcx.lang_items().sync_trait().map_or(false, |sync| {
+                self.rustc_ty_implements_trait(ty, param_env, sync, vec![])
+            }),
+        }
     }
 }
 
@@ -293,5 +514,20 @@ fn select_children_with_name(
         }
     }
 
+    // Associated items of traits and inherent impls
+    for id in search.iter().filter_map(rustc_hir::def::Res::opt_def_id) {
+        let containers = match tcx.def_kind(id) {
+            hir::def::DefKind::Trait => std::slice::from_ref(&id),
+            hir::def::DefKind::Struct | hir::def::DefKind::Enum | hir::def::DefKind::Union => tcx.inherent_impls(id),
+            _ => continue,
+        };
+        for container in containers {
+            tcx.associated_items(container)
+                .filter_by_name_unhygienic(name)
+                .map(|item| hir::def::Res::Def(tcx.def_kind(item.def_id), item.def_id))
+                .collect_into(&mut next_search);
+        }
+    }
+
     next_search
 }
@@ -1,6 +1,6 @@
 use marker_adapter::context::AstMapDriver;
 use marker_api::{
-    ast::{EnumVariant, ItemField},
+    ast::{Attribute, EnumVariant, ItemField},
     common::Level,
     prelude::*,
 };
@@ -9,6 +9,11 @@ use super::RustcContext;
 
 impl<'ast, 'tcx: 'ast> AstMapDriver<'ast> for RustcContext<'ast, 'tcx> {
     fn item(&'ast self, id: ItemId) -> Option<ItemKind<'ast>> {
+        // Only items of the current crate have an AST representation
+        if !self.rustc_converter.to_def_id(id).is_local() {
+            return None;
+        }
+
         let rustc_id = self.rustc_converter.to_item_id(id);
         self.marker_converter.item(rustc_id)
     }
@@ -46,6 +51,13 @@ impl<'ast, 'tcx: 'ast> AstMapDriver<'ast> for RustcContext<'ast, 'tcx> {
         }
     }
 
+    fn attrs(&'ast self, node: NodeId) -> &'ast [Attribute<'ast>] {
+        match self.rustc_converter.try_to_hir_id_from_emission_node(node) {
+            Some(id) => self.marker_converter.to_attrs(id),
+            None => &[],
+        }
+    }
+
     fn lint_level_at(&'ast self, api_lint: &'static Lint, node: NodeId) -> Level {
         if let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(node) {
             let lint = self.rustc_converter.to_lint(api_lint);
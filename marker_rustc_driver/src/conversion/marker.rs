@@ -12,11 +12,14 @@ use std::cell::{OnceCell, RefCell};
 
 use crate::context::storage::Storage;
 use marker_api::{
-    ast::{Body, CommonItemData, Crate, EnumVariant, ItemField, ModItem, Visibility as AstVisibility},
-    common::{Level, SymbolId},
+    ast::{
+        AstQPath, Attribute, Body, CommonItemData, Crate, EnumVariant, ItemField, MethodTarget, ModItem, TraitRef,
+        Visibility as AstVisibility,
+    },
+    common::{DriverTyId, Level, MacroId, SymbolId},
     prelude::*,
-    sem::{Visibility as SemVisibility, VisibilityKind},
-    span::{ExpnInfo, FilePos, Span, SpanSource},
+    sem::{Adjustment, ConstValue, LocalUsage, TyLayout, Visibility as SemVisibility, VisibilityKind},
+    span::{Comment, ExpnInfo, FilePos, Span, SpanSource},
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
@@ -66,6 +69,131 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         })
     }
 
+    pub fn expr_adjustments(&self, id: hir::HirId) -> &'ast [Adjustment<'ast>] {
+        // Check the cache
+        if let Some(adjustments) = self.inner.expr_adjustments.borrow().get(&id) {
+            return adjustments;
+        }
+
+        let adjustments = self.with_body(id, |inner| {
+            let adjustments = inner
+                .rustc_ty_check()
+                .adjustments()
+                .get(id)
+                .map_or(&[][..], Vec::as_slice);
+            inner.to_adjustments(adjustments)
+        });
+        self.inner.expr_adjustments.borrow_mut().insert(id, adjustments);
+        adjustments
+    }
+
+    pub fn local_usages(&self, var: hir::HirId) -> &'ast [LocalUsage] {
+        // Check the cache
+        if let Some(usages) = self.inner.local_usages.borrow().get(&var) {
+            return usages;
+        }
+
+        let usages = self.with_body(var, |inner| inner.to_local_usages(var));
+        self.inner.local_usages.borrow_mut().insert(var, usages);
+        usages
+    }
+
+    pub fn trait_ref_path(&self, trait_ref: &TraitRef<'ast>) -> &'ast AstQPath<'ast> {
+        self.inner
+            .trait_ref_paths
+            .borrow()
+            .get(&(trait_ref as *const TraitRef<'ast>))
+            .expect("the path is registered, when the `TraitRef` is allocated")
+    }
+
+    pub fn method_target(&self, hir_id: hir::HirId) -> &'ast MethodTarget<'ast> {
+        // Check the cache
+        if let Some(target) = self.inner.method_targets.borrow().get(&hir_id) {
+            return target;
+        }
+
+        let target = self.with_body(hir_id, |inner| {
+            let tcx = inner.rustc_cx;
+            let Some(hir::Node::Expr(hir::Expr {
+                kind: hir::ExprKind::MethodCall(_, receiver, ..),
+                ..
+            })) = tcx.hir().find(hir_id)
+            else {
+                unreachable!("the `HirId` belongs to a method call, since it comes from a `MethodExpr`")
+            };
+            let ty_check = inner.rustc_ty_check();
+            let def_id = ty_check
+                .type_dependent_def_id(hir_id)
+                .expect("method calls are always resolved during type checking");
+
+            // `Instance::resolve` selects the impl of trait methods, if the
+            // receiver type is concrete enough to determine it.
+            let owner = tcx.hir().enclosing_body_owner(hir_id);
+            let param_env = tcx.param_env_reveal_all_normalized(owner);
+            let args = tcx.erase_regions(ty_check.node_args(hir_id));
+            let impl_item = match rustc_middle::ty::Instance::resolve(tcx, param_env, def_id, args) {
+                Ok(Some(instance)) => match instance.def {
+                    rustc_middle::ty::InstanceDef::Item(impl_id) => Some(inner.to_item_id(impl_id)),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            MethodTarget::builder()
+                .id(inner.to_item_id(def_id))
+                .is_trait_method(tcx.trait_of_item(def_id).is_some())
+                .impl_item(impl_item)
+                .receiver_adjustments(inner.to_adjustments(ty_check.expr_adjustments(receiver)))
+                .build()
+        });
+        let target = self.inner.alloc(target);
+        self.inner.method_targets.borrow_mut().insert(hir_id, target);
+        target
+    }
+
+    /// Returns the rustc type and [`ParamEnv`](rustc_middle::ty::ParamEnv)
+    /// of a semantic type, identified by its [`DriverTyId`].
+    pub fn rustc_ty(&self, id: DriverTyId) -> DriverTy<'tcx> {
+        let index = usize::try_from(id.data()).expect("the id was created from an index");
+        self.inner.driver_tys.borrow()[index]
+    }
+
+    pub fn eval_const(&self, hir_id: hir::HirId) -> Option<ConstValue<'ast>> {
+        self.with_body(hir_id, |inner| {
+            let Some(hir::Node::Expr(expr)) = inner.rustc_cx.hir().find(hir_id) else {
+                return None;
+            };
+            inner.eval_const_expr(expr)
+        })
+    }
+
+    pub fn ty_layout(&self, id: DriverTyId) -> Option<&'ast TyLayout<'ast>> {
+        // Check the cache
+        if let Some(layout) = self.inner.ty_layouts.borrow().get(&id) {
+            return *layout;
+        }
+
+        let (ty, param_env) = self.rustc_ty(id);
+        let layout = self
+            .inner
+            .to_ty_layout(ty, param_env)
+            .map(|layout| self.inner.alloc(layout));
+        self.inner.ty_layouts.borrow_mut().insert(id, layout);
+        layout
+    }
+
+    pub fn file_comments(&self, file_start: rustc_span::BytePos) -> &'ast [Comment<'ast>] {
+        // Check the cache
+        if let Some(comments) = self.inner.file_comments.borrow().get(&file_start) {
+            return comments;
+        }
+
+        let src_file = self.inner.rustc_cx.sess.source_map().lookup_source_file(file_start);
+        let comments = self.inner.to_comments(&src_file);
+        self.inner.file_comments.borrow_mut().insert(file_start, comments);
+        comments
+    }
+
     forward_to_inner!(pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level);
 
     pub fn body(&self, id: hir::BodyId) -> &'ast Body<'ast> {
@@ -137,7 +265,10 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         self.inner.fields.borrow().get(&id).copied()
     }
 
+    forward_to_inner!(pub fn to_attrs(&self, hir_id: hir::HirId) -> &'ast [Attribute<'ast>]);
+    forward_to_inner!(pub fn to_item_id(&self, id: hir::def_id::DefId) -> ItemId);
     forward_to_inner!(pub fn to_ty_def_id(&self, id: hir::def_id::DefId) -> TyDefId);
+    forward_to_inner!(pub fn to_macro_id(&self, id: hir::def_id::DefId) -> MacroId);
     forward_to_inner!(pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast>);
     forward_to_inner!(pub fn to_span_source(&self, rust_span: rustc_span::Span) -> SpanSource<'ast>);
     forward_to_inner!(pub fn try_to_expn_info(&self, expn_id: rustc_span::ExpnId) -> Option<&'ast ExpnInfo<'ast>>);
@@ -160,6 +291,8 @@ macro_rules! forward_to_inner {
 }
 use forward_to_inner;
 
+type DriverTy<'tcx> = (rustc_middle::ty::Ty<'tcx>, rustc_middle::ty::ParamEnv<'tcx>);
+
 struct MarkerConverterInner<'ast, 'tcx> {
     rustc_cx: rustc_middle::ty::TyCtxt<'tcx>,
     storage: &'ast Storage<'ast>,
@@ -172,6 +305,22 @@ struct MarkerConverterInner<'ast, 'tcx> {
     stmts: RefCell<FxHashMap<StmtId, StmtKind<'ast>>>,
     fields: RefCell<FxHashMap<FieldId, &'ast ItemField<'ast>>>,
     variants: RefCell<FxHashMap<VariantId, &'ast EnumVariant<'ast>>>,
+    attrs: RefCell<FxHashMap<hir::HirId, &'ast [Attribute<'ast>]>>,
+    method_targets: RefCell<FxHashMap<hir::HirId, &'ast MethodTarget<'ast>>>,
+    expr_adjustments: RefCell<FxHashMap<hir::HirId, &'ast [Adjustment<'ast>]>>,
+    local_usages: RefCell<FxHashMap<hir::HirId, &'ast [LocalUsage]>>,
+    /// The paths of [`TraitRef`]s, identified by the address of the allocated
+    /// [`TraitRef`]. See [`MarkerConverterInner::register_trait_ref_path`].
+    trait_ref_paths: RefCell<FxHashMap<*const TraitRef<'ast>, &'ast AstQPath<'ast>>>,
+    /// Semantic types store a [`DriverTyId`], which is the index of the rustc
+    /// type in this list. The [`ParamEnv`](rustc_middle::ty::ParamEnv) of the
+    /// body, that the type originates from, is stored alongside it, to answer
+    /// questions about generic types.
+    driver_tys: RefCell<Vec<DriverTy<'tcx>>>,
+    driver_ty_ids: RefCell<FxHashMap<DriverTy<'tcx>, DriverTyId>>,
+    ty_layouts: RefCell<FxHashMap<DriverTyId, Option<&'ast TyLayout<'ast>>>>,
+    /// The comments of source files, identified by the start position of the file.
+    file_comments: RefCell<FxHashMap<rustc_span::BytePos, &'ast [Comment<'ast>]>>,
 
     // Cached/Dummy values
     builtin_span_source: &'ast marker_api::span::BuiltinInfo<'ast>,
@@ -227,6 +376,15 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             stmts: RefCell::default(),
             fields: RefCell::default(),
             variants: RefCell::default(),
+            attrs: RefCell::default(),
+            method_targets: RefCell::default(),
+            expr_adjustments: RefCell::default(),
+            local_usages: RefCell::default(),
+            trait_ref_paths: RefCell::default(),
+            driver_tys: RefCell::default(),
+            driver_ty_ids: RefCell::default(),
+            ty_layouts: RefCell::default(),
+            file_comments: RefCell::default(),
             builtin_span_source: storage.alloc(marker_api::span::BuiltinInfo::default()),
             num_symbols: RefCell::default(),
             lang_item_map: RefCell::default(),
@@ -271,6 +429,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .expect("MarkerConverterInner.rustc_ty_check is unexpectedly empty")
     }
 
+    /// Returns the [`ParamEnv`](rustc_middle::ty::ParamEnv) of the body, that
+    /// is currently being converted, or an empty one outside of bodies.
+    pub fn rustc_param_env(&self) -> rustc_middle::ty::ParamEnv<'tcx> {
+        self.rustc_body
+            .borrow()
+            .map_or_else(rustc_middle::ty::ParamEnv::empty, |body_id| {
+                let owner = self.rustc_cx.hir().body_owner_def_id(body_id);
+                self.rustc_cx.param_env(owner)
+            })
+    }
+
     #[must_use]
     fn alloc<T>(&self, t: T) -> &'ast T {
         self.storage.alloc(t)
@@ -1,4 +1,6 @@
+mod attr;
 mod expr;
+mod format_args;
 mod generic;
 mod item;
 mod pat;
@@ -0,0 +1,122 @@
+use marker_api::{
+    ast::{
+        AttrArgs, AttrDelim, AttrKind, AttrStyle, Attribute, DelimAttrArgs, DocCommentAttr, DocCommentKind, EqAttrArgs,
+        NormalAttr,
+    },
+    span::Ident,
+};
+use rustc_ast as ast;
+use rustc_hir as hir;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    #[must_use]
+    pub fn to_attrs(&self, hir_id: hir::HirId) -> &'ast [Attribute<'ast>] {
+        if let Some(attrs) = self.attrs.borrow().get(&hir_id) {
+            return attrs;
+        }
+
+        let rustc_attrs = self.rustc_cx.hir().attrs(hir_id);
+        let attrs = if rustc_attrs.is_empty() {
+            &[]
+        } else {
+            self.alloc_slice(rustc_attrs.iter().map(|attr| self.to_attr(attr)))
+        };
+        self.attrs.borrow_mut().insert(hir_id, attrs);
+        attrs
+    }
+
+    fn to_attr(&self, attr: &ast::Attribute) -> Attribute<'ast> {
+        let style = match attr.style {
+            ast::AttrStyle::Outer => AttrStyle::Outer,
+            ast::AttrStyle::Inner => AttrStyle::Inner,
+        };
+        let kind =
+            match &attr.kind {
+                ast::AttrKind::Normal(normal) => {
+                    let path =
+                        self.alloc_slice(
+                            normal.item.path.segments.iter().map(|seg| {
+                                Ident::new(self.to_symbol_id(seg.ident.name), self.to_span_id(seg.ident.span))
+                            }),
+                        );
+                    AttrKind::Normal(self.alloc(NormalAttr::new(path, self.to_attr_args(&normal.item.args))))
+                },
+                ast::AttrKind::DocComment(kind, text) => {
+                    let kind = match kind {
+                        ast::token::CommentKind::Line => DocCommentKind::Line,
+                        ast::token::CommentKind::Block => DocCommentKind::Block,
+                    };
+                    AttrKind::DocComment(self.alloc(DocCommentAttr::new(kind, self.to_symbol_id(*text))))
+                },
+            };
+
+        Attribute::builder()
+            .span(self.to_span_id(attr.span))
+            .style(style)
+            .kind(kind)
+            .from_cfg_attr(self.is_from_cfg_attr(attr))
+            .build()
+    }
+
+    fn to_attr_args(&self, args: &ast::AttrArgs) -> AttrArgs<'ast> {
+        match args {
+            ast::AttrArgs::Empty => AttrArgs::Empty,
+            ast::AttrArgs::Delimited(delim_args) => {
+                let delim = match delim_args.delim {
+                    ast::token::Delimiter::Parenthesis => AttrDelim::Paren,
+                    ast::token::Delimiter::Bracket => AttrDelim::Bracket,
+                    ast::token::Delimiter::Brace => AttrDelim::Brace,
+                    ast::token::Delimiter::Invisible => unreachable!("invisible delimiters never occur in attributes"),
+                };
+                // Prefer the written source, as the pretty printer normalizes
+                // the spacing, which would turn `clippy::all` into `clippy :: all`.
+                let inner_span = delim_args.dspan.open.between(delim_args.dspan.close);
+                let tokens = self
+                    .rustc_cx
+                    .sess
+                    .source_map()
+                    .span_to_snippet(inner_span)
+                    .ok()
+                    .filter(|_| !inner_span.from_expansion())
+                    .unwrap_or_else(|| rustc_ast_pretty::pprust::tts_to_string(&delim_args.tokens));
+                AttrArgs::Delimited(self.alloc(DelimAttrArgs::new(
+                    self.to_span_id(delim_args.dspan.entire()),
+                    delim,
+                    self.to_symbol_id(rustc_span::Symbol::intern(&tokens)),
+                )))
+            },
+            ast::AttrArgs::Eq(_, ast::AttrArgsEq::Hir(lit)) => {
+                let written = lit.as_token_lit().to_string();
+                let str_value = match lit.kind {
+                    ast::LitKind::Str(sym, _) => Some(self.to_symbol_id(sym)),
+                    _ => None,
+                };
+                AttrArgs::Eq(self.alloc(EqAttrArgs::new(
+                    self.to_span_id(lit.span),
+                    self.to_symbol_id(rustc_span::Symbol::intern(&written)),
+                    str_value,
+                )))
+            },
+            ast::AttrArgs::Eq(_, ast::AttrArgsEq::Ast(_)) => {
+                unreachable!("attribute values are lowered to literals in the HIR")
+            },
+        }
+    }
+
+    /// Attributes expanded from `#[cfg_attr(pred, attr)]` reuse the span of
+    /// the `attr` part. The source snippet therefore doesn't start with the `#`
+    /// of a normal attribute.
+    fn is_from_cfg_attr(&self, attr: &ast::Attribute) -> bool {
+        if attr.is_doc_comment() || attr.span.from_expansion() {
+            return false;
+        }
+
+        self.rustc_cx
+            .sess
+            .source_map()
+            .span_to_snippet(attr.span)
+            .map_or(false, |snippet| !snippet.starts_with('#'))
+    }
+}
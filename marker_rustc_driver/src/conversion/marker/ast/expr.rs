@@ -14,7 +14,7 @@ use rustc_hash::FxHashMap;
 use rustc_hir as hir;
 use std::str::FromStr;
 
-use crate::conversion::marker::MarkerConverterInner;
+use crate::{conversion::marker::MarkerConverterInner, lint_pass::FormatArgsCollector};
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     #[must_use]
@@ -83,6 +83,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     )))
                 }
             },
+            hir::ExprKind::Call(..) if FormatArgsCollector::is_format_args(expr.span) => {
+                ExprKind::FormatArgs(self.alloc(self.to_format_args_expr_from_desugar(data, expr)))
+            },
             hir::ExprKind::Call(operand, args) => match &operand.kind {
                 hir::ExprKind::Path(hir::QPath::LangItem(hir::LangItem::RangeInclusiveNew, _, _)) => {
                     ExprKind::Range(self.alloc({
@@ -409,6 +412,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             None => None,
         };
         MatchArm::new(
+            self.to_arm_id(arm.hir_id),
             self.to_span_id(arm.span),
             self.to_pat(arm.pat),
             guard,
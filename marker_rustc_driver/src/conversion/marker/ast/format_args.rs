@@ -0,0 +1,155 @@
+use marker_api::ast::{
+    CommonExprData, FormatAlignment, FormatArg, FormatArgKind, FormatArgPositionKind, FormatArgsExpr, FormatArgsPiece,
+    FormatCount, FormatDebugHex, FormatLitPiece, FormatPlaceholder, FormatSign, FormatTrait,
+};
+use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
+
+use crate::{conversion::marker::MarkerConverterInner, lint_pass::FormatArgsCollector};
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Rustc lowers [`format_args!`] into calls of internal functions. The
+    /// template and arguments are therefore taken from the AST representation,
+    /// collected by [`FormatArgsCollector`]. The argument expressions are
+    /// searched in the lowered HIR expression, by their span.
+    ///
+    /// The `format_expr` is the outermost expression of the desugaring. It has
+    /// the same span, as the [`rustc_ast::FormatArgs`] expression.
+    #[must_use]
+    pub fn to_format_args_expr_from_desugar(
+        &self,
+        data: CommonExprData<'ast>,
+        format_expr: &hir::Expr<'tcx>,
+    ) -> FormatArgsExpr<'ast> {
+        FormatArgsCollector::with_format_args(format_expr.span, |format_args| {
+            let template = self.alloc_slice(format_args.template.iter().map(|piece| match piece {
+                rustc_ast::FormatArgsPiece::Literal(sym) => {
+                    FormatArgsPiece::Lit(self.alloc(FormatLitPiece::new(self.to_symbol_id(*sym))))
+                },
+                rustc_ast::FormatArgsPiece::Placeholder(placeholder) => {
+                    FormatArgsPiece::Placeholder(self.alloc(self.to_format_placeholder(placeholder)))
+                },
+            }));
+            let args = self.alloc_slice(
+                format_args
+                    .arguments
+                    .all_args()
+                    .iter()
+                    .map(|arg| self.to_format_arg(format_expr, arg)),
+            );
+
+            FormatArgsExpr::builder()
+                .data(data)
+                .template(template)
+                .args(args)
+                .build()
+        })
+        .expect("the format args have been checked by the caller")
+    }
+
+    #[must_use]
+    fn to_format_placeholder(&self, placeholder: &rustc_ast::FormatPlaceholder) -> FormatPlaceholder<'ast> {
+        let options = &placeholder.format_options;
+        FormatPlaceholder::builder()
+            .span(placeholder.span.map(|span| self.to_span_id(span)))
+            .arg_index(self.to_format_arg_index(&placeholder.argument))
+            .arg_kind(match placeholder.argument.kind {
+                rustc_ast::FormatArgPositionKind::Implicit => FormatArgPositionKind::Implicit,
+                rustc_ast::FormatArgPositionKind::Number => FormatArgPositionKind::Number,
+                rustc_ast::FormatArgPositionKind::Named => FormatArgPositionKind::Named,
+            })
+            .arg_span(placeholder.argument.span.map(|span| self.to_span_id(span)))
+            .format_trait(self.to_format_trait(placeholder.format_trait))
+            .width(options.width.as_ref().map(|count| self.to_format_count(count)))
+            .precision(options.precision.as_ref().map(|count| self.to_format_count(count)))
+            .alignment(options.alignment.map(|alignment| match alignment {
+                rustc_ast::FormatAlignment::Left => FormatAlignment::Left,
+                rustc_ast::FormatAlignment::Right => FormatAlignment::Right,
+                rustc_ast::FormatAlignment::Center => FormatAlignment::Center,
+            }))
+            .fill(options.fill)
+            .sign(options.sign.map(|sign| match sign {
+                rustc_ast::FormatSign::Plus => FormatSign::Plus,
+                rustc_ast::FormatSign::Minus => FormatSign::Minus,
+            }))
+            .is_alternate(options.alternate)
+            .is_zero_padded(options.zero_pad)
+            .debug_hex(options.debug_hex.map(|debug_hex| match debug_hex {
+                rustc_ast::FormatDebugHex::Lower => FormatDebugHex::Lower,
+                rustc_ast::FormatDebugHex::Upper => FormatDebugHex::Upper,
+            }))
+            .build()
+    }
+
+    #[must_use]
+    fn to_format_arg_index(&self, position: &rustc_ast::FormatArgPosition) -> usize {
+        position.index.expect("rustc would have errored for an invalid index")
+    }
+
+    #[must_use]
+    fn to_format_trait(&self, format_trait: rustc_ast::FormatTrait) -> FormatTrait {
+        match format_trait {
+            rustc_ast::FormatTrait::Display => FormatTrait::Display,
+            rustc_ast::FormatTrait::Debug => FormatTrait::Debug,
+            rustc_ast::FormatTrait::LowerExp => FormatTrait::LowerExp,
+            rustc_ast::FormatTrait::UpperExp => FormatTrait::UpperExp,
+            rustc_ast::FormatTrait::Octal => FormatTrait::Octal,
+            rustc_ast::FormatTrait::Pointer => FormatTrait::Pointer,
+            rustc_ast::FormatTrait::Binary => FormatTrait::Binary,
+            rustc_ast::FormatTrait::LowerHex => FormatTrait::LowerHex,
+            rustc_ast::FormatTrait::UpperHex => FormatTrait::UpperHex,
+        }
+    }
+
+    #[must_use]
+    fn to_format_count(&self, count: &rustc_ast::FormatCount) -> FormatCount {
+        match count {
+            rustc_ast::FormatCount::Literal(value) => FormatCount::Lit(*value),
+            rustc_ast::FormatCount::Argument(position) => FormatCount::Arg(self.to_format_arg_index(position)),
+        }
+    }
+
+    #[must_use]
+    fn to_format_arg(&self, format_expr: &hir::Expr<'tcx>, arg: &rustc_ast::FormatArgument) -> FormatArg<'ast> {
+        let (kind, ident) = match arg.kind {
+            rustc_ast::FormatArgumentKind::Normal => (FormatArgKind::Normal, None),
+            rustc_ast::FormatArgumentKind::Named(ident) => (FormatArgKind::Named, Some(self.to_ident(ident))),
+            rustc_ast::FormatArgumentKind::Captured(ident) => (FormatArgKind::Captured, Some(self.to_ident(ident))),
+        };
+
+        let mut finder = FormatArgFinder {
+            span: arg.expr.span,
+            found: None,
+        };
+        finder.visit_expr(format_expr);
+        let expr = finder
+            .found
+            .map(|hir_id| self.to_expr(self.rustc_cx.hir().expect_expr(hir_id)));
+
+        FormatArg::new(self.to_span_id(arg.expr.span), kind, ident, expr)
+    }
+}
+
+/// Searches the lowered HIR expression of an argument, by the span of the
+/// AST expression. The argument might not be found, if rustc inlined it into
+/// the template during the lowering.
+struct FormatArgFinder {
+    span: rustc_span::Span,
+    found: Option<hir::HirId>,
+}
+
+impl<'v> Visitor<'v> for FormatArgFinder {
+    fn visit_expr(&mut self, expr: &'v hir::Expr<'v>) {
+        if self.found.is_some() {
+            return;
+        }
+
+        // The spans of HIR nodes can have a parent, if incremental compilation
+        // is enabled. The parent has to be removed, to match the AST span.
+        if expr.span.with_parent(None) == self.span {
+            self.found = Some(expr.hir_id);
+        } else {
+            intravisit::walk_expr(self, expr);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use marker_api::ast::{
-    BindingArg, ConstArg, ConstParam, GenericArgKind, GenericArgs, GenericParamKind, GenericParams, Lifetime,
-    LifetimeArg, LifetimeClause, LifetimeKind, LifetimeParam, TraitBound, TraitRef, TyArg, TyClause, TyParam,
+    AstPathTarget, BindingArg, ConstArg, ConstParam, GenericArgKind, GenericArgs, GenericParamKind, GenericParams,
+    Lifetime, LifetimeArg, LifetimeClause, LifetimeKind, LifetimeParam, TraitBound, TraitRef, TyArg, TyClause, TyParam,
     TyParamBound, WhereClauseKind,
 };
 use rustc_hir as hir;
@@ -26,10 +26,6 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
-    pub fn to_syn_generic_args_from_path(&self, rust_path: &rustc_hir::Path<'tcx>) -> GenericArgs<'ast> {
-        self.to_syn_generic_args(rust_path.segments.last().and_then(|s| s.args))
-    }
-
     #[must_use]
     pub fn to_syn_generic_args(&self, rustc_args: Option<&hir::GenericArgs<'tcx>>) -> GenericArgs<'ast> {
         let Some(rustc_args) = rustc_args else {
@@ -168,27 +164,29 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .iter()
             .filter_map(|bound| match bound {
                 hir::GenericBound::Trait(trait_ref, modifier) => {
-                    Some(TyParamBound::TraitBound(self.alloc(TraitBound::new(
+                    let (trait_ref, path) = self.to_trait_ref(&trait_ref.trait_ref);
+                    let bound = self.alloc(TraitBound::new(
                         !matches!(modifier, hir::TraitBoundModifier::None),
-                        self.to_trait_ref(&trait_ref.trait_ref),
+                        trait_ref,
                         self.to_span_id(bound.span()),
-                    ))))
+                    ));
+                    self.register_trait_ref_path(bound.trait_ref(), path);
+                    Some(TyParamBound::TraitBound(bound))
                 },
-                hir::GenericBound::LangItemTrait(lang_item, span, _, rustc_args) => Some(TyParamBound::TraitBound(
-                    self.alloc(TraitBound::new(
+                hir::GenericBound::LangItemTrait(lang_item, span, _, rustc_args) => {
+                    let generics = self.to_syn_generic_args(Some(rustc_args));
+                    let path = self.to_lang_item_qpath(*lang_item, *span, generics.clone());
+                    let AstPathTarget::Item(trait_id) = path.resolve() else {
+                        unreachable!("lang item paths always target items")
+                    };
+                    let bound = self.alloc(TraitBound::new(
                         false,
-                        TraitRef::new(
-                            self.to_item_id(
-                                self.rustc_cx
-                                    .get_lang_items(())
-                                    .get(*lang_item)
-                                    .expect("the lang item is used and should therefore be loaded"),
-                            ),
-                            self.to_syn_generic_args(Some(rustc_args)),
-                        ),
+                        TraitRef::new(trait_id, generics),
                         self.to_span_id(*span),
-                    )),
-                )),
+                    ));
+                    self.register_trait_ref_path(bound.trait_ref(), path);
+                    Some(TyParamBound::TraitBound(bound))
+                },
                 hir::GenericBound::Outlives(rust_lt) => self
                     .to_lifetime(rust_lt)
                     .map(|api_lt| TyParamBound::Lifetime(self.alloc(api_lt))),
@@ -204,11 +202,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         rust_lt: &rustc_hir::Lifetime,
     ) -> &'ast [TyParamBound<'ast>] {
         let traits = rust_bounds.iter().map(|rust_trait_ref| {
-            TyParamBound::TraitBound(self.storage.alloc(TraitBound::new(
-                false,
-                self.to_trait_ref(&rust_trait_ref.trait_ref),
-                self.to_span_id(rust_trait_ref.span),
-            )))
+            let (trait_ref, path) = self.to_trait_ref(&rust_trait_ref.trait_ref);
+            let bound = self
+                .storage
+                .alloc(TraitBound::new(false, trait_ref, self.to_span_id(rust_trait_ref.span)));
+            self.register_trait_ref_path(bound.trait_ref(), path);
+            TyParamBound::TraitBound(bound)
         });
 
         if let Some(lt) = self.to_lifetime(rust_lt) {
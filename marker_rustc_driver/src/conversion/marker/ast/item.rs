@@ -157,17 +157,26 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     data,
                     Some(self.to_symbol_id(rustc_span::sym::trait_alias)),
                 ))),
-                hir::ItemKind::Impl(imp) => ItemKind::Impl(self.alloc({
-                    ImplItem::new(
+                hir::ItemKind::Impl(imp) => {
+                    let (trait_ref, path) = imp
+                        .of_trait
+                        .as_ref()
+                        .map(|trait_ref| self.to_trait_ref(trait_ref))
+                        .unzip();
+                    let item = self.alloc(ImplItem::new(
                         data,
                         matches!(imp.unsafety, hir::Unsafety::Unsafe),
                         matches!(imp.polarity, rustc_ast::ImplPolarity::Positive),
-                        imp.of_trait.as_ref().map(|trait_ref| self.to_trait_ref(trait_ref)),
+                        trait_ref,
                         self.to_syn_generic_params(imp.generics),
                         self.to_syn_ty(imp.self_ty),
                         self.to_assoc_items_from_impl(imp.items),
-                    )
-                })),
+                    ));
+                    if let (Some(trait_ref), Some(path)) = (item.trait_ref(), path) {
+                        self.register_trait_ref_path(trait_ref, path);
+                    }
+                    ItemKind::Impl(item)
+                },
             };
 
         self.items.borrow_mut().insert(id, item);
@@ -153,6 +153,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         transmute_id!(HirIdLayout as StmtId = id.into())
     }
 
+    #[must_use]
+    pub fn to_arm_id(&self, id: impl Into<HirIdLayout>) -> ArmId {
+        transmute_id!(HirIdLayout as ArmId = id.into())
+    }
+
     #[must_use]
     pub fn to_span_src_id(&self, id: rustc_span::SyntaxContext) -> SpanSrcId {
         // FIXME(xFrednet): This conversion is theoretically unsound, since
@@ -270,31 +275,44 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
                 AstQPath::new(None, Some(marker_ty), path, res)
             },
-            // I recommend reading the comment of `Self::lang_item_map` for context
-            hir::QPath::LangItem(item, span, _) => {
-                let id = self
-                    .rustc_cx
-                    .lang_items()
-                    .get(*item)
-                    .expect("if the lang item is used, it also has to be in the map");
-                AstQPath::new(
-                    None,
-                    None,
-                    AstPath::new(self.alloc_slice([AstPathSegment::new(
-                        Ident::new(
-                            *self.lang_item_map.borrow().get(item).unwrap_or_else(|| {
-                                panic!("`&MarkerConverterInner::lang_item_map` doesn't contain `{item:?}`")
-                            }),
-                            self.to_span_id(*span),
-                        ),
-                        GenericArgs::new(&[]),
-                    )])),
-                    AstPathTarget::Item(self.to_item_id(id)),
-                )
-            },
+            hir::QPath::LangItem(item, span, _) => self.to_lang_item_qpath(*item, *span, GenericArgs::new(&[])),
         }
     }
 
+    /// Creates a path with a single segment for the given lang item. Lang items,
+    /// which are not in `Self::lang_item_map`, use the name of the item. I
+    /// recommend reading the comment of `Self::lang_item_map` for context.
+    pub fn to_lang_item_qpath(
+        &self,
+        item: hir::LangItem,
+        span: rustc_span::Span,
+        generics: GenericArgs<'ast>,
+    ) -> AstQPath<'ast> {
+        let id = self
+            .rustc_cx
+            .lang_items()
+            .get(item)
+            .expect("if the lang item is used, it also has to be in the map");
+        AstQPath::new(
+            None,
+            None,
+            AstPath::new(
+                self.alloc_slice([AstPathSegment::new(
+                    Ident::new(
+                        self.lang_item_map
+                            .borrow()
+                            .get(&item)
+                            .copied()
+                            .unwrap_or_else(|| self.to_symbol_id(self.rustc_cx.item_name(id))),
+                        self.to_span_id(span),
+                    ),
+                    generics,
+                )]),
+            ),
+            AstPathTarget::Item(self.to_item_id(id)),
+        )
+    }
+
     pub fn to_qpath_from_expr(&self, qpath: &hir::QPath<'tcx>, expr: &hir::Expr<'_>) -> AstQPath<'ast> {
         self.to_qpath(qpath, || self.resolve_qpath_in_body(qpath, expr.hir_id))
     }
@@ -408,13 +426,31 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         AstPathSegment::new(self.to_ident(segment.ident), self.to_syn_generic_args(segment.args))
     }
 
-    pub fn to_trait_ref(&self, trait_ref: &rustc_hir::TraitRef<'tcx>) -> TraitRef<'ast> {
+    /// Converts the trait reference. The path of the trait is returned
+    /// separately, since it's not stored in the [`TraitRef`]. It has to be
+    /// registered via [`Self::register_trait_ref_path`], once the [`TraitRef`]
+    /// has been allocated.
+    pub fn to_trait_ref(&self, trait_ref: &rustc_hir::TraitRef<'tcx>) -> (TraitRef<'ast>, AstQPath<'ast>) {
         let trait_id = match trait_ref.path.res {
             hir::def::Res::Def(hir::def::DefKind::Trait | hir::def::DefKind::TraitAlias, rustc_id) => {
                 self.to_item_id(rustc_id)
             },
             _ => unreachable!("reached `PolyTraitRef` which can't be translated {trait_ref:#?}"),
         };
-        TraitRef::new(trait_id, self.to_syn_generic_args_from_path(trait_ref.path))
+        let path = AstQPath::new(
+            None,
+            None,
+            self.to_path(trait_ref.path),
+            self.to_path_target(&trait_ref.path.res),
+        );
+        (TraitRef::new(trait_id, path.generics().clone()), path)
+    }
+
+    /// Stores the path of an allocated [`TraitRef`]. The path is requested by
+    /// the [`TraitRef`] on demand, identified by its address.
+    pub fn register_trait_ref_path(&self, trait_ref: &'ast TraitRef<'ast>, path: AstQPath<'ast>) {
+        self.trait_ref_paths
+            .borrow_mut()
+            .insert(trait_ref as *const TraitRef<'ast>, self.alloc(path));
     }
 }
@@ -1,3 +1,7 @@
+mod adjustment;
+mod consts;
 mod generic;
 mod item;
+mod layout;
+mod local_usage;
 mod ty;
@@ -0,0 +1,44 @@
+use marker_api::sem::{Adjustment, AdjustmentKind};
+use rustc_middle as mid;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    #[must_use]
+    pub fn to_adjustments(&self, adjustments: &[mid::ty::adjustment::Adjustment<'tcx>]) -> &'ast [Adjustment<'ast>] {
+        if adjustments.is_empty() {
+            return &[];
+        }
+
+        self.alloc_slice(
+            adjustments
+                .iter()
+                .map(|adj| Adjustment::new(self.to_adjustment_kind(&adj.kind), self.to_sem_ty(adj.target))),
+        )
+    }
+
+    pub fn to_adjustment_kind(&self, kind: &mid::ty::adjustment::Adjust<'tcx>) -> AdjustmentKind {
+        use mid::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability, PointerCoercion};
+
+        match kind {
+            Adjust::NeverToAny => AdjustmentKind::NeverToAny,
+            Adjust::Deref(None) => AdjustmentKind::Deref,
+            Adjust::Deref(Some(overloaded)) => AdjustmentKind::OverloadedDeref(self.to_mutability(overloaded.mutbl)),
+            Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not)) => {
+                AdjustmentKind::Borrow(self.to_mutability(rustc_ast::Mutability::Not))
+            },
+            Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. })) => {
+                AdjustmentKind::Borrow(self.to_mutability(rustc_ast::Mutability::Mut))
+            },
+            Adjust::Borrow(AutoBorrow::RawPtr(mutbl)) => AdjustmentKind::RawBorrow(self.to_mutability(*mutbl)),
+            Adjust::Pointer(PointerCoercion::ReifyFnPointer) => AdjustmentKind::ReifyFnPointer,
+            Adjust::Pointer(PointerCoercion::UnsafeFnPointer) => AdjustmentKind::UnsafeFnPointer,
+            Adjust::Pointer(PointerCoercion::ClosureFnPointer(_)) => AdjustmentKind::ClosureFnPointer,
+            Adjust::Pointer(PointerCoercion::MutToConstPointer) => AdjustmentKind::MutToConstPointer,
+            Adjust::Pointer(PointerCoercion::ArrayToPointer) => AdjustmentKind::ArrayToPointer,
+            // `dyn*` is unstable and the closest stable equivalent is an unsizing
+            // coercion to a trait object.
+            Adjust::Pointer(PointerCoercion::Unsize) | Adjust::DynStar => AdjustmentKind::Unsize,
+        }
+    }
+}
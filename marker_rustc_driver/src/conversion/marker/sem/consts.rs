@@ -0,0 +1,429 @@
+use std::str::FromStr;
+
+use marker_api::{
+    common::NumKind,
+    sem::{AggregateValue, ConstValue, FloatValue, IntValue, StrValue},
+};
+use rustc_hir as hir;
+use rustc_middle as mid;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_target::abi::Size;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    #[must_use]
+    pub fn to_const_value(&self, value: mid::ty::Const<'tcx>) -> ConstValue<'ast> {
+        match value.kind() {
+            mid::ty::ConstKind::Param(param) => {
+                // Generic parameters are only converted inside bodies. See the
+                // conversion of `TyKind::Param` for more information.
+                let Some(body_id) = *self.rustc_body.borrow() else {
+                    return ConstValue::Unknown;
+                };
+                let owner = self.rustc_cx.hir().body_owner_def_id(body_id);
+                let generic_info = self
+                    .rustc_cx
+                    .generics_of(owner.to_def_id())
+                    .const_param(&param, self.rustc_cx);
+                ConstValue::Param(self.to_generic_id(generic_info.def_id))
+            },
+            mid::ty::ConstKind::Value(valtree) => self.to_const_value_from_valtree(valtree, value.ty()),
+            mid::ty::ConstKind::Unevaluated(_) if !value.has_escaping_bound_vars() => value
+                .eval(self.rustc_cx, self.rustc_param_env(), None)
+                .map_or(ConstValue::Unknown, |valtree| {
+                    self.to_const_value_from_valtree(valtree, value.ty())
+                }),
+            _ => ConstValue::Unknown,
+        }
+    }
+
+    fn to_const_value_from_valtree(&self, valtree: mid::ty::ValTree<'tcx>, ty: mid::ty::Ty<'tcx>) -> ConstValue<'ast> {
+        match valtree {
+            mid::ty::ValTree::Leaf(int) => self.to_const_value_from_scalar(int, ty),
+            // Aggregates are only allowed as const generic arguments with
+            // unstable features, they are therefore not supported for now.
+            mid::ty::ValTree::Branch(_) => ConstValue::Unknown,
+        }
+    }
+
+    fn to_const_value_from_scalar(&self, int: mid::ty::ScalarInt, ty: mid::ty::Ty<'tcx>) -> ConstValue<'ast> {
+        let bits = int.assert_bits(int.size());
+        match ty.kind() {
+            mid::ty::TyKind::Bool => int.try_to_bool().map_or(ConstValue::Unknown, ConstValue::Bool),
+            mid::ty::TyKind::Char => u32::try_from(bits)
+                .ok()
+                .and_then(char::from_u32)
+                .map_or(ConstValue::Unknown, ConstValue::Char),
+            mid::ty::TyKind::Int(_) => ConstValue::Int(IntValue::new(
+                int.size().sign_extend(bits),
+                self.to_num_kind(ty).expect("checked type"),
+            )),
+            mid::ty::TyKind::Uint(_) => {
+                ConstValue::Int(IntValue::new(bits, self.to_num_kind(ty).expect("checked type")))
+            },
+            mid::ty::TyKind::Float(mid::ty::FloatTy::F32) => u32::try_from(bits).map_or(ConstValue::Unknown, |bits| {
+                ConstValue::Float(FloatValue::new(f64::from(f32::from_bits(bits)), NumKind::F32))
+            }),
+            mid::ty::TyKind::Float(mid::ty::FloatTy::F64) => u64::try_from(bits).map_or(ConstValue::Unknown, |bits| {
+                ConstValue::Float(FloatValue::new(f64::from_bits(bits), NumKind::F64))
+            }),
+            _ => ConstValue::Unknown,
+        }
+    }
+
+    fn to_const_value_from_mir(&self, value: mid::mir::ConstValue<'tcx>, ty: mid::ty::Ty<'tcx>) -> ConstValue<'ast> {
+        let tcx = self.rustc_cx;
+        match ty.kind() {
+            mid::ty::TyKind::Bool
+            | mid::ty::TyKind::Char
+            | mid::ty::TyKind::Int(_)
+            | mid::ty::TyKind::Uint(_)
+            | mid::ty::TyKind::Float(_) => value
+                .try_to_scalar_int()
+                .map_or(ConstValue::Unknown, |int| self.to_const_value_from_scalar(int, ty)),
+            mid::ty::TyKind::Ref(_, inner, _) if inner.is_str() => value
+                .try_get_slice_bytes_for_diagnostics(tcx)
+                .and_then(|bytes| std::str::from_utf8(bytes).ok())
+                .map_or(ConstValue::Unknown, |text| {
+                    ConstValue::Str(StrValue::new(self.storage.alloc_str(text)))
+                }),
+            mid::ty::TyKind::Adt(adt, _) if adt.is_union() => ConstValue::Unknown,
+            mid::ty::TyKind::Adt(..) | mid::ty::TyKind::Tuple(_) | mid::ty::TyKind::Array(..) => {
+                let Some(destructured) = tcx.try_destructure_mir_constant_for_user_output(value, ty) else {
+                    return ConstValue::Unknown;
+                };
+                let variant = match (ty.kind(), destructured.variant) {
+                    (mid::ty::TyKind::Adt(adt, _), Some(index)) if adt.is_enum() => {
+                        Some(self.to_variant_id(adt.variant(index).def_id))
+                    },
+                    _ => None,
+                };
+                let fields = self.alloc_slice(
+                    destructured
+                        .fields
+                        .iter()
+                        .map(|(value, ty)| self.to_const_value_from_mir(*value, *ty)),
+                );
+                ConstValue::Aggregate(self.alloc(AggregateValue::new(variant, fields)))
+            },
+            _ => ConstValue::Unknown,
+        }
+    }
+
+    fn to_num_kind(&self, ty: mid::ty::Ty<'tcx>) -> Option<NumKind> {
+        let kind = match ty.kind() {
+            mid::ty::TyKind::Int(int_ty) => match int_ty {
+                mid::ty::IntTy::Isize => NumKind::Isize,
+                mid::ty::IntTy::I8 => NumKind::I8,
+                mid::ty::IntTy::I16 => NumKind::I16,
+                mid::ty::IntTy::I32 => NumKind::I32,
+                mid::ty::IntTy::I64 => NumKind::I64,
+                mid::ty::IntTy::I128 => NumKind::I128,
+            },
+            mid::ty::TyKind::Uint(uint_ty) => match uint_ty {
+                mid::ty::UintTy::Usize => NumKind::Usize,
+                mid::ty::UintTy::U8 => NumKind::U8,
+                mid::ty::UintTy::U16 => NumKind::U16,
+                mid::ty::UintTy::U32 => NumKind::U32,
+                mid::ty::UintTy::U64 => NumKind::U64,
+                mid::ty::UintTy::U128 => NumKind::U128,
+            },
+            mid::ty::TyKind::Float(float_ty) => match float_ty {
+                mid::ty::FloatTy::F32 => NumKind::F32,
+                mid::ty::FloatTy::F64 => NumKind::F64,
+            },
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// Constant evaluation of expressions
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Tries to evaluate the given expression. This only supports a subset of
+    /// expressions, that can be evaluated without running the MIR interpreter.
+    /// Constants are evaluated by rustc, which also checks their values.
+    #[must_use]
+    pub fn eval_const_expr(&self, expr: &hir::Expr<'tcx>) -> Option<ConstValue<'ast>> {
+        match expr.kind {
+            hir::ExprKind::Lit(lit) => self.eval_lit(&lit.node, self.rustc_ty_check().expr_ty(expr)),
+            hir::ExprKind::Path(ref qpath) => self.eval_path(expr, qpath),
+            hir::ExprKind::Unary(op, operand) => {
+                // Negative literals, like `-128_i8`, have to be evaluated together,
+                // as the literal alone would be out of range.
+                if op == hir::UnOp::Neg
+                    && let hir::ExprKind::Lit(lit) = operand.kind
+                    && let rustc_ast::LitKind::Int(value, _) = lit.node
+                {
+                    let kind = self.to_num_kind(self.rustc_ty_check().expr_ty(expr))?;
+                    let value = i128::try_from(value).ok()?.checked_neg()?;
+                    return self.to_signed_int_value(value, kind).map(ConstValue::Int);
+                }
+
+                self.eval_unary(op, self.eval_const_expr(operand)?)
+            },
+            hir::ExprKind::Binary(op, lhs, rhs) => {
+                self.eval_binary(op.node, self.eval_const_expr(lhs)?, self.eval_const_expr(rhs)?)
+            },
+            hir::ExprKind::Tup(exprs) | hir::ExprKind::Array(exprs) => {
+                let fields = exprs
+                    .iter()
+                    .map(|expr| self.eval_const_expr(expr))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(ConstValue::Aggregate(
+                    self.alloc(AggregateValue::new(None, self.alloc_slice(fields))),
+                ))
+            },
+            hir::ExprKind::Block(block, None)
+                if block.stmts.is_empty() && matches!(block.rules, hir::BlockCheckMode::DefaultBlock) =>
+            {
+                self.eval_const_expr(block.expr?)
+            },
+            hir::ExprKind::DropTemps(inner) => self.eval_const_expr(inner),
+            _ => None,
+        }
+    }
+
+    fn eval_lit(&self, lit: &rustc_ast::LitKind, ty: mid::ty::Ty<'tcx>) -> Option<ConstValue<'ast>> {
+        let value = match lit {
+            rustc_ast::LitKind::Str(sym, _) => ConstValue::Str(StrValue::new(self.storage.alloc_str(sym.as_str()))),
+            rustc_ast::LitKind::Byte(value) => ConstValue::Int(IntValue::new(u128::from(*value), NumKind::U8)),
+            rustc_ast::LitKind::Char(value) => ConstValue::Char(*value),
+            rustc_ast::LitKind::Bool(value) => ConstValue::Bool(*value),
+            rustc_ast::LitKind::Int(value, _) => {
+                let kind = self.to_num_kind(ty)?;
+                let value = if kind.is_signed() {
+                    self.to_signed_int_value(i128::try_from(*value).ok()?, kind)?
+                } else {
+                    self.to_unsigned_int_value(*value, kind)?
+                };
+                ConstValue::Int(value)
+            },
+            rustc_ast::LitKind::Float(sym, _) => {
+                let kind = self.to_num_kind(ty)?;
+                // Digit separators, like in `1_000.5`, are rejected by `from_str`
+                let lit = sym.as_str().replace('_', "");
+                let value = if kind == NumKind::F32 {
+                    f64::from(f32::from_str(&lit).ok()?)
+                } else {
+                    f64::from_str(&lit).ok()?
+                };
+                ConstValue::Float(FloatValue::new(value, kind))
+            },
+            rustc_ast::LitKind::ByteStr(..) | rustc_ast::LitKind::CStr(..) | rustc_ast::LitKind::Err => return None,
+        };
+        Some(value)
+    }
+
+    fn eval_path(&self, expr: &hir::Expr<'tcx>, qpath: &hir::QPath<'tcx>) -> Option<ConstValue<'ast>> {
+        let tcx = self.rustc_cx;
+        let ty_check = self.rustc_ty_check();
+        match ty_check.qpath_res(qpath, expr.hir_id) {
+            hir::def::Res::Def(hir::def::DefKind::Const | hir::def::DefKind::AssocConst, def_id) => {
+                let args = tcx.erase_regions(ty_check.node_args(expr.hir_id));
+                let param_env = self.rustc_param_env().with_reveal_all_normalized(tcx);
+                let value = tcx
+                    .const_eval_resolve(param_env, mid::mir::UnevaluatedConst::new(def_id, args), None)
+                    .ok()?;
+                Some(self.to_const_value_from_mir(value, tcx.erase_regions(ty_check.expr_ty(expr))))
+            },
+            hir::def::Res::Def(hir::def::DefKind::ConstParam, def_id) => {
+                Some(ConstValue::Param(self.to_generic_id(def_id)))
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_unary(&self, op: hir::UnOp, value: ConstValue<'ast>) -> Option<ConstValue<'ast>> {
+        #[expect(non_exhaustive_omitted_patterns)]
+        match (op, value) {
+            (hir::UnOp::Neg, ConstValue::Int(int)) if int.kind().is_signed() => Some(ConstValue::Int(
+                self.to_signed_int_value(int.to_i128()?.checked_neg()?, int.kind())?,
+            )),
+            (hir::UnOp::Neg, ConstValue::Float(float)) => {
+                Some(ConstValue::Float(FloatValue::new(-float.value(), float.kind())))
+            },
+            (hir::UnOp::Not, ConstValue::Bool(value)) => Some(ConstValue::Bool(!value)),
+            (hir::UnOp::Not, ConstValue::Int(int)) => {
+                let bits = if int.kind().is_signed() {
+                    i128_to_bits(!int.to_i128()?)
+                } else {
+                    !int.to_u128()?
+                };
+                Some(ConstValue::Int(self.to_int_value_truncated(bits, int.kind())))
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_binary(
+        &self,
+        op: hir::BinOpKind,
+        lhs: ConstValue<'ast>,
+        rhs: ConstValue<'ast>,
+    ) -> Option<ConstValue<'ast>> {
+        #[expect(non_exhaustive_omitted_patterns)]
+        match (lhs, rhs) {
+            (ConstValue::Int(lhs), ConstValue::Int(rhs)) => self.eval_int_binary(op, lhs, rhs),
+            (ConstValue::Float(lhs), ConstValue::Float(rhs)) if lhs.kind() == rhs.kind() => {
+                eval_float_binary(op, lhs, rhs)
+            },
+            (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => {
+                let value = match op {
+                    hir::BinOpKind::And | hir::BinOpKind::BitAnd => lhs & rhs,
+                    hir::BinOpKind::Or | hir::BinOpKind::BitOr => lhs | rhs,
+                    hir::BinOpKind::BitXor | hir::BinOpKind::Ne => lhs ^ rhs,
+                    hir::BinOpKind::Eq => lhs == rhs,
+                    _ => return None,
+                };
+                Some(ConstValue::Bool(value))
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_int_binary(&self, op: hir::BinOpKind, lhs: IntValue, rhs: IntValue) -> Option<ConstValue<'ast>> {
+        let kind = lhs.kind();
+        let size = self.int_size(kind);
+
+        // The operands of shifts can have different types
+        if matches!(op, hir::BinOpKind::Shl | hir::BinOpKind::Shr) {
+            let amount = u32::try_from(rhs.to_u128()?).ok()?;
+            if u64::from(amount) >= size.bits() {
+                return None;
+            }
+            let bits = match (op, kind.is_signed()) {
+                (hir::BinOpKind::Shl, true) => i128_to_bits(lhs.to_i128()?) << amount,
+                (hir::BinOpKind::Shl, false) => lhs.to_u128()? << amount,
+                (_, true) => i128_to_bits(lhs.to_i128()? >> amount),
+                (_, false) => lhs.to_u128()? >> amount,
+            };
+            return Some(ConstValue::Int(self.to_int_value_truncated(bits, kind)));
+        }
+
+        if rhs.kind() != kind {
+            return None;
+        }
+
+        if kind.is_signed() {
+            let (lhs, rhs) = (lhs.to_i128()?, rhs.to_i128()?);
+            let value = match op {
+                hir::BinOpKind::Add => lhs.checked_add(rhs)?,
+                hir::BinOpKind::Sub => lhs.checked_sub(rhs)?,
+                hir::BinOpKind::Mul => lhs.checked_mul(rhs)?,
+                hir::BinOpKind::Div => lhs.checked_div(rhs)?,
+                hir::BinOpKind::Rem => {
+                    // `MIN % -1` overflows, like `MIN / -1`, even if the result fits
+                    self.to_signed_int_value(lhs.checked_div(rhs)?, kind)?;
+                    lhs.checked_rem(rhs)?
+                },
+                hir::BinOpKind::BitAnd => lhs & rhs,
+                hir::BinOpKind::BitOr => lhs | rhs,
+                hir::BinOpKind::BitXor => lhs ^ rhs,
+                _ => return eval_cmp(op, lhs.cmp(&rhs)),
+            };
+            self.to_signed_int_value(value, kind).map(ConstValue::Int)
+        } else {
+            let (lhs, rhs) = (lhs.to_u128()?, rhs.to_u128()?);
+            let value = match op {
+                hir::BinOpKind::Add => lhs.checked_add(rhs)?,
+                hir::BinOpKind::Sub => lhs.checked_sub(rhs)?,
+                hir::BinOpKind::Mul => lhs.checked_mul(rhs)?,
+                hir::BinOpKind::Div => lhs.checked_div(rhs)?,
+                hir::BinOpKind::Rem => lhs.checked_rem(rhs)?,
+                hir::BinOpKind::BitAnd => lhs & rhs,
+                hir::BinOpKind::BitOr => lhs | rhs,
+                hir::BinOpKind::BitXor => lhs ^ rhs,
+                _ => return eval_cmp(op, lhs.cmp(&rhs)),
+            };
+            self.to_unsigned_int_value(value, kind).map(ConstValue::Int)
+        }
+    }
+
+    /// Creates an [`IntValue`] of the given signed kind, if the value is in the
+    /// range of the type. This returns `None` for overflowing values.
+    fn to_signed_int_value(&self, value: i128, kind: NumKind) -> Option<IntValue> {
+        let size = self.int_size(kind);
+        let bits = i128_to_bits(value);
+        (size.sign_extend(size.truncate(bits)) == bits).then(|| IntValue::new(bits, kind))
+    }
+
+    /// Creates an [`IntValue`] of the given unsigned kind, if the value is in
+    /// the range of the type. This returns `None` for overflowing values.
+    fn to_unsigned_int_value(&self, value: u128, kind: NumKind) -> Option<IntValue> {
+        let size = self.int_size(kind);
+        (size.truncate(value) == value).then(|| IntValue::new(value, kind))
+    }
+
+    /// Creates an [`IntValue`] from the truncated bits of the value. Signed
+    /// values are sign extended.
+    fn to_int_value_truncated(&self, bits: u128, kind: NumKind) -> IntValue {
+        let size = self.int_size(kind);
+        if kind.is_signed() {
+            IntValue::new(size.sign_extend(size.truncate(bits)), kind)
+        } else {
+            IntValue::new(size.truncate(bits), kind)
+        }
+    }
+
+    fn int_size(&self, kind: NumKind) -> Size {
+        match kind {
+            NumKind::I8 | NumKind::U8 => Size::from_bits(8),
+            NumKind::I16 | NumKind::U16 => Size::from_bits(16),
+            NumKind::I32 | NumKind::U32 | NumKind::F32 => Size::from_bits(32),
+            NumKind::I64 | NumKind::U64 | NumKind::F64 => Size::from_bits(64),
+            NumKind::I128 | NumKind::U128 => Size::from_bits(128),
+            NumKind::Isize | NumKind::Usize => self.rustc_cx.data_layout.pointer_size,
+            _ => unreachable!("all numeric kinds are covered"),
+        }
+    }
+}
+
+/// Returns the two's complement representation of the value.
+#[allow(clippy::cast_sign_loss)]
+fn i128_to_bits(value: i128) -> u128 {
+    value as u128
+}
+
+fn eval_float_binary<'ast>(op: hir::BinOpKind, lhs: FloatValue, rhs: FloatValue) -> Option<ConstValue<'ast>> {
+    let kind = lhs.kind();
+    let (lhs, rhs) = (lhs.value(), rhs.value());
+    let value = if kind == NumKind::F32 {
+        // The operation has to be performed with `f32` to get the same rounding
+        #[allow(clippy::cast_possible_truncation)]
+        let (lhs, rhs) = (lhs as f32, rhs as f32);
+        let value = match op {
+            hir::BinOpKind::Add => lhs + rhs,
+            hir::BinOpKind::Sub => lhs - rhs,
+            hir::BinOpKind::Mul => lhs * rhs,
+            hir::BinOpKind::Div => lhs / rhs,
+            hir::BinOpKind::Rem => lhs % rhs,
+            _ => return eval_cmp(op, lhs.partial_cmp(&rhs)?),
+        };
+        f64::from(value)
+    } else {
+        match op {
+            hir::BinOpKind::Add => lhs + rhs,
+            hir::BinOpKind::Sub => lhs - rhs,
+            hir::BinOpKind::Mul => lhs * rhs,
+            hir::BinOpKind::Div => lhs / rhs,
+            hir::BinOpKind::Rem => lhs % rhs,
+            _ => return eval_cmp(op, lhs.partial_cmp(&rhs)?),
+        }
+    };
+    Some(ConstValue::Float(FloatValue::new(value, kind)))
+}
+
+fn eval_cmp<'ast>(op: hir::BinOpKind, ordering: std::cmp::Ordering) -> Option<ConstValue<'ast>> {
+    let value = match op {
+        hir::BinOpKind::Eq => ordering.is_eq(),
+        hir::BinOpKind::Ne => ordering.is_ne(),
+        hir::BinOpKind::Lt => ordering.is_lt(),
+        hir::BinOpKind::Le => ordering.is_le(),
+        hir::BinOpKind::Gt => ordering.is_gt(),
+        hir::BinOpKind::Ge => ordering.is_ge(),
+        _ => return None,
+    };
+    Some(ConstValue::Bool(value))
+}
@@ -1,4 +1,4 @@
-use marker_api::sem::{BindingArg, ConstArg, ConstValue, GenericArgKind, GenericArgs, TraitBound};
+use marker_api::sem::{BindingArg, ConstArg, GenericArgKind, GenericArgs, TraitBound};
 use rustc_middle as mid;
 
 use crate::conversion::marker::MarkerConverterInner;
@@ -19,9 +19,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         match &arg.unpack() {
             mid::ty::GenericArgKind::Lifetime(_) => None,
             mid::ty::GenericArgKind::Type(ty) => Some(GenericArgKind::Ty(self.to_sem_ty(*ty))),
-            mid::ty::GenericArgKind::Const(_) => {
-                Some(GenericArgKind::Const(self.alloc(ConstArg::new(ConstValue::new()))))
-            },
+            mid::ty::GenericArgKind::Const(value) => Some(GenericArgKind::Const(
+                self.alloc(ConstArg::new(self.to_const_value(*value))),
+            )),
         }
     }
 
@@ -0,0 +1,54 @@
+use marker_api::sem::{Niche, TyLayout, VariantLayout};
+use rustc_middle as mid;
+use rustc_middle::ty::layout::{LayoutCx, LayoutOf};
+use rustc_middle::ty::TypeVisitableExt;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Computes the layout of the given type. This returns `None`, if the
+    /// layout depends on generic parameters or if the type is unsized.
+    #[must_use]
+    pub fn to_ty_layout(&self, ty: mid::ty::Ty<'tcx>, param_env: mid::ty::ParamEnv<'tcx>) -> Option<TyLayout<'ast>> {
+        // Bound variables can only be handled inside their binder
+        if ty.has_escaping_bound_vars() {
+            return None;
+        }
+
+        let tcx = self.rustc_cx;
+        let cx = LayoutCx {
+            tcx,
+            param_env: param_env.with_reveal_all_normalized(tcx),
+        };
+        let layout = cx.layout_of(tcx.erase_regions(ty)).ok()?;
+        if layout.is_unsized() {
+            return None;
+        }
+
+        let variants: &[VariantLayout] = match layout.ty.kind() {
+            mid::ty::Adt(adt, _) if adt.is_enum() => {
+                self.alloc_slice(adt.variants().iter_enumerated().map(|(index, variant)| {
+                    VariantLayout::new(
+                        self.to_variant_id(variant.def_id),
+                        layout.for_variant(&cx, index).size.bytes(),
+                    )
+                }))
+            },
+            _ => &[],
+        };
+        let niche = layout.largest_niche.map(|niche| {
+            Niche::new(
+                niche.offset.bytes(),
+                niche.value.size(&cx).bytes(),
+                niche.available(&cx),
+            )
+        });
+
+        Some(TyLayout::new(
+            layout.size.bytes(),
+            layout.align.abi.bytes(),
+            variants,
+            niche,
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+use marker_api::sem::{AdjustmentKind, LocalUsage, LocalUsageKind};
+use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::hir::nested_filter;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Collects the usages of the given local variable in the current body,
+    /// including the bodies of nested closures.
+    #[must_use]
+    pub fn to_local_usages(&self, var: hir::HirId) -> &'ast [LocalUsage] {
+        let body_id = self
+            .rustc_body
+            .borrow()
+            .expect("local usages can only be collected inside bodies");
+        let body = self.rustc_cx.hir().body(body_id);
+        let mut visitor = LocalUsageVisitor {
+            cx: self,
+            var,
+            usages: vec![],
+        };
+        visitor.visit_body(body);
+        self.alloc_slice(visitor.usages)
+    }
+
+    fn to_local_usage_kind(&self, path_expr: &'tcx hir::Expr<'tcx>) -> LocalUsageKind {
+        let map = self.rustc_cx.hir();
+        let parent_expr = |expr: &hir::Expr<'tcx>| match map.find_parent(expr.hir_id) {
+            Some(hir::Node::Expr(parent)) => Some(parent),
+            _ => None,
+        };
+
+        // Usages of fields and elements are classified by the usage of the
+        // outermost projection, like `var.field` in `var.field.len()`.
+        let mut place = path_expr;
+        loop {
+            let first_adjustment = self.rustc_ty_check().expr_adjustments(place).first();
+            if let Some(adjustment) = first_adjustment {
+                // Adjustments, other than borrows, use the value of the place
+                if let AdjustmentKind::Borrow(mutability)
+                | AdjustmentKind::RawBorrow(mutability)
+                | AdjustmentKind::OverloadedDeref(mutability) = self.to_adjustment_kind(&adjustment.kind)
+                {
+                    return LocalUsageKind::Borrow(mutability);
+                }
+                return LocalUsageKind::Read;
+            }
+
+            match parent_expr(place) {
+                Some(
+                    parent @ hir::Expr {
+                        kind: hir::ExprKind::Field(base, _) | hir::ExprKind::Index(base, _, _),
+                        ..
+                    },
+                ) if base.hir_id == place.hir_id => place = parent,
+                _ => break,
+            }
+        }
+
+        match parent_expr(place).map(|parent| parent.kind) {
+            Some(hir::ExprKind::Assign(lhs, ..) | hir::ExprKind::AssignOp(_, lhs, _)) if lhs.hir_id == place.hir_id => {
+                LocalUsageKind::Write
+            },
+            Some(hir::ExprKind::AddrOf(_, mutability, operand)) if operand.hir_id == place.hir_id => {
+                LocalUsageKind::Borrow(self.to_mutability(mutability))
+            },
+            _ => LocalUsageKind::Read,
+        }
+    }
+}
+
+struct LocalUsageVisitor<'a, 'ast, 'tcx> {
+    cx: &'a MarkerConverterInner<'ast, 'tcx>,
+    var: hir::HirId,
+    usages: Vec<LocalUsage>,
+}
+
+impl<'a, 'ast, 'tcx> Visitor<'tcx> for LocalUsageVisitor<'a, 'ast, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.rustc_cx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind
+            && path.res == hir::def::Res::Local(self.var)
+        {
+            let kind = self.cx.to_local_usage_kind(expr);
+            self.usages.push(LocalUsage::new(self.cx.to_expr_id(expr.hir_id), kind));
+        }
+
+        intravisit::walk_expr(self, expr);
+    }
+}
@@ -1,7 +1,7 @@
 use marker_api::{
-    common::{NumKind, TextKind},
+    common::{DriverTyId, NumKind, TextKind},
     sem::{
-        AdtTy, AliasTy, ArrayTy, BoolTy, ClosureTy, ConstValue, FnPtrTy, FnTy, GenericTy, NeverTy, NumTy, RawPtrTy,
+        AdtTy, AliasTy, ArrayTy, BoolTy, ClosureTy, CommonTyData, FnPtrTy, FnTy, GenericTy, NeverTy, NumTy, RawPtrTy,
         RefTy, SliceTy, TextTy, TraitObjTy, TupleTy, TyKind, UnstableTy,
     },
 };
@@ -14,9 +14,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     pub fn to_sem_ty(&self, rustc_ty: mid::ty::Ty<'tcx>) -> TyKind<'ast> {
         // Semantic types could be cached, the question is if they should and at
         // which level.
+        let data = CommonTyData::new(self.to_driver_ty_id(rustc_ty));
         match &rustc_ty.kind() {
-            mid::ty::TyKind::Bool => TyKind::Bool(self.alloc(BoolTy::new())),
-            mid::ty::TyKind::Char => TyKind::Text(self.alloc(TextTy::new(TextKind::Char))),
+            mid::ty::TyKind::Bool => TyKind::Bool(self.alloc(BoolTy::new(data))),
+            mid::ty::TyKind::Char => TyKind::Text(self.alloc(TextTy::new(data, TextKind::Char))),
             mid::ty::TyKind::Int(int_ty) => {
                 let num_ty = match int_ty {
                     mid::ty::IntTy::Isize => NumKind::Isize,
@@ -26,7 +27,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     mid::ty::IntTy::I64 => NumKind::I64,
                     mid::ty::IntTy::I128 => NumKind::I128,
                 };
-                TyKind::Num(self.alloc(NumTy::new(num_ty)))
+                TyKind::Num(self.alloc(NumTy::new(data, num_ty)))
             },
             mid::ty::TyKind::Uint(uint_ty) => {
                 let num_ty = match uint_ty {
@@ -37,43 +38,48 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     mid::ty::UintTy::U64 => NumKind::U64,
                     mid::ty::UintTy::U128 => NumKind::U128,
                 };
-                TyKind::Num(self.alloc(NumTy::new(num_ty)))
+                TyKind::Num(self.alloc(NumTy::new(data, num_ty)))
             },
             mid::ty::TyKind::Float(float_ty) => {
                 let num_ty = match float_ty {
                     mid::ty::FloatTy::F32 => NumKind::F32,
                     mid::ty::FloatTy::F64 => NumKind::F64,
                 };
-                TyKind::Num(self.alloc(NumTy::new(num_ty)))
+                TyKind::Num(self.alloc(NumTy::new(data, num_ty)))
             },
-            mid::ty::TyKind::Str => TyKind::Text(self.alloc(TextTy::new(TextKind::Str))),
+            mid::ty::TyKind::Str => TyKind::Text(self.alloc(TextTy::new(data, TextKind::Str))),
             mid::ty::TyKind::Adt(def, generics) => TyKind::Adt(self.alloc(AdtTy::new(
+                data,
                 self.to_ty_def_id(def.did()),
                 self.to_sem_generic_args(generics),
             ))),
             mid::ty::TyKind::Foreign(_) => {
                 todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
             },
-            mid::ty::TyKind::Array(inner, _len) => {
-                TyKind::Array(self.alloc(ArrayTy::new(self.to_sem_ty(*inner), ConstValue::new())))
+            mid::ty::TyKind::Array(inner, len) => {
+                TyKind::Array(self.alloc(ArrayTy::new(data, self.to_sem_ty(*inner), self.to_const_value(*len))))
             },
-            mid::ty::TyKind::Slice(inner) => TyKind::Slice(self.alloc(SliceTy::new(self.to_sem_ty(*inner)))),
+            mid::ty::TyKind::Slice(inner) => TyKind::Slice(self.alloc(SliceTy::new(data, self.to_sem_ty(*inner)))),
             mid::ty::TyKind::Tuple(ty_lst) => TyKind::Tuple(self.alloc(TupleTy::new(
+                data,
                 self.alloc_slice(ty_lst.iter().map(|ty| self.to_sem_ty(ty))),
             ))),
             mid::ty::TyKind::RawPtr(ty_and_mut) => TyKind::RawPtr(self.alloc(RawPtrTy::new(
+                data,
                 self.to_mutability(ty_and_mut.mutbl),
                 self.to_sem_ty(ty_and_mut.ty),
             ))),
             mid::ty::TyKind::Ref(_lifetime, inner, muta) => {
-                TyKind::Ref(self.alloc(RefTy::new(self.to_mutability(*muta), self.to_sem_ty(*inner))))
+                TyKind::Ref(self.alloc(RefTy::new(data, self.to_mutability(*muta), self.to_sem_ty(*inner))))
             },
             mid::ty::TyKind::FnDef(fn_id, generic_args) => TyKind::FnTy(self.alloc(FnTy::new(
+                data,
                 self.to_item_id(*fn_id),
                 self.to_sem_generic_args(generic_args),
             ))),
             mid::ty::TyKind::FnPtr(fn_info) => TyKind::FnPtr(
                 self.alloc(FnPtrTy::new(
+                    data,
                     self.to_safety(fn_info.unsafety()),
                     self.to_abi(fn_info.abi()),
                     self.alloc_slice(
@@ -90,17 +96,20 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 if !matches!(kind, mid::ty::DynKind::Dyn) {
                     unimplemented!("the docs are not totally clear, when `DynStar` is used, her it is: {rustc_ty:#?}")
                 }
-                TyKind::TraitObj(self.alloc(TraitObjTy::new(self.to_sem_trait_bounds(binders))))
+                TyKind::TraitObj(self.alloc(TraitObjTy::new(data, self.to_sem_trait_bounds(binders))))
             },
             mid::ty::TyKind::Closure(id, generics) => TyKind::ClosureTy(self.alloc(ClosureTy::new(
+                data,
                 self.to_ty_def_id(*id),
                 self.to_sem_generic_args(generics),
             ))),
             mid::ty::TyKind::Coroutine(_, _, _) | mid::ty::TyKind::CoroutineWitness(_, _) => {
-                TyKind::Unstable(self.alloc(UnstableTy::new()))
+                TyKind::Unstable(self.alloc(UnstableTy::new(data)))
+            },
+            mid::ty::TyKind::Never => TyKind::Never(self.alloc(NeverTy::new(data))),
+            mid::ty::TyKind::Alias(_, info) => {
+                TyKind::Alias(self.alloc(AliasTy::new(data, self.to_item_id(info.def_id))))
             },
-            mid::ty::TyKind::Never => TyKind::Never(self.alloc(NeverTy::new())),
-            mid::ty::TyKind::Alias(_, info) => TyKind::Alias(self.alloc(AliasTy::new(self.to_item_id(info.def_id)))),
             mid::ty::TyKind::Param(param) => {
                 let body_id = self
                     .rustc_body
@@ -114,7 +123,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     .rustc_cx
                     .generics_of(owner.to_def_id())
                     .type_param(param, self.rustc_cx);
-                TyKind::Generic(self.alloc(GenericTy::new(self.to_generic_id(generic_info.def_id))))
+                TyKind::Generic(self.alloc(GenericTy::new(data, self.to_generic_id(generic_info.def_id))))
             },
             mid::ty::TyKind::Bound(_, _) => {
                 unreachable!("used by rustc for higher ranked types, which are not represented in marker")
@@ -126,3 +135,22 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
     }
 }
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Stores the given type together with the [`ParamEnv`](mid::ty::ParamEnv)
+    /// of the current body. The returned id can be used to query rustc about the
+    /// type later on.
+    fn to_driver_ty_id(&self, rustc_ty: mid::ty::Ty<'tcx>) -> DriverTyId {
+        let key = (rustc_ty, self.rustc_param_env());
+
+        if let Some(id) = self.driver_ty_ids.borrow().get(&key) {
+            return *id;
+        }
+
+        let mut driver_tys = self.driver_tys.borrow_mut();
+        let id = DriverTyId::new(driver_tys.len() as u64);
+        driver_tys.push(key);
+        self.driver_ty_ids.borrow_mut().insert(key, id);
+        id
+    }
+}
@@ -1,7 +1,9 @@
 use marker_api::{
+    ast::AttrStyle,
     prelude::Span,
-    span::{ExpnInfo, FileInfo, FilePos, SpanPos, SpanSource},
+    span::{Comment, CommentKind, ExpnInfo, FileInfo, FilePos, MacroKind, SpanPos, SpanSource},
 };
+use rustc_span::Pos;
 
 use crate::conversion::marker::MarkerConverterInner;
 
@@ -27,7 +29,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         if !ctxt.is_root() {
             let expn_data = ctxt.outer_expn_data();
             return match expn_data.kind {
-                rustc_span::ExpnKind::Macro(_, _) => SpanSource::Macro(self.alloc(self.to_expn_info(&expn_data))),
+                rustc_span::ExpnKind::Macro(kind, path) => {
+                    SpanSource::Macro(self.alloc(self.to_expn_info(&expn_data, kind, path)))
+                },
                 rustc_span::ExpnKind::AstPass(_) => SpanSource::Builtin(self.builtin_span_source),
                 rustc_span::ExpnKind::Desugaring(_) => unreachable!("desugaring spans should never be created"),
                 rustc_span::ExpnKind::Root => unreachable!("checked above"),
@@ -47,23 +51,100 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 format!("MarkerConverter::to_span_source(): Unexpected file name: {rust_span:#?} -> {src_file:#?}")
             },
         };
-        SpanSource::File(self.alloc(FileInfo::new(self.storage.alloc_str(&name), self.to_span_src_id(ctxt))))
+        SpanSource::File(self.alloc(FileInfo::new(
+            self.storage.alloc_str(&name),
+            self.to_span_src_id(ctxt),
+            self.to_span_pos(src_file.start_pos),
+        )))
     }
 
+    /// Comments are not part of rustc's IR. They're collected by lexing the
+    /// source of the file again.
+    pub fn to_comments(&self, src_file: &rustc_span::SourceFile) -> &'ast [Comment<'ast>] {
+        let Some(src) = src_file.src.as_deref() else {
+            return &[];
+        };
+
+        let mut comments = vec![];
+        let mut offset = rustc_lexer::strip_shebang(src).unwrap_or(0);
+        // Tracks if the current line contains code, to detect trailing comments
+        let mut line_has_code = offset != 0;
+        for token in rustc_lexer::tokenize(&src[offset..]) {
+            let start = offset;
+            offset += token.len as usize;
+            let text = &src[start..offset];
+
+            let (kind, doc_style) = match token.kind {
+                rustc_lexer::TokenKind::LineComment { doc_style } => (CommentKind::Line, doc_style),
+                rustc_lexer::TokenKind::BlockComment { doc_style, .. } => (CommentKind::Block, doc_style),
+                rustc_lexer::TokenKind::Whitespace => {
+                    line_has_code &= !text.contains('\n');
+                    continue;
+                },
+                _ => {
+                    line_has_code = true;
+                    continue;
+                },
+            };
+            let doc_style = doc_style.map(|style| match style {
+                rustc_lexer::DocStyle::Outer => AttrStyle::Outer,
+                rustc_lexer::DocStyle::Inner => AttrStyle::Inner,
+            });
+            let span = rustc_span::Span::with_root_ctxt(
+                src_file.start_pos + rustc_span::BytePos::from_usize(start),
+                src_file.start_pos + rustc_span::BytePos::from_usize(offset),
+            );
+            comments.push(Comment::new(
+                kind,
+                doc_style,
+                line_has_code,
+                self.storage.alloc_str(text),
+                self.to_span(span),
+            ));
+            line_has_code &= !text.contains('\n');
+        }
+
+        self.alloc_slice(comments)
+    }
+
+    /// Returns the [`ExpnInfo`] of the expansion, if it belongs to a macro. Other
+    /// expansions, like desugarings, can't be represented by an [`ExpnInfo`].
     pub fn try_to_expn_info(&self, id: rustc_span::ExpnId) -> Option<&'ast ExpnInfo<'ast>> {
-        (id != rustc_span::ExpnId::root()).then(|| self.alloc(self.to_expn_info(&id.expn_data())))
+        let data = id.expn_data();
+        let rustc_span::ExpnKind::Macro(kind, path) = data.kind else {
+            return None;
+        };
+        Some(self.alloc(self.to_expn_info(&data, kind, path)))
     }
 
-    pub fn to_expn_info(&self, data: &rustc_span::ExpnData) -> ExpnInfo<'ast> {
-        debug_assert!(
-            matches!(&data.kind, rustc_span::ExpnKind::Macro(_, _)),
-            "this expansion data doesn't belong to a macro: {data:#?}"
-        );
-        ExpnInfo::new(
-            self.to_expn_id(data.parent),
-            self.to_span_id(data.call_site),
-            self.to_macro_id(data.macro_def_id.expect("filled, because this belongs to a macro")),
-        )
+    fn to_expn_info(
+        &self,
+        data: &rustc_span::ExpnData,
+        kind: rustc_span::hygiene::MacroKind,
+        path: rustc_span::Symbol,
+    ) -> ExpnInfo<'ast> {
+        let def_id = data.macro_def_id.expect("filled, because this belongs to a macro");
+
+        ExpnInfo::builder()
+            .parent(self.to_expn_id(data.parent))
+            .call_site(self.to_span_id(data.call_site))
+            .def_site(self.to_span_id(data.def_site))
+            .macro_id(self.to_macro_id(def_id))
+            .macro_kind(self.to_macro_kind(kind))
+            .macro_name(self.to_symbol_id(self.rustc_cx.item_name(def_id)))
+            .macro_path(self.to_symbol_id(path))
+            .macro_crate(self.to_crate_id(def_id.krate))
+            .macro_crate_name(self.to_symbol_id(self.rustc_cx.crate_name(def_id.krate)))
+            .is_local_macro(def_id.is_local())
+            .build()
+    }
+
+    fn to_macro_kind(&self, kind: rustc_span::hygiene::MacroKind) -> MacroKind {
+        match kind {
+            rustc_span::hygiene::MacroKind::Bang => MacroKind::Bang,
+            rustc_span::hygiene::MacroKind::Attr => MacroKind::Attr,
+            rustc_span::hygiene::MacroKind::Derive => MacroKind::Derive,
+        }
     }
 
     pub fn try_to_span_pos(&self, scx: rustc_span::SyntaxContext, pos: rustc_span::BytePos) -> Option<FilePos<'ast>> {
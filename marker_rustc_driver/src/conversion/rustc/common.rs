@@ -55,6 +55,7 @@ impl_into_hir_id_for!(ExprId);
 impl_into_hir_id_for!(VarId);
 impl_into_hir_id_for!(StmtId);
 impl_into_hir_id_for!(FieldId);
+impl_into_hir_id_for!(ArmId);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SpanSourceInfo {
@@ -136,6 +137,8 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             NodeId::Body(id) => return Some(self.to_body_id(id).hir_id),
             NodeId::Field(id) => return Some(self.to_hir_id(id)),
             NodeId::Variant(id) => self.to_def_id(id),
+            NodeId::Generic(id) => self.to_def_id(id),
+            NodeId::Arm(id) => return Some(self.to_hir_id(id)),
             _ => unreachable!(),
         };
 
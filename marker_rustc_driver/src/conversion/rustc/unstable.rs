@@ -20,9 +20,12 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             // see that it actually has the `'static` lifetime
             let lint: &'static rustc_lint::Lint = lints.borrow_mut().entry(api_lint).or_insert_with(move || {
                 // Not extracted to an extra function, as it's very specific
+                // Reporting in local macros and macros from specific crates is
+                // handled by `MarkerContext::emit_lint`. rustc should therefore
+                // only suppress lints, if no macros are reported.
                 let report_in_external_macro = match api_lint.report_in_macro {
                     MacroReport::No => false,
-                    MacroReport::All => true,
+                    MacroReport::Local | MacroReport::Crates(_) | MacroReport::All => true,
                     _ => unreachable!(),
                 };
 
@@ -17,19 +17,23 @@
 #![allow(rustdoc::private_intra_doc_links)]
 
 extern crate rustc_ast;
+extern crate rustc_ast_pretty;
 extern crate rustc_data_structures;
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_hash;
 extern crate rustc_hir;
 extern crate rustc_hir_analysis;
+extern crate rustc_infer;
 extern crate rustc_interface;
+extern crate rustc_lexer;
 extern crate rustc_lint;
 extern crate rustc_lint_defs;
 extern crate rustc_middle;
 extern crate rustc_session;
 extern crate rustc_span;
 extern crate rustc_target;
+extern crate rustc_trait_selection;
 
 pub mod context;
 pub mod conversion;
@@ -40,8 +44,10 @@ use std::ops::Deref;
 use std::process::Command;
 
 use camino::{Utf8Path, Utf8PathBuf};
-use marker_adapter::{LintCrateInfo, LINT_CRATES_ENV};
+use marker_adapter::{LintCrateInfo, LINT_CRATES_CONFIG_ENV, LINT_CRATES_ENV};
 use marker_error::Context;
+use rustc_hash::FxHashMap;
+use rustc_lint::LintId;
 
 use crate::conversion::rustc::RustcConverter;
 
@@ -49,6 +55,16 @@ use crate::conversion::rustc::RustcConverter;
 const RUSTC_TOOLCHAIN_VERSION: &str = "nightly-2023-11-16";
 // endregion replace rust toolchain dev
 
+/// The environment value, containing the lint level arguments, which should
+/// be passed to rustc. The arguments are separated by whitespace.
+const LINT_LEVELS_ENV: &str = "MARKER_LINT_LEVELS";
+
+/// The environment value, which caps the level of all Marker lints, if it's set
+/// to `warn`. This is used by `cargo-marker`, to prevent lints with the error
+/// level from stopping the compilation, while the diagnostics are processed.
+/// Capped diagnostics are marked with the [`marker_adapter::CAPPED_LINT_NOTE`].
+const CAP_LINTS_ENV: &str = "MARKER_CAP_LINTS";
+
 struct DefaultCallbacks {
     env_vars: Vec<(&'static str, String)>,
 }
@@ -100,6 +116,29 @@ impl rustc_driver::Callbacks for MarkerCallback {
 
             lint_store.register_lints(&lints);
 
+            // Register a group for every lint crate, like `marker::lint_crate`.
+            // This allows users to set the level of all lints of a lint crate at once.
+            let mut groups: FxHashMap<String, Vec<LintId>> = FxHashMap::default();
+            for lint in &lints {
+                let name = lint.name_lower();
+                if let Some((group, _)) = name.rsplit_once("::") {
+                    groups.entry(group.to_string()).or_default().push(LintId::of(lint));
+                }
+            }
+            // Groups declared by lint crates, like `marker::lint_crate::pedantic`
+            for (group, group_lints) in lint_pass::RustcLintPass::marker_lint_groups() {
+                let lint_ids = group_lints
+                    .iter()
+                    .map(|lint| LintId::of(RustcConverter::static_to_lint(lint)))
+                    .collect();
+                let prev = groups.insert(group.to_ascii_lowercase(), lint_ids);
+                assert!(prev.is_none(), "duplicate lint groups are rejected by the adapter");
+            }
+            for (group, lint_ids) in groups {
+                lint_store.register_group(true, Box::leak(group.into_boxed_str()), None, lint_ids);
+            }
+
+            lint_store.register_early_pass(|| Box::new(lint_pass::FormatArgsCollector));
             lint_store.register_late_pass(|_| Box::new(lint_pass::RustcLintPass));
         }));
     }
@@ -270,7 +309,15 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
     let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
 
     let enable_marker = !cap_lints_allow && (!no_deps || in_primary_package);
-    let env_vars = vec![(LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default())];
+    let env_vars = vec![
+        (LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default()),
+        (
+            LINT_CRATES_CONFIG_ENV,
+            std::env::var(LINT_CRATES_CONFIG_ENV).unwrap_or_default(),
+        ),
+        (LINT_LEVELS_ENV, std::env::var(LINT_LEVELS_ENV).unwrap_or_default()),
+        (CAP_LINTS_ENV, std::env::var(CAP_LINTS_ENV).unwrap_or_default()),
+    ];
     if !enable_marker {
         rustc_driver::RunCompiler::new(&orig_args, &mut DefaultCallbacks { env_vars }).run()?;
         return Ok(());
@@ -300,6 +347,12 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
 
     orig_args.extend(additional_args);
 
+    // Lint level arguments, like `--deny=marker::lint_crate::lint_name`, specified
+    // by the user. These are added last, to override the levels set by Cargo.
+    if let Ok(lint_levels) = std::env::var(LINT_LEVELS_ENV) {
+        orig_args.extend(lint_levels.split_whitespace().map(str::to_string));
+    }
+
     let mut callback = MarkerCallback { env_vars, lint_crates };
     rustc_driver::RunCompiler::new(&orig_args, &mut callback).run()?;
 
@@ -1,7 +1,8 @@
-use std::cell::OnceCell;
+use std::cell::{OnceCell, RefCell};
 
 use marker_adapter::{Adapter, LintCrateInfo};
 use marker_api::Lint;
+use rustc_hash::FxHashMap;
 
 use crate::context::{storage::Storage, RustcContext};
 
@@ -19,6 +20,12 @@ thread_local! {
     /// only single threaded. This cell will therefore only be constructed once, and
     /// this driver will always use the same adapter.
     static ADAPTER: OnceCell<Adapter> = OnceCell::new();
+
+    /// The [`rustc_ast::FormatArgs`] nodes of the current crate, collected by
+    /// [`FormatArgsCollector`]. They're keyed by the span of the expression,
+    /// since this is the only connection to the lowered HIR expression.
+    static FORMAT_ARGS: RefCell<FxHashMap<rustc_span::Span, rustc_ast::FormatArgs>> =
+        RefCell::default();
 }
 
 pub struct RustcLintPass;
@@ -36,6 +43,11 @@ impl RustcLintPass {
     pub fn marker_lints() -> Vec<&'static Lint> {
         ADAPTER.with(|adapter| adapter.get().unwrap().marker_lints())
     }
+
+    #[must_use]
+    pub fn marker_lint_groups() -> Vec<(String, &'static [&'static Lint])> {
+        ADAPTER.with(|adapter| adapter.get().unwrap().marker_lint_groups())
+    }
 }
 
 rustc_lint_defs::impl_lint_pass!(RustcLintPass => []);
@@ -45,6 +57,41 @@ impl<'tcx> rustc_lint::LateLintPass<'tcx> for RustcLintPass {
         ADAPTER.with(|adapter| {
             process_crate(rustc_cx, adapter.get().unwrap());
         });
+        // The collected spans are only meaningful for the current crate
+        FORMAT_ARGS.with(|map| map.borrow_mut().clear());
+    }
+}
+
+/// The [`format_args!`] macro is lowered into internal function calls, which
+/// lose most information about the template string. This early lint pass
+/// collects the AST representation, to allow the conversion of the lowered
+/// HIR expressions into a [`FormatArgsExpr`](marker_api::ast::FormatArgsExpr).
+pub struct FormatArgsCollector;
+
+rustc_lint_defs::impl_lint_pass!(FormatArgsCollector => []);
+
+impl rustc_lint::EarlyLintPass for FormatArgsCollector {
+    fn check_expr(&mut self, _cx: &rustc_lint::EarlyContext<'_>, expr: &rustc_ast::Expr) {
+        if let rustc_ast::ExprKind::FormatArgs(args) = &expr.kind {
+            FORMAT_ARGS.with(|map| map.borrow_mut().insert(expr.span, (**args).clone()));
+        }
+    }
+}
+
+impl FormatArgsCollector {
+    /// Returns `true`, if the HIR expression with the given span was lowered
+    /// from a [`rustc_ast::FormatArgs`] node.
+    #[must_use]
+    pub fn is_format_args(span: rustc_span::Span) -> bool {
+        Self::with_format_args(span, |_| ()).is_some()
+    }
+
+    /// Calls the given function with the [`rustc_ast::FormatArgs`] node, which
+    /// was lowered into the HIR expression with the given span.
+    pub fn with_format_args<R>(span: rustc_span::Span, f: impl FnOnce(&rustc_ast::FormatArgs) -> R) -> Option<R> {
+        // During lowering, spans can gain a parent, if incremental compilation
+        // is enabled. The parent has to be removed, to match the AST span.
+        FORMAT_ARGS.with(|map| map.borrow().get(&span.with_parent(None)).map(f))
     }
 }
 
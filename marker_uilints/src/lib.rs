@@ -4,17 +4,77 @@
 mod utils;
 
 use marker_api::{
-    ast::{AstPathTarget, EnumVariant, ItemField, LetStmt, StaticItem},
+    ast::{
+        AstPathTarget, AstQPath, AttrArgs, AttrKind, Attribute, EnumVariant, FormatArgsExpr, FormatArgsPiece,
+        GenericParamKind, IdentPat, ItemField, LetStmt, MatchArm, MethodExpr, StaticItem,
+    },
     diagnostic::Applicability,
     prelude::*,
     sem::TyKind,
-    LintPass, LintPassInfo, LintPassInfoBuilder,
+    span::SpanSource,
+    LintCrateConfig, LintPass, LintPassInfo, LintPassInfoBuilder,
 };
+use marker_utils::visitor::{Traversable, Visitor};
+use std::ops::ControlFlow;
 
 #[derive(Default)]
-struct TestLintPass {}
+struct TestLintPass {
+    /// The item, that [`LintPass`] hooks like `check_pat` and `check_ty`
+    /// should be reported for.
+    visit_hooks_item: Option<ItemId>,
+    /// Counts the callbacks inside items, named `print_post_hooks*`
+    post_hooks: Option<PostHookCounts>,
+    /// The number of items, that have been checked with `check_item_post`
+    post_hooks_items: usize,
+    /// Records the calls of expression and body hooks inside items, named
+    /// `print_post_hook_sequence*`, in the order they happened
+    post_hook_sequence: Option<Vec<String>>,
+    /// The lint crate config, which was available during the construction
+    init_config: Option<&'static LintCrateConfig<'static>>,
+}
+
+/// The configuration of this lint crate, set by `tests/uitest.rs`
+#[derive(Debug, serde::Deserialize)]
+#[allow(dead_code)]
+struct UiLintsConfig {
+    max_params: usize,
+    banned_names: Vec<String>,
+}
+
+/// A configuration type, which doesn't match the configuration of this lint crate
+#[derive(Debug, serde::Deserialize)]
+#[allow(dead_code)]
+struct InvalidUiLintsConfig {
+    banned_names: String,
+}
+
+#[derive(Debug, Default)]
+struct PostHookCounts {
+    exprs: usize,
+    exprs_post: usize,
+    bodies_post: usize,
+}
 
-marker_api::export_lint_pass!(TestLintPass);
+impl TestLintPass {
+    fn emit_visit_hook_lint<'ast>(&self, cx: &'ast MarkerContext<'ast>, msg: String, span: Option<&Span<'ast>>) {
+        let Some(item) = self.visit_hooks_item.and_then(|id| cx.ast().item(id)) else {
+            return;
+        };
+        cx.emit_lint(TEST_LINT, item, msg).decorate(|diag| {
+            if let Some(span) = span {
+                diag.span(span);
+            }
+        });
+    }
+}
+
+marker_api::export_lint_pass!(
+    TestLintPass,
+    TestLintPass {
+        init_config: marker_api::lint_crate_config(),
+        ..Default::default()
+    }
+);
 
 marker_api::declare_lint! {
     /// # What it does
@@ -35,6 +95,26 @@ marker_api::declare_lint! {
     marker_api::common::MacroReport::All,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to test reporting in local macros.
+    MACRO_REPORT_LOCAL_LINT,
+    Warn,
+    marker_api::common::MacroReport::Local,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to test reporting in macros from the `alloc` crate.
+    MACRO_REPORT_CRATES_LINT,
+    Warn,
+    marker_api::common::MacroReport::Crates(&["alloc"]),
+}
+
 marker_api::declare_lint! {
     /// # What it does
     /// A lint used for markers uitests.
@@ -68,6 +148,21 @@ marker_api::declare_lint! {
     Warn,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint to test the attribute representation.
+    TEST_ATTRS,
+    Warn,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint to test lint groups. It's part of the `test_group` group.
+    TEST_GROUP_LINT,
+    Allow,
+    group = "test_group",
+}
+
 fn emit_item_with_test_name_lint<'ast>(
     cx: &'ast MarkerContext<'ast>,
     node: impl EmissionNode<'ast>,
@@ -83,6 +178,7 @@ impl LintPass for TestLintPass {
             TEST_LINT,
             ITEM_WITH_TEST_NAME,
             PRINT_EVERY_EXPR,
+            TEST_GROUP_LINT,
             utils::TEST_CONTAINS_RETURN,
         ]))
         .build()
@@ -91,20 +187,48 @@ impl LintPass for TestLintPass {
     fn check_item<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
         utils::check_item(cx, item);
 
+        self.visit_hooks_item = item
+            .ident()
+            .filter(|ident| ident.name().starts_with("print_visit_hooks"))
+            .map(|_| item.id());
+        if item
+            .ident()
+            .map_or(false, |ident| ident.name().starts_with("print_post_hooks"))
+        {
+            self.post_hooks = Some(PostHookCounts::default());
+        }
+        if item
+            .ident()
+            .map_or(false, |ident| ident.name().starts_with("print_post_hook_sequence"))
+        {
+            self.post_hook_sequence = Some(vec![]);
+        }
+
         if let ItemKind::Fn(item) = item {
-            if let Some(ident) = item.ident() {
-                if ident.name() == "test_ty_id_resolution_trigger" {
-                    test_ty_id_resolution(cx);
-                } else if ident.name() == "uilints_please_ice_on_this" {
-                    panic!("free ice cream for everyone!!!");
-                }
-            }
+            self.check_fn_triggers(cx, item);
         }
 
         if let ItemKind::Static(item) = item {
             check_static_item(cx, item);
         }
 
+        if let ItemKind::Impl(item) = item {
+            check_impl_trait_path(cx, item);
+        }
+
+        if let Some(ident) = item.ident() {
+            if ident.name().starts_with("print_attrs") {
+                emit_attrs_lint(cx, item, ident.span(), item.attrs());
+                if let ItemKind::Fn(func) = item {
+                    for param in func.generics().params() {
+                        if let Some(span) = param.span() {
+                            emit_attrs_lint(cx, item, span, param.attrs());
+                        }
+                    }
+                }
+            }
+        }
+
         if matches!(
             item.ident().map(marker_api::span::Ident::name),
             Some(name) if name.starts_with("FindMe") || name.starts_with("FIND_ME") || name.starts_with("find_me")
@@ -170,15 +294,72 @@ impl LintPass for TestLintPass {
         }
     }
 
+    fn check_item_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+        let Some(ident) = item.ident() else { return };
+        if ident.name().starts_with("print_post_hook_sequence") {
+            let sequence = self.post_hook_sequence.take().unwrap_or_default();
+            cx.emit_lint(TEST_LINT, item, "checked item with the post hook sequence")
+                .decorate(|diag| {
+                    for call in &sequence {
+                        diag.note(call);
+                    }
+                });
+            return;
+        }
+        if !ident.name().starts_with("print_post_hooks") {
+            return;
+        }
+
+        let counts = self.post_hooks.take().unwrap_or_default();
+        self.post_hooks_items += 1;
+        cx.emit_lint(TEST_LINT, item, "checked item with post hooks")
+            .decorate(|diag| {
+                diag.note(format!("{counts:?}"));
+            });
+    }
+
+    fn check_body_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _body: &'ast ast::Body<'ast>) {
+        if let Some(counts) = &mut self.post_hooks {
+            counts.bodies_post += 1;
+        }
+        if let Some(sequence) = &mut self.post_hook_sequence {
+            sequence.push("check_body_post".to_string());
+        }
+    }
+
+    fn check_expr_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) {
+        if let Some(counts) = &mut self.post_hooks {
+            counts.exprs_post += 1;
+        }
+        if let Some(sequence) = &mut self.post_hook_sequence {
+            sequence.push(format!("check_expr_post: `{}`", expr.span().snippet_or("..")));
+        }
+    }
+
+    fn check_crate_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, krate: &'ast ast::Crate<'ast>) {
+        if self.post_hooks_items == 0 {
+            return;
+        }
+
+        cx.emit_lint(TEST_LINT, ItemKind::Mod(krate.root_mod()), "checked crate")
+            .decorate(|diag| {
+                diag.note(format!("Items with post hooks: {}", self.post_hooks_items));
+            });
+    }
+
     fn check_field<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, field: &'ast ItemField<'ast>) {
         if field.ident().starts_with("find_me") {
             emit_item_with_test_name_lint(cx, field, "a field");
+        } else if field.ident().starts_with("print_attrs") {
+            emit_attrs_lint(cx, field, field.span(), field.attrs());
         }
     }
 
     fn check_variant<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, variant: &'ast EnumVariant<'ast>) {
         if variant.ident().starts_with("FindMe") {
             emit_item_with_test_name_lint(cx, variant, "an enum variant");
+        } else if variant.ident().starts_with("PrintAttrs") {
+            emit_attrs_lint(cx, variant, variant.span(), variant.attrs());
         }
     }
 
@@ -188,17 +369,14 @@ impl LintPass for TestLintPass {
         if let StmtKind::Let(lets) = stmt {
             let PatKind::Ident(ident) = lets.pat() else { return };
             let Some(expr) = lets.init() else { return };
-            if ident.name().starts_with("_print") {
+            if ident.name().starts_with("_print_attrs") {
+                emit_attrs_lint(cx, stmt, stmt.span(), stmt.attrs());
+            } else if ident.name().starts_with("_print") {
                 cx.emit_lint(TEST_LINT, stmt, "print test").decorate(|diag| {
                     diag.note(format!("{expr:#?}"));
                 });
             } else if ident.name().starts_with("_span") {
-                cx.emit_lint(PRINT_SPAN_LINT, stmt, "print span").decorate(|diag| {
-                    let span = expr.span();
-                    diag.note(format!("Debug: {span:#?}"));
-                    diag.note(format!("Snippet: {}", span.snippet_or("..")));
-                    diag.note(format!("Source: {:#?}", span.source()));
-                });
+                print_span(cx, stmt, expr);
             } else if ident.name().starts_with("_ty") {
                 cx.emit_lint(TEST_LINT, stmt, "print type test").decorate(|diag| {
                     diag.note(format!("{:#?}", expr.ty()));
@@ -226,16 +404,357 @@ impl LintPass for TestLintPass {
                 });
             } else if ident.name().starts_with("_check_ast_map") {
                 check_ast_map(cx, lets);
+            } else if ident.name().starts_with("_check_item_path") {
+                cx.emit_lint(TEST_LINT, stmt, "check item resolution").decorate(|diag| {
+                    let id = match expr {
+                        ExprKind::Call(call) => match call.func() {
+                            ExprKind::Path(path) => match path.path().resolve() {
+                                AstPathTarget::Item(id) => id,
+                                _ => unreachable!("the callee should be an item"),
+                            },
+                            _ => unreachable!("the callee should be a path"),
+                        },
+                        ExprKind::Method(method) => method.resolve().id(),
+                        _ => unreachable!("`_check_item_path` should be assigned a call"),
+                    };
+                    for path in [
+                        "std::mem::forget",
+                        "std::vec::Vec::new",
+                        "std::clone::Clone::clone",
+                        "crate::TestType::new",
+                    ] {
+                        let ids = cx.resolve_item_ids(path);
+                        diag.note(format!("Is this a {path:#?} -> {}", ids.contains(&id)));
+                    }
+                });
+            } else if ident.name().starts_with("_method_target") {
+                let ExprKind::Method(method) = expr else {
+                    unreachable!("`_method_target` should be assigned a method call")
+                };
+                check_method_target(cx, method);
+            } else if ident.name().starts_with("_check_local_usages") {
+                check_local_usages(cx, stmt, ident);
+            } else if ident.name().starts_with("_check_format_args") {
+                check_format_args(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_format_macro") {
+                check_format_macro(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_macro_report") {
+                cx.emit_lint(MACRO_REPORT_LOCAL_LINT, expr, "reported with `MacroReport::Local`");
+                cx.emit_lint(MACRO_REPORT_CRATES_LINT, expr, "reported with `MacroReport::Crates`");
+            } else if ident.name().starts_with("_check_adjustments") {
+                cx.emit_lint(TEST_LINT, stmt, "testing `ExprData::adjustments`")
+                    .decorate(|diag| {
+                        for adj in expr.adjustments() {
+                            diag.note(format!("{:?} -> {:?}", adj.kind(), adj.target()));
+                        }
+                    });
+            } else if ident.name().starts_with("_check_ty_traits") {
+                check_ty_traits(cx, stmt, expr.ty());
+            } else if ident.name().starts_with("_check_ty_layout") {
+                check_ty_layout(cx, stmt, expr.ty());
+            } else if ident.name().starts_with("_check_comments") {
+                check_comments(cx, stmt);
+            } else if ident.name().starts_with("_check_eval_const") {
+                cx.emit_lint(TEST_LINT, stmt, "testing `MarkerContext::eval_const`")
+                    .decorate(|diag| {
+                        diag.note(format!("eval_const() -> {:#?}", cx.eval_const(expr)));
+                        if let TyKind::Array(array) = expr.ty() {
+                            diag.note(format!("ArrayTy::len() -> {:?}", array.len()));
+                        }
+                    });
             }
         }
     }
 
     fn check_expr<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) {
+        if let Some(counts) = &mut self.post_hooks {
+            counts.exprs += 1;
+        }
+        if let Some(sequence) = &mut self.post_hook_sequence {
+            sequence.push(format!("check_expr: `{}`", expr.span().snippet_or("..")));
+        }
+
+        if let ExprKind::Match(match_expr) = expr {
+            for arm in match_expr.arms().iter().filter(|arm| !arm.attrs().is_empty()) {
+                emit_attrs_lint(cx, expr, arm.span(), arm.attrs());
+            }
+        }
+
         cx.emit_lint(PRINT_EVERY_EXPR, expr, "expr").decorate(|diag| {
             diag.note(&format!("SpanSource: {:#?}", expr.span().source()));
             diag.note(&format!("Snippet: {:#?}", expr.span().snippet_or("<..>")));
         });
     }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, pat: PatKind<'ast>) {
+        self.emit_visit_hook_lint(cx, "check_pat".to_string(), Some(pat.span()));
+    }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: ast::TyKind<'ast>) {
+        self.emit_visit_hook_lint(cx, "check_ty".to_string(), Some(ty.span()));
+    }
+
+    fn check_generic_param<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, param: GenericParamKind<'ast>) {
+        self.emit_visit_hook_lint(cx, "check_generic_param".to_string(), param.span());
+    }
+
+    fn check_match_arm<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, arm: &'ast MatchArm<'ast>) {
+        self.emit_visit_hook_lint(cx, "check_match_arm".to_string(), Some(arm.span()));
+    }
+
+    fn check_path<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, path: &'ast AstQPath<'ast>) {
+        let segments: Vec<_> = path.segments().iter().map(|seg| seg.ident().name()).collect();
+        let span = match (path.segments().first(), path.segments().last()) {
+            (Some(first), Some(last)) => Some(first.ident().span().with_end(last.ident().span().end())),
+            _ => None,
+        };
+        self.emit_visit_hook_lint(cx, format!("check_path: `{}`", segments.join("::")), span.as_ref());
+    }
+}
+
+fn emit_attrs_lint<'ast>(
+    cx: &'ast MarkerContext<'ast>,
+    node: impl EmissionNode<'ast>,
+    span: &Span<'ast>,
+    attrs: &[Attribute<'ast>],
+) {
+    cx.emit_lint(TEST_ATTRS, node, "printing attributes").decorate(|diag| {
+        diag.span(span);
+        for attr in attrs {
+            let style = if attr.is_inner() { "inner" } else { "outer" };
+            let desc = match attr.kind() {
+                AttrKind::Normal(normal) => {
+                    let path: Vec<_> = normal.path().iter().map(marker_api::span::Ident::name).collect();
+                    let args = match normal.args() {
+                        AttrArgs::Empty => String::new(),
+                        AttrArgs::Delimited(args) => format!(" {:?}({})", args.delim(), args.tokens()),
+                        AttrArgs::Eq(args) => format!(" = {} (str: {:?})", args.lit(), args.str_value()),
+                        _ => unreachable!(),
+                    };
+                    format!("{}{args}", path.join("::"))
+                },
+                AttrKind::DocComment(doc) => format!("{:?} doc comment: {:?}", doc.comment_kind(), doc.text()),
+                _ => unreachable!(),
+            };
+            diag.note(format!(
+                "{style}: {desc} (from_cfg_attr: {}, doc_str: {:?}, snippet: `{}`)",
+                attr.is_from_cfg_attr(),
+                attr.doc_str(),
+                attr.span().snippet_or("..")
+            ));
+        }
+    });
+}
+
+fn check_comments<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>) {
+    cx.emit_lint(TEST_LINT, stmt, "checking comments").decorate(|diag| {
+        for comment in stmt.span().preceding_comments() {
+            diag.note(format!(
+                "{:?} comment (doc_style: {:?}, is_trailing: {}): {:?} (content: {:?})",
+                comment.kind(),
+                comment.doc_style(),
+                comment.is_trailing(),
+                comment.text(),
+                comment.content(),
+            ));
+        }
+        if let SpanSource::File(file) = stmt.span().source() {
+            diag.note(format!("Comments in file: {}", file.comments().len()));
+        }
+    });
+}
+
+fn check_method_target<'ast>(cx: &'ast MarkerContext<'ast>, method: &'ast MethodExpr<'ast>) {
+    fn item_name(cx: &MarkerContext<'_>, id: ItemId) -> String {
+        cx.ast()
+            .item(id)
+            .and_then(|item| item.ident().map(|ident| ident.name().to_string()))
+            .unwrap_or_else(|| "<external>".to_string())
+    }
+
+    cx.emit_lint(TEST_LINT, method, "testing `MethodExpr::resolve`")
+        .decorate(|diag| {
+            let target = method.resolve();
+            diag.note(format!("id() -> {}", item_name(cx, target.id())));
+            diag.note(format!("is_trait_method() -> {}", target.is_trait_method()));
+            diag.note(format!(
+                "impl_item() -> {:?}",
+                target.impl_item().map(|id| (item_name(cx, id), id == target.id()))
+            ));
+            for adj in target.receiver_adjustments() {
+                diag.note(format!("adjustment: {:?}", adj.kind()));
+            }
+        });
+}
+
+fn print_span<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    cx.emit_lint(PRINT_SPAN_LINT, stmt, "print span").decorate(|diag| {
+        let span = expr.span();
+        diag.note(format!("Debug: {span:#?}"));
+        diag.note(format!("Snippet: {}", span.snippet_or("..")));
+        diag.note(format!("Source: {:#?}", span.source()));
+
+        if let SpanSource::Macro(expn) = span.source() {
+            diag.note(format!("Macro kind: {:?}", expn.macro_kind()));
+            diag.note(format!("Macro name: {}", expn.macro_name()));
+            diag.note(format!("Macro path: {}", expn.macro_path()));
+            diag.note(format!("Macro crate: {}", expn.macro_crate_name()));
+            diag.note(format!("Is local macro: {}", expn.is_local_macro()));
+            // The definition of external macros would point into the sysroot
+            if expn.is_local_macro() {
+                diag.span_note("Macro definition", expn.def_site());
+            }
+            for path in ["crate::magic_macro", "std::vec", "std::format_args"] {
+                let is_macro = cx.resolve_macro_ids(path).contains(&expn.macro_id());
+                diag.note(format!("Is this a {path:#?} -> {is_macro}"));
+            }
+        }
+    });
+}
+
+fn check_local_usages<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, ident: &'ast IdentPat<'ast>) {
+    cx.emit_lint(TEST_LINT, stmt, "testing `MarkerContext::local_usages`")
+        .decorate(|diag| {
+            for usage in cx.local_usages(ident.var_id()) {
+                let ExprKind::Path(path) = cx.ast().expr(usage.expr()) else {
+                    unreachable!("local usages should be path expressions")
+                };
+                assert_eq!(path.resolve_local(), Some(ident.var_id()));
+                diag.span_note(format!("{:?}", usage.kind()), path);
+            }
+        });
+}
+
+/// Checks the [`FormatArgsExpr`] passed as the first argument of a call, like
+/// `std::fmt::format(format_args!(...))`.
+fn check_format_args<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::Call(call) = expr else { return };
+    let Some(ExprKind::FormatArgs(format_args)) = call.args().first() else {
+        return;
+    };
+
+    emit_format_args(cx, stmt, format_args);
+}
+
+/// Checks the [`FormatArgsExpr`]s expanded from macros like `println!(...)`,
+/// which are nested inside the given expression.
+fn check_format_macro<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    struct FormatArgsVisitor {
+        stmt: StmtId,
+    }
+
+    impl Visitor<()> for FormatArgsVisitor {
+        fn visit_expr<'v_ast>(&mut self, cx: &'v_ast MarkerContext<'v_ast>, expr: ExprKind<'v_ast>) -> ControlFlow<()> {
+            if let ExprKind::FormatArgs(format_args) = expr {
+                emit_format_args(cx, cx.ast().stmt(self.stmt), format_args);
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let _ = expr.traverse(cx, &mut FormatArgsVisitor { stmt: stmt.id() });
+}
+
+fn emit_format_args<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, format_args: &FormatArgsExpr<'ast>) {
+    cx.emit_lint(TEST_LINT, stmt, "testing `FormatArgsExpr`")
+        .decorate(|diag| {
+            for piece in format_args.template() {
+                match piece {
+                    FormatArgsPiece::Lit(lit) => diag.note(format!("Lit: {:?}", lit.value())),
+                    FormatArgsPiece::Placeholder(placeholder) => {
+                        let msg = format!(
+                            "Placeholder: arg {} ({:?}), {:?}, width: {:?}, precision: {:?}, \
+                        alignment: {:?}, fill: {:?}, sign: {:?}, alternate: {}, zero padded: {}, \
+                        debug hex: {:?}, default options: {}",
+                            placeholder.arg_index(),
+                            placeholder.arg_kind(),
+                            placeholder.format_trait(),
+                            placeholder.width(),
+                            placeholder.precision(),
+                            placeholder.alignment(),
+                            placeholder.fill(),
+                            placeholder.sign(),
+                            placeholder.is_alternate(),
+                            placeholder.is_zero_padded(),
+                            placeholder.debug_hex(),
+                            placeholder.has_default_options(),
+                        );
+                        match placeholder.span() {
+                            Some(span) => diag.span_note(msg, span),
+                            None => diag.note(msg),
+                        }
+                    },
+                    _ => unreachable!("all pieces are covered"),
+                };
+            }
+            for arg in format_args.args() {
+                let msg = format!(
+                    "Arg: {:?}, ident: {:?}, expr: {:?}",
+                    arg.kind(),
+                    arg.ident().map(Ident::name),
+                    arg.expr().map(|arg_expr| arg_expr.span().snippet_or("..")),
+                );
+                diag.span_note(msg, arg.span());
+            }
+        });
+}
+
+fn check_ty_traits<'ast>(cx: &'ast MarkerContext<'ast>, node: impl EmissionNode<'ast>, ty: TyKind<'ast>) {
+    fn implements<'ast>(cx: &MarkerContext<'ast>, ty: TyKind<'ast>, path: &str, args: &[TyKind<'ast>]) -> bool {
+        cx.resolve_ty_ids(path)
+            .iter()
+            .any(|id| cx.ty_implements_trait(ty, *id, args))
+    }
+
+    cx.emit_lint(TEST_LINT, node, "testing type queries").decorate(|diag| {
+        diag.note(format!("is_copy() -> {}", ty.is_copy()));
+        diag.note(format!("is_send() -> {}", ty.is_send()));
+        diag.note(format!("is_sync() -> {}", ty.is_sync()));
+        diag.note(format!("is_sized() -> {}", ty.is_sized()));
+        diag.note(format!("needs_drop() -> {}", ty.needs_drop()));
+        diag.note(format!(
+            "implements `Clone` -> {}",
+            implements(cx, ty, "std::clone::Clone", &[])
+        ));
+        diag.note(format!(
+            "implements `Default` -> {}",
+            implements(cx, ty, "std::default::Default", &[])
+        ));
+        diag.note(format!(
+            "implements `PartialEq<Self>` -> {}",
+            implements(cx, ty, "std::cmp::PartialEq", &[ty])
+        ));
+        diag.note(format!(
+            "implements `Clone<Self>` -> {}",
+            implements(cx, ty, "std::clone::Clone", &[ty])
+        ));
+    });
+}
+
+fn check_ty_layout<'ast>(cx: &'ast MarkerContext<'ast>, node: impl EmissionNode<'ast>, ty: TyKind<'ast>) {
+    cx.emit_lint(TEST_LINT, node, "testing `MarkerContext::ty_layout`")
+        .decorate(|diag| {
+            let Some(layout) = cx.ty_layout(ty) else {
+                diag.note("layout -> None");
+                return;
+            };
+            diag.note(format!("size: {}, align: {}", layout.size(), layout.align()));
+            for variant in layout.variants() {
+                let name = cx
+                    .ast()
+                    .variant(variant.variant())
+                    .map_or("<external>", |var| var.ident());
+                diag.note(format!("variant `{name}`: {}", variant.size()));
+            }
+            if let Some(niche) = layout.niche() {
+                diag.note(format!(
+                    "niche: offset {}, size {}, available {}",
+                    niche.offset(),
+                    niche.size(),
+                    niche.available()
+                ));
+            }
+        });
 }
 
 fn check_ast_map<'ast>(cx: &'ast MarkerContext<'ast>, lets: &'ast LetStmt<'ast>) {
@@ -269,8 +788,8 @@ fn check_ast_map<'ast>(cx: &'ast MarkerContext<'ast>, lets: &'ast LetStmt<'ast>)
 }
 
 fn check_static_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast StaticItem<'ast>) {
-    if let Some(name) = item.ident() {
-        let name = name.name();
+    if let Some(ident) = item.ident() {
+        let name = ident.name();
         if name.starts_with("PRINT_TYPE") {
             cx.emit_lint(TEST_LINT, item, "printing type for").decorate(|diag| {
                 diag.span(item.ty().span());
@@ -285,10 +804,59 @@ fn check_static_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast StaticItem
                     diag.span_help("a spanned help", item.span());
                     diag.span_suggestion("try", item.span(), "duck", Applicability::Unspecified);
                 });
+        } else if name.starts_with("MULTIPART_SUGGESTION") {
+            cx.emit_lint(TEST_LINT, item, "testing multipart suggestions")
+                .decorate(|diag| {
+                    diag.multipart_suggestion(
+                        "try multiple",
+                        [(ident.span(), "DUCK"), (item.ty().span(), "Duck")],
+                        Applicability::Unspecified,
+                    );
+                    diag.multipart_suggestion(
+                        "empty suggestions are ignored",
+                        std::iter::empty::<(&Span<'_>, &str)>(),
+                        Applicability::Unspecified,
+                    );
+                });
         }
     }
 }
 
+fn test_item_id_resolution<'ast>(cx: &'ast MarkerContext<'ast>) {
+    fn try_resolve_path(cx: &MarkerContext<'_>, path: &str) {
+        let ids = cx.resolve_item_ids(path);
+        eprintln!("Resolving {path:?} yielded {} item(s)", ids.len());
+    }
+
+    eprintln!("# Invalid paths");
+    try_resolve_path(cx, "");
+    try_resolve_path(cx, "forget");
+    try_resolve_path(cx, "std::mem::does_not_exist");
+
+    eprintln!();
+    eprintln!("# Not an item");
+    try_resolve_path(cx, "std::option::Option::None");
+
+    eprintln!();
+    eprintln!("# Valid");
+    try_resolve_path(cx, "std::mem");
+    try_resolve_path(cx, "std::mem::forget");
+    try_resolve_path(cx, "std::f32::consts::PI");
+    try_resolve_path(cx, "std::clone::Clone");
+    try_resolve_path(cx, "std::clone::Clone::clone");
+    try_resolve_path(cx, "std::vec::Vec::new");
+
+    eprintln!();
+    eprintln!("# Valid local items");
+    try_resolve_path(cx, "crate::TEST_STATIC");
+    try_resolve_path(cx, "crate::TestType::new");
+    try_resolve_path(cx, "item_id_resolution::test_ty_id_resolution_trigger");
+
+    eprintln!();
+    eprintln!("=====================================================================");
+    eprintln!();
+}
+
 fn test_ty_id_resolution<'ast>(cx: &'ast MarkerContext<'ast>) {
     fn try_resolve_path(cx: &MarkerContext<'_>, path: &str) {
         let ids = cx.resolve_ty_ids(path);
@@ -334,3 +902,70 @@ fn test_ty_id_resolution<'ast>(cx: &'ast MarkerContext<'ast>) {
     eprintln!("=====================================================================");
     eprintln!();
 }
+
+/// Prints the trait path of impls for types, named `PrintTraitPath*`
+fn check_impl_trait_path<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast marker_api::ast::ImplItem<'ast>) {
+    let Some(trait_ref) = item.trait_ref() else { return };
+    if !item.ty().span().snippet_or("").starts_with("PrintTraitPath") {
+        return;
+    }
+
+    let path = trait_ref.path();
+    let names: Vec<_> = path.segments().iter().map(|seg| seg.ident().name()).collect();
+    cx.emit_lint(TEST_LINT, item, "printing the trait path of an impl")
+        .decorate(|diag| {
+            if let Some(segment) = path.segments().last() {
+                diag.span(segment.ident().span());
+            }
+            diag.note(format!("path: {}", names.join("::")));
+            diag.note(format!(
+                "resolves to the trait: {}",
+                matches!(path.resolve(), AstPathTarget::Item(id) if id == trait_ref.trait_id())
+            ));
+        });
+}
+
+impl TestLintPass {
+    /// Checks for functions with special names, which trigger tests.
+    fn check_fn_triggers<'ast>(&self, cx: &'ast MarkerContext<'ast>, item: &'ast marker_api::ast::FnItem<'ast>) {
+        let Some(ident) = item.ident() else { return };
+        if ident.name() == "test_ty_id_resolution_trigger" {
+            test_ty_id_resolution(cx);
+        } else if ident.name() == "test_item_id_resolution_trigger" {
+            test_item_id_resolution(cx);
+        } else if ident.name().starts_with("print_lint_group") {
+            cx.emit_lint(TEST_GROUP_LINT, item, "a lint in a group");
+        } else if ident.name() == "print_lint_crate_config" {
+            emit_lint_crate_config_lint(cx, item, self.init_config);
+        } else if ident.name() == "uilints_please_ice_on_this" {
+            panic!("free ice cream for everyone!!!");
+        }
+    }
+}
+
+fn emit_lint_crate_config_lint<'ast>(
+    cx: &'ast MarkerContext<'ast>,
+    item: &'ast marker_api::ast::FnItem<'ast>,
+    init_config: Option<&LintCrateConfig<'_>>,
+) {
+    let Some(config) = cx.lint_crate_config() else {
+        cx.emit_lint(TEST_LINT, item, "no lint crate config");
+        return;
+    };
+
+    cx.emit_lint(TEST_LINT, item, "printing lint crate config")
+        .decorate(|diag| {
+            diag.span(item.ident().unwrap().span());
+            diag.note(format!("lint crate: {}", config.lint_crate()));
+            diag.note(format!("table: {}", config.table()));
+            diag.note(format!(
+                "available at construction: {}",
+                init_config.is_some_and(|init| init.lint_crate() == config.lint_crate())
+            ));
+            diag.note(format!("config: {:?}", config.deserialize::<UiLintsConfig>()));
+            diag.note(format!(
+                "invalid: {}",
+                config.deserialize::<InvalidUiLintsConfig>().unwrap_err()
+            ));
+        });
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code, arithmetic_overflow, unconditional_panic)]
+
+const LIMIT: usize = 8 * 4;
+const GREETING: &str = "Hello";
+const PAIR: (u8, bool) = (1, true);
+const ORDER: Option<i32> = Some(-3);
+
+struct Config;
+
+impl Config {
+    const SIZE: u16 = 512;
+}
+
+fn generic<const N: usize>() {
+    let _check_eval_const_param = N;
+    let _check_eval_const_array: [u8; N] = [0; N];
+}
+
+fn main() {
+    let _check_eval_const_int = 1 + 2 * 3;
+    let _check_eval_const_neg = -128_i8;
+    let _check_eval_const_shift = 1_u32 << 31;
+    let _check_eval_const_overflow = 255_u8 + 1;
+    let _check_eval_const_cmp = 3 < 4 && true;
+    let _check_eval_const_float = 1.5_f32 * 2.0;
+    let _check_eval_const_char = 'x';
+    let _check_eval_const_path = LIMIT;
+    let _check_eval_const_assoc = Config::SIZE - 12;
+    let _check_eval_const_str = GREETING;
+    let _check_eval_const_tuple = PAIR;
+    let _check_eval_const_enum = ORDER;
+    let _check_eval_const_array = [0_u8; LIMIT];
+
+    let value = 5;
+    let _check_eval_const_local = value + 1;
+
+    generic::<4>();
+
+    let _check_eval_const_float_separator = 1_000.5_f64;
+    let _check_eval_const_rem_overflow = i8::MIN % -1;
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+struct Pair(u8, u32);
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write([u8; 64]),
+}
+
+fn generic<T, U: ?Sized>(value: T, unsized_ref: &U) {
+    let _check_ty_layout_generic = value;
+    let _check_ty_layout_generic_ref = unsized_ref;
+}
+
+fn main() {
+    let _check_ty_layout_u32 = 1u32;
+    let _check_ty_layout_pair = Pair(1, 2);
+    let _check_ty_layout_enum = Message::Quit;
+    let _check_ty_layout_option = Some(&1);
+    let _check_ty_layout_bool = true;
+    let _check_ty_layout_ref = &[1u8, 2];
+    let _check_ty_layout_array = [0u16; 10];
+
+    generic(1, "str");
+}
@@ -0,0 +1,25 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Default, PartialEq)]
+struct CopyType(u32);
+
+struct DropType;
+
+impl Drop for DropType {
+    fn drop(&mut self) {}
+}
+
+fn generic<T: Clone + Send>(value: T) {
+    let _check_ty_traits_generic = value;
+}
+
+fn main() {
+    let _check_ty_traits_u32 = 1u32;
+    let _check_ty_traits_copy = CopyType(1);
+    let _check_ty_traits_rc = Rc::new(1);
+    let _check_ty_traits_cell = Cell::new(1);
+    let _check_ty_traits_drop = DropType;
+
+    generic(1);
+}
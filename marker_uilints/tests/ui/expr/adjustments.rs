@@ -0,0 +1,15 @@
+fn takes_slice(_: &[i32]) -> &[i32] {
+    &[]
+}
+
+fn main() {
+    let vec = vec![1, 2];
+    let _check_adjustments_none = 1;
+    let _check_adjustments_deref_unsize: &[i32] = &vec;
+    let _check_adjustments_reify: fn(&[i32]) -> &[i32] = takes_slice;
+    let _check_adjustments_closure: fn() = || {};
+
+    let mut number = 1;
+    let reference = &mut number;
+    let _check_adjustments_reborrow: &i32 = reference;
+}
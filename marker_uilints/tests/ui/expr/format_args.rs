@@ -0,0 +1,24 @@
+use std::fmt::format;
+
+fn main() {
+    let x = 1;
+    let y = "marker";
+    let width = 8;
+
+    let _check_format_args = format(format_args!("Hello {{{y}}}!"));
+    let _check_format_args = format(format_args!("{x:?} {0:>5} {name:#x?}", x + 1, name = y));
+    let _check_format_args = format(format_args!("{:-^width$.2} {:+05} {:.*}", 1.5, x, 3, 2.0));
+    let _check_format_args = format(format_args!("{} {:p}", "inlined", &x));
+}
+
+#[warn(marker::marker_uilints::print_every_expr)]
+fn print_every_expr(x: u32) {
+    let _ = format_args!("{x} {}", x + 1);
+}
+
+fn format_macros(x: i32, y: &str) -> String {
+    let _check_format_macro = println!("{x} {}", y);
+    let _check_format_macro = if x > 0 { 0 } else { panic!("{x:>4}") };
+    let _check_format_macro = format!("{y:?}{}", x + 1);
+    _check_format_macro
+}
@@ -0,0 +1,29 @@
+struct Pair {
+    first: Vec<i32>,
+    second: i32,
+}
+
+fn main() {
+    let mut _check_local_usages_vec = vec![1, 2];
+    _check_local_usages_vec.push(3);
+    let _element = _check_local_usages_vec[0];
+    _check_local_usages_vec = Vec::new();
+    let _len = (&_check_local_usages_vec).len();
+    println!("{_check_local_usages_vec:?}");
+    drop(_check_local_usages_vec);
+
+    let mut _check_local_usages_pair = Pair {
+        first: vec![],
+        second: 1,
+    };
+    _check_local_usages_pair.second += 1;
+    let _copy = _check_local_usages_pair.second;
+    let _len = _check_local_usages_pair.first.len();
+    let _ref = &mut _check_local_usages_pair.first;
+    let _ptr = std::ptr::addr_of!(_check_local_usages_pair);
+
+    let _check_local_usages_captured = 1;
+    let closure = || _check_local_usages_captured + 1;
+    closure();
+    let _unrelated = |_check_local_usages_captured: i32| _check_local_usages_captured;
+}
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+struct Duck;
+
+impl Duck {
+    fn quack(&self) {}
+}
+
+trait Animal {
+    fn name(&self) -> &'static str {
+        "animal"
+    }
+
+    fn noise(&self) -> &'static str;
+}
+
+impl Animal for Duck {
+    fn noise(&self) -> &'static str {
+        "quack"
+    }
+}
+
+fn generic<T: Animal>(animal: &T) {
+    let _method_target_generic = animal.noise();
+}
+
+fn main() {
+    let duck = Duck;
+    let _method_target_inherent = duck.quack();
+    let _method_target_trait = duck.noise();
+    let _method_target_default = duck.name();
+
+    let rc = Rc::new(Duck);
+    let _method_target_deref = rc.quack();
+
+    let dyn_animal: &dyn Animal = &duck;
+    let _method_target_dyn = dyn_animal.noise();
+
+    let mut vec = vec![1, 2];
+    let _method_target_mut = vec.push(3);
+    let _method_target_external = vec.clone();
+
+    generic(&duck);
+}
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+/// A doc comment
+/** A block doc comment */
+#[doc = "A doc attribute"]
+#[must_use = "it's important"]
+#[cfg_attr(all(), allow(non_camel_case_types))]
+#[rustfmt::skip]
+#[repr(C)]
+#[derive(Debug)]
+struct print_attrs_struct {
+    #[allow(unused)]
+    print_attrs_field: u32,
+}
+
+enum Foo {
+    /// A variant
+    #[allow(unused)]
+    PrintAttrsVariant,
+}
+
+#[inline]
+fn print_attrs_fn<#[allow(unused)] 'a, #[allow(unused)] T>() {
+    #![allow(clippy::all)]
+    //! Inner docs
+}
+
+fn main() {
+    #[allow(unused_variables)]
+    let _print_attrs_stmt = 1;
+
+    match 1 {
+        #[allow(unused)]
+        1 => {},
+        _ => {},
+    }
+}
@@ -9,3 +9,19 @@ fn test_ty_id_resolution_trigger() {
 }
 
 fn main() {}
+
+impl TestType {
+    fn new() -> Self {
+        Self(0)
+    }
+}
+
+static TEST_STATIC: u32 = 0;
+
+// Please don't change the function name, it's used by the lint
+fn test_item_id_resolution_trigger() {
+    let _check_item_path_forget = std::mem::forget(String::new());
+    let _check_item_path_vec: Vec<u32> = Vec::new();
+    let _check_item_path_clone = String::new().clone();
+    let _check_item_path_local = TestType::new();
+}
@@ -0,0 +1,3 @@
+fn print_lint_crate_config() {}
+
+fn main() {}
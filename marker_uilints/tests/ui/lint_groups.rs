@@ -0,0 +1,9 @@
+fn print_lint_group_allowed() {}
+
+#[warn(marker::marker_uilints::test_group)]
+fn print_lint_group_warn() {}
+
+#[deny(marker::marker_uilints::test_group)]
+fn print_lint_group_deny() {}
+
+fn main() {}
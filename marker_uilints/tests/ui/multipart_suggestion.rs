@@ -0,0 +1,3 @@
+static MULTIPART_SUGGESTION: u32 = 4;
+
+fn main() {}
@@ -0,0 +1,5 @@
+fn print_post_hook_sequence(x: u32) -> u32 {
+    (x + 2) * 3
+}
+
+fn main() {}
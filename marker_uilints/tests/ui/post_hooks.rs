@@ -0,0 +1,11 @@
+fn print_post_hooks_simple() -> u32 {
+    let a = 1 + 2;
+    a * 3
+}
+
+fn print_post_hooks_closure() {
+    let add = |a: u32, b: u32| a + b;
+    let _ = add(1, 2);
+}
+
+fn main() {}
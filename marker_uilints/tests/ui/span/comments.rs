@@ -0,0 +1,29 @@
+//! Inner doc comment of the test file
+
+fn main() {
+    // This comment belongs to the first statement
+    let _check_comments_single = 1;
+
+    let _unrelated = 2; // This comment is followed by code
+
+    /* A block comment */
+    // SAFETY: A line comment
+    let _check_comments_multiple = unsafe { std::mem::transmute::<u32, f32>(1) };
+
+    // This comment is separated by code
+    let _separator = 3;
+    let _check_comments_none = 4;
+
+    //// Four slashes are a normal comment
+    /** An outer block doc comment */
+    /**/
+    let _check_comments_special = 5;
+}
+
+fn trailing() {
+    let _a = 1; /* Trailing block comment */ // Trailing line comment
+    let _check_comments_after_trailing = 2;
+
+    /* A multi-line
+    block comment */ let _check_comments_after_block = 3;
+}
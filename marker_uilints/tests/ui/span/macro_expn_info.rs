@@ -0,0 +1,12 @@
+mod macros {
+    #[macro_export]
+    macro_rules! exported_array {
+        ($($x:expr),*) => {
+            [$($x),*]
+        };
+    }
+}
+
+fn main() {
+    let _span_path_macro = crate::exported_array!(1, 2);
+}
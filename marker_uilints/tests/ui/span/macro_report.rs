@@ -0,0 +1,21 @@
+macro_rules! local_macro {
+    () => {
+        1 + 2
+    };
+}
+
+macro_rules! local_stmt_macro {
+    () => {{
+        let _check_macro_report_local_in_alloc_macro = 1 + 2;
+    }};
+}
+
+fn main() {
+    let _check_macro_report_user_code = 1 + 2;
+    let _check_macro_report_local_macro = local_macro!();
+    let _check_macro_report_alloc_macro = vec![1, 2];
+    let _check_macro_report_core_macro = matches!(1, 1);
+
+    // The local macro is expanded inside the expansion of `vec`
+    let _ = vec![local_stmt_macro!()];
+}
@@ -0,0 +1,19 @@
+struct PrintTraitPathA;
+
+impl Clone for PrintTraitPathA {
+    fn clone(&self) -> Self {
+        Self
+    }
+}
+
+struct PrintTraitPathB;
+
+impl std::ops::Add<u8> for PrintTraitPathB {
+    type Output = Self;
+
+    fn add(self, _: u8) -> Self {
+        self
+    }
+}
+
+fn main() {}
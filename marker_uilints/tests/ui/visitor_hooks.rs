@@ -0,0 +1,21 @@
+enum Shape {
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+
+fn print_visit_hooks<'a, T: Clone, const N: usize>(param: &'a [T; N], shape: Shape) -> Option<Vec<T>>
+where
+    T: Default,
+{
+    let (a, _): (u8, u16) = (1, 2);
+    let len = param.len() as u32;
+    match shape {
+        Shape::Circle(r) if r > 0.0 => {},
+        Shape::Rect { w, .. } => {},
+        _ => {},
+    }
+    let _ = Vec::<u8>::new();
+    None
+}
+
+fn main() {}
@@ -4,6 +4,13 @@ use std::env;
 fn main() -> color_eyre::Result<()> {
     let mut config: Config = marker_uitest::simple_ui_test_config!("tests/ui", "../target")?;
 
+    // The lint crate config is usually set by `cargo-marker`, from the
+    // `[workspace.metadata.marker.config]` table in the `Cargo.toml` file.
+    env::set_var(
+        "MARKER_LINT_CRATES_CONFIG",
+        r#"{"manifest_path":"Cargo.toml","config":{"marker_uilints":{"max_params":3,"banned_names":["foo","bar"]}}}"#,
+    );
+
     config.filter(r"\\/", "/");
     config.filter(r"\\\\", "/");
 
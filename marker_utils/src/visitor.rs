@@ -1,7 +1,10 @@
 use std::ops::ControlFlow;
 
 use marker_api::{
-    ast::{EnumVariant, ItemField},
+    ast::{
+        AstQPath, EnumVariant, GenericArgKind, GenericArgs, GenericParamKind, GenericParams, ItemField, MatchArm,
+        TyKind, TyParamBound, WhereClauseKind,
+    },
     prelude::*,
 };
 
@@ -66,6 +69,11 @@ pub trait Visitor<B> {
         ControlFlow::Continue(())
     }
 
+    /// This is called after all nodes inside the item have been visited.
+    fn visit_item_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _item: ItemKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
     fn visit_field<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _field: &'ast ItemField<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
@@ -82,6 +90,15 @@ pub trait Visitor<B> {
         ControlFlow::Continue(())
     }
 
+    /// This is called after all nodes inside the body have been visited.
+    fn visit_body_post<'ast>(
+        &mut self,
+        _cx: &'ast MarkerContext<'ast>,
+        _body: &'ast ast::Body<'ast>,
+    ) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
     fn visit_stmt<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _stmt: StmtKind<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
@@ -89,8 +106,50 @@ pub trait Visitor<B> {
     fn visit_expr<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
+
+    /// This is called after all nodes inside the expression have been visited.
+    fn visit_expr_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _pat: PatKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    /// This is called for expressions inside patterns, like the place of a
+    /// [`PlacePat`](marker_api::ast::PlacePat), the literal of a
+    /// [`LitPat`](marker_api::ast::LitPat) or the bounds of a
+    /// [`RangePat`](marker_api::ast::RangePat). These expressions are not
+    /// passed to [`Visitor::visit_expr`]. They can be traversed with
+    /// [`traverse_expr`], if needed.
+    fn visit_pat_expr<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _ty: TyKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_generic_param<'ast>(
+        &mut self,
+        _cx: &'ast MarkerContext<'ast>,
+        _param: GenericParamKind<'ast>,
+    ) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_match_arm<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _arm: &'ast MatchArm<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    /// Visits qualified paths in expressions, patterns and types. The paths of
+    /// [`UseItem`](marker_api::ast::UseItem)s are not visited by this method.
+    fn visit_path<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _path: &'ast AstQPath<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
 }
 
+#[allow(clippy::too_many_lines)]
 pub fn traverse_item<'ast, B>(
     cx: &'ast MarkerContext<'ast>,
     visitor: &mut dyn Visitor<B>,
@@ -126,38 +185,70 @@ pub fn traverse_item<'ast, B>(
             }
         },
         ItemKind::Static(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             traverse_body_id(cx, visitor, item.body_id())?;
         },
         ItemKind::Const(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             traverse_body_id(cx, visitor, item.body_id())?;
         },
         ItemKind::Fn(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            for param in item.params() {
+                traverse_pat(cx, visitor, param.pat())?;
+                traverse_ty(cx, visitor, param.ty())?;
+            }
+            if let Some(return_ty) = item.return_ty() {
+                traverse_ty(cx, visitor, *return_ty)?;
+            }
             traverse_body_id(cx, visitor, item.body_id())?;
         },
+        ItemKind::TyAlias(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            traverse_ty_param_bounds(cx, visitor, item.bounds())?;
+            if let Some(aliased_ty) = item.aliased_ty() {
+                traverse_ty(cx, visitor, aliased_ty)?;
+            }
+        },
         ItemKind::Struct(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Union(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Enum(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for variant in item.variants() {
                 visitor.visit_variant(cx, variant)?;
+                for field in variant.fields() {
+                    traverse_ty(cx, visitor, field.ty())?;
+                }
                 if let Some(const_expr) = variant.discriminant() {
                     traverse_expr(cx, visitor, const_expr.expr())?;
                 }
             }
         },
         ItemKind::Trait(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            traverse_ty_param_bounds(cx, visitor, item.supertraits())?;
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
         },
         ItemKind::Impl(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            if let Some(trait_ref) = item.trait_ref() {
+                traverse_path(cx, visitor, trait_ref.path())?;
+            }
+            traverse_ty(cx, visitor, item.ty())?;
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
@@ -167,11 +258,14 @@ pub fn traverse_item<'ast, B>(
                 traverse_item(cx, visitor, ext_item.as_item())?;
             }
         },
-        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) | ItemKind::TyAlias(_) => {
+        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) => {
             // These items have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all items are covered"),
     }
+
+    visitor.visit_item_post(cx, kind)?;
+
     ControlFlow::Continue(())
 }
 
@@ -184,6 +278,8 @@ pub fn traverse_body<'ast, B>(
 
     traverse_expr(cx, visitor, body.expr())?;
 
+    visitor.visit_body_post(cx, body)?;
+
     ControlFlow::Continue(())
 }
 
@@ -199,6 +295,10 @@ pub fn traverse_stmt<'ast, B>(
             traverse_item(cx, visitor, item.item())?;
         },
         StmtKind::Let(lt) => {
+            traverse_pat(cx, visitor, lt.pat())?;
+            if let Some(ty) = lt.ty() {
+                traverse_ty(cx, visitor, ty)?;
+            }
             if let Some(init) = lt.init() {
                 traverse_expr(cx, visitor, init)?;
             }
@@ -233,6 +333,15 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Closure(e) => {
+            for param in e.params() {
+                traverse_pat(cx, visitor, param.pat())?;
+                if let Some(ty) = param.ty() {
+                    traverse_ty(cx, visitor, ty)?;
+                }
+            }
+            if let Some(return_ty) = e.return_ty() {
+                traverse_ty(cx, visitor, return_ty)?;
+            }
             if let VisitorScope::AllBodies = visitor.scope() {
                 let body = cx.ast().body(e.body_id());
                 traverse_body(cx, visitor, body)?;
@@ -252,10 +361,12 @@ pub fn traverse_expr<'ast, B>(
             traverse_expr(cx, visitor, e.expr())?;
         },
         ExprKind::Assign(e) => {
+            traverse_pat(cx, visitor, e.assignee())?;
             traverse_expr(cx, visitor, e.value())?;
         },
         ExprKind::As(e) => {
             traverse_expr(cx, visitor, e.expr())?;
+            traverse_ty(cx, visitor, e.cast_ty())?;
         },
         ExprKind::Call(e) => {
             traverse_expr(cx, visitor, e.func())?;
@@ -265,6 +376,7 @@ pub fn traverse_expr<'ast, B>(
         },
         ExprKind::Method(e) => {
             traverse_expr(cx, visitor, e.receiver())?;
+            traverse_generic_args(cx, visitor, e.method().generics())?;
             for arg in e.args() {
                 traverse_expr(cx, visitor, *arg)?;
             }
@@ -283,6 +395,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Ctor(e) => {
+            traverse_path(cx, visitor, e.path())?;
             for field in e.fields() {
                 traverse_expr(cx, visitor, field.expr())?;
             }
@@ -317,11 +430,14 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Let(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.scrutinee())?;
         },
         ExprKind::Match(e) => {
             traverse_expr(cx, visitor, e.scrutinee())?;
             for arm in e.arms() {
+                visitor.visit_match_arm(cx, arm)?;
+                traverse_pat(cx, visitor, arm.pat())?;
                 if let Some(guard) = arm.guard() {
                     traverse_expr(cx, visitor, guard)?;
                 }
@@ -339,6 +455,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::For(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.iterable())?;
             traverse_expr(cx, visitor, e.block())?;
         },
@@ -352,19 +469,252 @@ pub fn traverse_expr<'ast, B>(
         ExprKind::Await(e) => {
             traverse_expr(cx, visitor, e.expr())?;
         },
+        ExprKind::Path(e) => {
+            traverse_path(cx, visitor, e.path())?;
+        },
+        ExprKind::FormatArgs(e) => {
+            for arg in e.args() {
+                if let Some(arg_expr) = arg.expr() {
+                    traverse_expr(cx, visitor, arg_expr)?;
+                }
+            }
+        },
         ExprKind::IntLit(_)
         | ExprKind::FloatLit(_)
         | ExprKind::StrLit(_)
         | ExprKind::CharLit(_)
         | ExprKind::BoolLit(_)
         | ExprKind::Unstable(_)
-        | ExprKind::Path(_)
         | ExprKind::Continue(_) => {
             // These expressions have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all expressions are covered"),
     }
 
+    visitor.visit_expr_post(cx, expr)?;
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_pat<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    pat: PatKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_pat(cx, pat)?;
+
+    match pat {
+        PatKind::Ident(p) => {
+            if let Some(binding) = p.binding_pat() {
+                traverse_pat(cx, visitor, binding)?;
+            }
+        },
+        PatKind::Ref(p) => {
+            traverse_pat(cx, visitor, p.pat())?;
+        },
+        PatKind::Struct(p) => {
+            traverse_path(cx, visitor, p.path())?;
+            for field in p.fields() {
+                traverse_pat(cx, visitor, field.pat())?;
+            }
+        },
+        PatKind::Tuple(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Slice(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Or(p) => {
+            for pat in p.pats() {
+                traverse_pat(cx, visitor, *pat)?;
+            }
+        },
+        PatKind::Place(p) => {
+            visitor.visit_pat_expr(cx, p.place())?;
+        },
+        PatKind::Lit(p) => {
+            visitor.visit_pat_expr(cx, p.lit().into())?;
+        },
+        PatKind::Path(p) => {
+            traverse_path(cx, visitor, p.path())?;
+        },
+        PatKind::Range(p) => {
+            if let Some(start) = p.start() {
+                visitor.visit_pat_expr(cx, start)?;
+            }
+            if let Some(end) = p.end() {
+                visitor.visit_pat_expr(cx, end)?;
+            }
+        },
+        PatKind::Wildcard(_) | PatKind::Rest(_) | PatKind::Unstable(_) => {
+            // These patterns have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all patterns are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_ty<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    ty: TyKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_ty(cx, ty)?;
+
+    match ty {
+        TyKind::Tuple(t) => {
+            for ty in t.types() {
+                traverse_ty(cx, visitor, *ty)?;
+            }
+        },
+        TyKind::Array(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+            if let Some(len) = t.len() {
+                traverse_expr(cx, visitor, len.expr())?;
+            }
+        },
+        TyKind::Slice(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::Ref(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::RawPtr(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::FnPtr(t) => {
+            for param in t.params() {
+                traverse_ty(cx, visitor, param.ty())?;
+            }
+            if let Some(return_ty) = t.return_ty() {
+                traverse_ty(cx, visitor, *return_ty)?;
+            }
+        },
+        TyKind::TraitObj(t) => {
+            traverse_ty_param_bounds(cx, visitor, t.trait_bounds())?;
+        },
+        TyKind::ImplTrait(t) => {
+            traverse_ty_param_bounds(cx, visitor, t.trait_bounds())?;
+        },
+        TyKind::Path(t) => {
+            traverse_path(cx, visitor, t.path())?;
+        },
+        TyKind::Bool(_) | TyKind::Num(_) | TyKind::Text(_) | TyKind::Never(_) | TyKind::Inferred(_) => {
+            // These types have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all types are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_generic_params<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    generics: &'ast GenericParams<'ast>,
+) -> ControlFlow<B> {
+    for param in generics.params() {
+        visitor.visit_generic_param(cx, *param)?;
+
+        if let GenericParamKind::Const(param) = param {
+            traverse_ty(cx, visitor, param.ty())?;
+            if let Some(default) = param.default() {
+                traverse_expr(cx, visitor, default.expr())?;
+            }
+        }
+    }
+
+    for clause in generics.clauses() {
+        match clause {
+            WhereClauseKind::Lifetime(_) => {
+                // Lifetime clauses have no sub nodes, which are visited by this visitor
+            },
+            WhereClauseKind::Ty(clause) => {
+                if let Some(params) = clause.params() {
+                    traverse_generic_params(cx, visitor, params)?;
+                }
+                traverse_ty(cx, visitor, clause.ty())?;
+                traverse_ty_param_bounds(cx, visitor, clause.bounds())?;
+            },
+            _ => unreachable!("all clauses are covered"),
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_path<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    path: &'ast AstQPath<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_path(cx, path)?;
+
+    if let Some(self_ty) = path.self_ty() {
+        traverse_ty(cx, visitor, self_ty)?;
+    }
+    if let Some(path_ty) = path.path_ty() {
+        // The segments of type relative paths include the segments of the
+        // path type. Only the last segment belongs to this path.
+        traverse_ty(cx, visitor, path_ty)?;
+        traverse_generic_args(cx, visitor, path.generics())?;
+    } else {
+        for segment in path.segments() {
+            traverse_generic_args(cx, visitor, segment.generics())?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_generic_args<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    args: &'ast GenericArgs<'ast>,
+) -> ControlFlow<B> {
+    for arg in args.args() {
+        match arg {
+            GenericArgKind::Ty(arg) => {
+                traverse_ty(cx, visitor, arg.ty())?;
+            },
+            GenericArgKind::Binding(arg) => {
+                traverse_ty(cx, visitor, arg.ty())?;
+            },
+            GenericArgKind::Const(arg) => {
+                traverse_expr(cx, visitor, arg.expr().expr())?;
+            },
+            GenericArgKind::Lifetime(_) => {
+                // Lifetimes have no sub nodes, which are visited by this visitor
+            },
+            _ => unreachable!("all generic arguments are covered"),
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_ty_param_bounds<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    bounds: &'ast [TyParamBound<'ast>],
+) -> ControlFlow<B> {
+    for bound in bounds {
+        match bound {
+            TyParamBound::TraitBound(bound) => {
+                traverse_path(cx, visitor, bound.trait_ref().path())?;
+            },
+            TyParamBound::Lifetime(_) => {
+                // Lifetimes have no sub nodes, which are visited by this visitor
+            },
+            _ => unreachable!("all bounds are covered"),
+        }
+    }
+
     ControlFlow::Continue(())
 }
 
@@ -442,6 +792,8 @@ impl_traversable_for!(ExprKind<'ast>, traverse_expr);
 impl_traversable_for!(StmtKind<'ast>, traverse_stmt);
 impl_traversable_for!(ItemKind<'ast>, traverse_item);
 impl_traversable_for!(&'ast ast::Body<'ast>, traverse_body);
+impl_traversable_for!(PatKind<'ast>, traverse_pat);
+impl_traversable_for!(TyKind<'ast>, traverse_ty);
 
 /// This trait extends the [`Traversable`] trait with more functions, specific to
 /// the `bool` return type.